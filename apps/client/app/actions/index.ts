@@ -1,24 +1,44 @@
 import {
+  feeModel,
+  fetchInventoryFromSeeds,
+  fetchMaybeGlobalConfigFromSeeds,
   fetchPool,
-  getCloseInstruction,
+  findInventoryPda,
+  getAcceptAuthorityInstruction,
+  getApplyFeeInstruction,
+  getClaimFeesInstructionAsync,
+  getCloseAndDrainInstructionAsync,
   getCreateInstructionAsync,
-  getDepositInstruction,
+  getDepositManyInstruction,
   getFloorSwapErrorMessage,
-  getSetActiveInstruction,
+  getProposeAuthorityInstruction,
+  getProposeFeeInstruction,
   getSetFeeInstruction,
-  getSwapInstruction,
-  getWithdrawInstruction,
+  getSetMultisigInstruction,
+  getSetRolesInstruction,
+  getSetStatusInstruction,
+  getSwapInstructionAsync,
+  getUpdatePoolInstruction,
+  getWithdrawManyInstruction,
   isFloorSwapError,
+  PoolStatus,
+  RoyaltyBase,
+  type FeeModel,
 } from '@midevils/sdk';
 import {
+  AccountRole,
+  address,
   compileTransaction,
   createTransaction,
   getBase58Decoder,
   getBase64EncodedWireTransaction,
   getSolanaErrorFromTransactionError,
   isSolanaError,
+  isSome,
+  partiallySignTransactionMessageWithSigners,
   pipe,
   signature,
+  some,
   SOLANA_ERROR__BLOCK_HEIGHT_EXCEEDED,
   SOLANA_ERROR__RPC_SUBSCRIPTIONS__CHANNEL_CONNECTION_CLOSED,
   type Address,
@@ -28,13 +48,52 @@ import {
   type Transaction,
   type TransactionMessage,
   type TransactionSendingSigner,
+  type TransactionSigner,
   type TransactionWithBlockhashLifetime,
 } from 'gill';
+import { getAssociatedTokenAccountAddress } from 'gill/programs';
 import { createBlockHeightExceedencePromiseFactory } from '@solana/transaction-confirmation';
 import type { Client } from '~/context/rpc';
 import { CORE_PROGRAM_ADDRESS } from '@midevils/shared';
 import { chunk } from 'lodash-es';
 
+// DepositMany and WithdrawMany run one TransferV1 CPI per asset in a single
+// instruction, so they need more than the default 200k compute units.
+const MANY_ASSETS_COMPUTE_UNIT_LIMIT = 1_400_000;
+
+// CloseAndDrain shares its transaction with the status change and the fee
+// claim, so it takes fewer assets than DepositMany and WithdrawMany.
+const MAX_DRAINED_ASSETS = 10;
+
+// The multisig signers of a pool come before the assets in the remaining
+// accounts of WithdrawMany and CloseAndDrain.
+function withSigners<T extends Instruction>(
+  ix: T,
+  signers: TransactionSigner[]
+): T {
+  return {
+    ...ix,
+    accounts: [
+      ...(ix.accounts ?? []),
+      ...signers.map((signer) => ({
+        address: signer.address,
+        role: AccountRole.READONLY_SIGNER,
+        signer,
+      })),
+    ],
+  };
+}
+
+function withAssets<T extends Instruction>(ix: T, assets: Address[]): T {
+  return {
+    ...ix,
+    accounts: [
+      ...(ix.accounts ?? []),
+      ...assets.map((address) => ({ address, role: AccountRole.WRITABLE })),
+    ],
+  };
+}
+
 export async function deposit({
   client,
   collection,
@@ -52,19 +111,21 @@ export async function deposit({
     return;
   }
 
-  const ixs = chunk(assets, 23).map((assets) =>
-    assets.map((asset) =>
-      getDepositInstruction({
-        asset,
+  const [inventory] = await findInventoryPda({ pool });
+  const ixs = chunk(assets, 23).map((assets) => [
+    withAssets(
+      getDepositManyInstruction({
         payer: signer,
         pool,
+        inventory,
         collection,
         coreProgram: CORE_PROGRAM_ADDRESS,
-      })
-    )
-  );
+      }),
+      assets
+    ),
+  ]);
 
-  await sendTxs(client, signer, ixs);
+  await sendTxs(client, signer, ixs, MANY_ASSETS_COMPUTE_UNIT_LIMIT);
 }
 
 export async function withdraw({
@@ -73,30 +134,38 @@ export async function withdraw({
   pool,
   assets,
   signer,
+  multisigSigners = [],
 }: {
   client: Client;
   collection: Address;
   pool: Address;
   assets: Address[];
   signer: TransactionSendingSigner;
+  // As many multisig signers as the threshold, when the pool has a multisig.
+  multisigSigners?: TransactionSigner[];
 }) {
   if (!assets.length) {
     return;
   }
 
-  const ixs = chunk(assets, 23).map((assets) =>
-    assets.map((asset) =>
-      getWithdrawInstruction({
-        asset,
-        authority: signer,
-        pool,
-        collection,
-        coreProgram: CORE_PROGRAM_ADDRESS,
-      })
-    )
-  );
+  const [inventory] = await findInventoryPda({ pool });
+  const ixs = chunk(assets, 23).map((assets) => [
+    withAssets(
+      withSigners(
+        getWithdrawManyInstruction({
+          authority: signer,
+          pool,
+          inventory,
+          collection,
+          coreProgram: CORE_PROGRAM_ADDRESS,
+        }),
+        multisigSigners
+      ),
+      assets
+    ),
+  ]);
 
-  await sendTxs(client, signer, ixs);
+  await sendTxs(client, signer, ixs, MANY_ASSETS_COMPUTE_UNIT_LIMIT);
 }
 
 export async function swap({
@@ -106,6 +175,7 @@ export async function swap({
   signer,
   sourceAsset,
   destAsset,
+  referrer,
 }: {
   client: Client;
   collection: Address;
@@ -113,70 +183,425 @@ export async function swap({
   sourceAsset: Address;
   destAsset: Address;
   signer: TransactionSendingSigner;
+  // The wallet of a partner front-end receiving the referral share of the fee.
+  referrer?: Address;
 }) {
-  const [poolAcc, balance] = await Promise.all([
+  const [poolAcc, globalConfig, balance] = await Promise.all([
     fetchPool(client.rpc, pool),
+    fetchMaybeGlobalConfigFromSeeds(client.rpc),
     client.rpc.getBalance(signer.address).send(),
   ]);
 
-  if (balance.value < poolAcc.data.feeAmount + 5000n) {
+  const { feeMint, feeSplit, treasury, referencePrice, royaltyBase } =
+    poolAcc.data;
+  const poolFee = computeFee(poolAcc.data.feeModel, referencePrice);
+  // The protocol fee is charged on top of the pool fee, in the same currency.
+  const protocolFee = globalConfig.exists
+    ? computeProtocolFee(
+        globalConfig.data.protocolFee,
+        poolFee,
+        isSome(feeMint)
+      )
+    : 0n;
+  const protocolTreasury =
+    globalConfig.exists && protocolFee > 0n
+      ? globalConfig.data.protocolTreasury
+      : undefined;
+  // So are the royalties of the asset received.
+  const royalties = await getRoyalties(client, collection, destAsset);
+  const royaltyFee = computeRoyalties(
+    royalties,
+    royaltyBase === RoyaltyBase.Fee ? poolFee : referencePrice
+  );
+  const swapFee = poolFee + protocolFee + royaltyFee;
+
+  if (!isSome(feeMint) && balance.value < swapFee + 5000n) {
     throw new Error(
       `Insufficient balance for swap, please ensure you have at least ${
-        poolAcc.data.feeAmount / 10n ** 9n
+        swapFee / 10n ** 9n
       } SOL swap fee, plus a small amount to cover Solana transaction fee`
     );
   }
 
-  const ix = getSwapInstruction({
+  const feeTokenAccounts = isSome(feeMint)
+    ? await getFeeTokenAccounts(client, feeMint.value, signer.address, treasury)
+    : undefined;
+
+  // Token fees are split on swap, so the program expects the fee split
+  // recipients, then the royalty creators. Lamport fees are split when claimed.
+  const recipients = [
+    ...(feeTokenAccounts ? feeSplit.map((recipient) => recipient.address) : []),
+    ...royalties.creators.map((creator) => creator.address),
+  ];
+  const ix = withAssets(
+    await getSwapInstructionAsync({
+      pool,
+      payer: signer,
+      sourceAsset,
+      destAsset,
+      collection,
+      ...feeTokenAccounts,
+      // Fail rather than pay more if the pool changes before the swap lands.
+      maxFee: swapFee,
+      expectedSequence: some(poolAcc.data.sequence),
+      referrer,
+      referrerTokenAccount:
+        feeTokenAccounts && referrer
+          ? await getAssociatedTokenAccountAddress(
+              feeTokenAccounts.feeMint,
+              referrer,
+              feeTokenAccounts.tokenProgram
+            )
+          : undefined,
+      protocolTreasury,
+      protocolTreasuryTokenAccount:
+        feeTokenAccounts && protocolTreasury
+          ? await getAssociatedTokenAccountAddress(
+              feeTokenAccounts.feeMint,
+              protocolTreasury,
+              feeTokenAccounts.tokenProgram
+            )
+          : undefined,
+    }),
+    feeTokenAccounts
+      ? await Promise.all(
+          recipients.map((recipient) =>
+            getAssociatedTokenAccountAddress(
+              feeTokenAccounts.feeMint,
+              recipient,
+              feeTokenAccounts.tokenProgram
+            )
+          )
+        )
+      : recipients
+  );
+
+  await sendTxs(client, signer, [[ix]]);
+}
+
+export async function setStatus({
+  client,
+  pool,
+  signer,
+  status,
+}: {
+  client: Client;
+  pool: Address;
+  signer: TransactionSendingSigner;
+  status: PoolStatus;
+}) {
+  const ix = getSetStatusInstruction({
+    authority: signer,
     pool,
-    payer: signer,
-    sourceAsset,
-    destAsset,
-    collection,
-    treasury: poolAcc.data.treasury,
-    coreProgram: CORE_PROGRAM_ADDRESS,
+    status,
   });
 
   await sendTxs(client, signer, [[ix]]);
 }
 
-export async function setActive({
+export async function updateTreasury({
   client,
   pool,
   signer,
-  active,
+  treasury,
 }: {
   client: Client;
   pool: Address;
   signer: TransactionSendingSigner;
-  active: boolean;
+  treasury: Address;
 }) {
-  const ix = getSetActiveInstruction({
+  const ix = getUpdatePoolInstruction({
     authority: signer,
     pool,
-    active,
+    treasury,
   });
 
   await sendTxs(client, signer, [[ix]]);
 }
 
-export async function closePool({
+export async function proposeAuthority({
+  client,
+  pool,
+  signer,
+  newAuthority,
+}: {
+  client: Client;
+  pool: Address;
+  signer: TransactionSendingSigner;
+  newAuthority: Address;
+}) {
+  const ix = getProposeAuthorityInstruction({
+    authority: signer,
+    pool,
+    newAuthority,
+  });
+
+  await sendTxs(client, signer, [[ix]]);
+}
+
+export async function acceptAuthority({
+  client,
+  pool,
+  signer,
+}: {
+  client: Client;
+  pool: Address;
+  signer: TransactionSendingSigner;
+}) {
+  const ix = getAcceptAuthorityInstruction({
+    newAuthority: signer,
+    pool,
+  });
+
+  await sendTxs(client, signer, [[ix]]);
+}
+
+export async function setRoles({
+  client,
+  pool,
+  signer,
+  operator,
+  feeManager,
+}: {
+  client: Client;
+  pool: Address;
+  signer: TransactionSendingSigner;
+  // Omitted roles are revoked.
+  operator?: Address;
+  feeManager?: Address;
+}) {
+  const ix = getSetRolesInstruction({
+    authority: signer,
+    pool,
+    operator,
+    feeManager,
+  });
+
+  await sendTxs(client, signer, [[ix]]);
+}
+
+export async function setMultisig({
   client,
   pool,
   signer,
+  threshold,
+  signers,
 }: {
   client: Client;
   pool: Address;
   signer: TransactionSendingSigner;
+  threshold: number;
+  // No signers removes the multisig.
+  signers: Address[];
 }) {
-  const ix = getCloseInstruction({
+  const ix = getSetMultisigInstruction({
     authority: signer,
     pool,
+    threshold,
+    signers,
   });
 
   await sendTxs(client, signer, [[ix]]);
 }
 
+export async function claimFees({
+  client,
+  pool,
+  signer,
+}: {
+  client: Client;
+  pool: Address;
+  signer: TransactionSendingSigner;
+}) {
+  const ix = await getClaimFeesIx(client, pool, signer);
+
+  await sendTxs(client, signer, [[ix]]);
+}
+
+export async function closePool({
+  client,
+  pool,
+  signer,
+  multisigSigners = [],
+}: {
+  client: Client;
+  pool: Address;
+  signer: TransactionSendingSigner;
+  // As many multisig signers as the threshold, when the pool has a multisig.
+  multisigSigners?: TransactionSigner[];
+}) {
+  const [{ data }, { data: inventory }] = await Promise.all([
+    fetchPool(client.rpc, pool),
+    fetchInventoryFromSeeds(client.rpc, { pool }),
+  ]);
+  const { collection, status } = data;
+  const ixs: Instruction[] = [];
+
+  // Assets that do not fit in the closing transaction are withdrawn first.
+  await withdraw({
+    client,
+    collection,
+    pool,
+    assets: inventory.assets.slice(0, -MAX_DRAINED_ASSETS),
+    signer,
+    multisigSigners,
+  });
+
+  // Only pools in the Closing status can be closed.
+  if (status !== PoolStatus.Closing) {
+    ixs.push(
+      getSetStatusInstruction({
+        authority: signer,
+        pool,
+        status: PoolStatus.Closing,
+      })
+    );
+  }
+
+  // The fee vault must be emptied before the pool can be closed.
+  ixs.push(await getClaimFeesIx(client, pool, signer));
+  ixs.push(
+    withAssets(
+      withSigners(
+        await getCloseAndDrainInstructionAsync({
+          authority: signer,
+          pool,
+          collection,
+          coreProgram: CORE_PROGRAM_ADDRESS,
+        }),
+        multisigSigners
+      ),
+      inventory.assets.slice(-MAX_DRAINED_ASSETS)
+    )
+  );
+
+  await sendTxs(client, signer, [ixs], MANY_ASSETS_COMPUTE_UNIT_LIMIT);
+}
+
+async function getClaimFeesIx(
+  client: Client,
+  pool: Address,
+  signer: TransactionSendingSigner
+) {
+  const { feeSplit, treasury } = (await fetchPool(client.rpc, pool)).data;
+
+  return withAssets(
+    await getClaimFeesInstructionAsync({
+      pool,
+      claimer: signer,
+      treasury,
+    }),
+    feeSplit.map((recipient) => recipient.address)
+  );
+}
+
+// Pools with a fee mint are paid from and to associated token accounts.
+async function getFeeTokenAccounts(
+  client: Client,
+  feeMint: Address,
+  payer: Address,
+  treasury: Address
+) {
+  const { value: mintAccount } = await client.rpc
+    .getAccountInfo(feeMint, { encoding: 'base64' })
+    .send();
+  if (!mintAccount) {
+    throw new Error('Fee mint not found');
+  }
+
+  const tokenProgram = mintAccount.owner;
+  const [payerTokenAccount, treasuryTokenAccount] = await Promise.all([
+    getAssociatedTokenAccountAddress(feeMint, payer, tokenProgram),
+    getAssociatedTokenAccountAddress(feeMint, treasury, tokenProgram),
+  ]);
+
+  return {
+    feeMint,
+    payerTokenAccount,
+    treasuryTokenAccount,
+    tokenProgram,
+  };
+}
+
+// Mirrors find_royalties in the program: the collection Royalties plugin takes
+// precedence over the asset one, and creators are passed in plugin order.
+type Royalties = {
+  basisPoints: number;
+  creators: { address: Address; percentage: number }[];
+};
+
+async function getRoyalties(
+  client: Client,
+  collection: Address,
+  asset: Address
+): Promise<Royalties> {
+  const [collectionAsset, destAsset] = await Promise.all([
+    client.rpc.getAsset({ id: collection }).send(),
+    client.rpc.getAsset({ id: asset }).send(),
+  ]);
+  const royalties = (collectionAsset.plugins?.royalties ??
+    destAsset.plugins?.royalties) as
+    | {
+        data?: {
+          basis_points?: number;
+          creators?: { address: string; percentage: number }[];
+        };
+      }
+    | undefined;
+
+  return {
+    basisPoints: royalties?.data?.basis_points ?? 0,
+    creators: (royalties?.data?.creators ?? []).map((creator) => ({
+      address: address(creator.address),
+      percentage: creator.percentage,
+    })),
+  };
+}
+
+// Mirrors total_royalties in the program, each creator share is rounded down.
+function computeRoyalties(royalties: Royalties, baseAmount: bigint) {
+  const bps = BigInt(royalties.basisPoints);
+  return royalties.creators.reduce(
+    (total, creator) =>
+      total + (baseAmount * bps * BigInt(creator.percentage)) / 1_000_000n,
+    0n
+  );
+}
+
+// Mirrors FeeModel::compute in the program.
+function computeFee(feeModel: FeeModel, referencePrice: bigint) {
+  switch (feeModel.__kind) {
+    case 'Flat':
+      return feeModel.lamports;
+    case 'Bps':
+      return (referencePrice * BigInt(feeModel.bps)) / 10_000n;
+    case 'FlatPlusBps':
+      return (
+        feeModel.lamports + (referencePrice * BigInt(feeModel.bps)) / 10_000n
+      );
+  }
+}
+
+// Mirrors GlobalConfig::protocol_swap_fee in the program: the basis points
+// apply to the pool fee and the flat part is only charged in SOL.
+function computeProtocolFee(
+  feeModel: FeeModel,
+  poolFee: bigint,
+  paysInToken: boolean
+) {
+  switch (feeModel.__kind) {
+    case 'Flat':
+      return paysInToken ? 0n : feeModel.lamports;
+    case 'Bps':
+      return (poolFee * BigInt(feeModel.bps)) / 10_000n;
+    case 'FlatPlusBps':
+      return (
+        (paysInToken ? 0n : feeModel.lamports) +
+        (poolFee * BigInt(feeModel.bps)) / 10_000n
+      );
+  }
+}
+
 export async function setFee({
   client,
   pool,
@@ -188,19 +613,47 @@ export async function setFee({
   signer: TransactionSendingSigner;
   feeAmount: bigint;
 }) {
-  const ix = getSetFeeInstruction({
+  const poolAcc = await fetchPool(client.rpc, pool);
+  const current = poolAcc.data.feeModel;
+  const args = {
     authority: signer,
     pool,
-    feeAmount,
-  });
+    feeModel: feeModel('Flat', { lamports: feeAmount }),
+    feeMint: null,
+  };
 
-  await sendTxs(client, signer, [[ix]]);
+  // Lowering the fee applies immediately, raising it has to wait out the fee
+  // delay of the pool.
+  if (current.__kind === 'Flat' && feeAmount <= current.lamports) {
+    await sendTxs(client, signer, [[getSetFeeInstruction(args)]]);
+    return;
+  }
+
+  const ixs: Instruction[] = [getProposeFeeInstruction(args)];
+  if (poolAcc.data.feeDelay === 0) {
+    ixs.push(getApplyFeeInstruction({ pool }));
+  }
+
+  await sendTxs(client, signer, [ixs]);
+}
+
+export async function applyFee({
+  client,
+  pool,
+  signer,
+}: {
+  client: Client;
+  pool: Address;
+  signer: TransactionSendingSigner;
+}) {
+  await sendTxs(client, signer, [[getApplyFeeInstruction({ pool })]]);
 }
 
 async function sendTxs(
   client: Client,
   signer: TransactionSendingSigner,
-  ixGroups: Instruction[][]
+  ixGroups: Instruction[][],
+  computeUnitLimit?: number
 ) {
   const { value: latestBlockhash } = await client.rpc
     .getLatestBlockhash()
@@ -212,6 +665,7 @@ async function sendTxs(
       version: 0,
       instructions: ixs,
       latestBlockhash,
+      computeUnitLimit,
     })
   );
 
@@ -255,8 +709,11 @@ async function sendTxs(
     })
   );
 
+  // Multisig signers sign before the wallet signs and sends.
   const sigs = await signer.signAndSendTransactions(
-    txs.map(compileTransaction)
+    await Promise.all(
+      txs.map((tx) => partiallySignTransactionMessageWithSigners(tx))
+    )
   );
 
   await Promise.all(
@@ -329,18 +786,25 @@ export async function createPool({
   authority,
   treasury,
   feeAmount,
+  maxFeeAmount,
+  feeDelay,
 }: {
   client: Client;
   authority: TransactionSendingSigner;
   collection: Address;
   treasury: Address;
   feeAmount: bigint;
+  maxFeeAmount: bigint;
+  feeDelay: number;
 }) {
   const createIx = await getCreateInstructionAsync({
     authority,
     collection,
     treasury,
-    feeAmount,
+    feeModel: feeModel('Flat', { lamports: feeAmount }),
+    feeMint: null,
+    maxFee: maxFeeAmount,
+    feeDelay,
   });
 
   await sendTxs(client, authority, [[createIx]]);
@@ -43,8 +43,9 @@ export const links: Route.LinksFunction = () => [
 export const loader = async ({ context }: Route.LoaderArgs) => {
   const authority = address(context.cloudflare.env.AUTHORITY_ADDRESS);
   const collection = address(context.cloudflare.env.COLLECTION_ADDRESS);
+  // The configured authority created the pool, so it stays in the seeds.
   const pool = await findPoolPda({
-    authority,
+    creator: authority,
     collection,
   });
   return {
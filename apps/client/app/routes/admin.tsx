@@ -4,17 +4,31 @@ import {
   type UiWalletAccount,
 } from '@wallet-ui/react';
 
-import { address, type Account } from 'gill';
+import {
+  address,
+  isNone,
+  isSome,
+  type Account,
+  type Address,
+  type Option,
+} from 'gill';
 import { Container } from '~/components/Container';
 import { useEffect, useState } from 'react';
 import { AssetSelector } from '~/components/AssetSelector';
 import { useSettings } from '~/context/settings';
 import {
+  acceptAuthority,
+  applyFee,
+  claimFees,
   closePool,
   createPool,
   deposit,
-  setActive,
+  proposeAuthority,
   setFee,
+  setMultisig,
+  setRoles,
+  setStatus,
+  updateTreasury,
   withdraw,
 } from '~/actions';
 import toast from 'react-hot-toast';
@@ -24,7 +38,7 @@ import { Button } from '~/components/Button';
 import { useRpc } from '~/context/rpc';
 import clsx from 'clsx';
 import { useNavigate } from 'react-router';
-import { fetchMaybePool, type Pool } from '@midevils/sdk';
+import { fetchMaybePool, PoolStatus, type Pool } from '@midevils/sdk';
 import { SYSTEM_PROGRAM_ADDRESS } from '@midevils/shared';
 import { Wallet } from '~/components/Wallet';
 import { usePool } from '~/context/pool';
@@ -35,11 +49,13 @@ function Create({ account }: { account: UiWalletAccount }) {
   const [treasury, setTreasury] = useState<string>('');
   const [treasuryError, setTreasuryError] = useState<string | null>(null);
   const [feeAmount, setFeeAmount] = useState<number | ''>('');
+  const [maxFeeAmount, setMaxFeeAmount] = useState<number | ''>('');
+  const [feeDelayHours, setFeeDelayHours] = useState<number | ''>('');
   const { collection } = useSettings();
   const client = useRpc();
   const navigate = useNavigate();
 
-  const canSubmit = feeAmount && treasury;
+  const canSubmit = feeAmount && maxFeeAmount && treasury;
 
   useEffect(() => {
     (async () => {
@@ -72,6 +88,8 @@ function Create({ account }: { account: UiWalletAccount }) {
       authority: signer,
       treasury: address(treasury),
       feeAmount: BigInt(Number(feeAmount) * 10 ** 9),
+      maxFeeAmount: BigInt(Number(maxFeeAmount) * 10 ** 9),
+      feeDelay: Math.round(Number(feeDelayHours) * 3600),
       collection,
     });
 
@@ -123,6 +141,36 @@ function Create({ account }: { account: UiWalletAccount }) {
               }
             />
           </fieldset>
+
+          <fieldset className="fieldset w-full">
+            <legend className="fieldset-legend text-black">
+              Enter the maximum swap fee in SOL
+            </legend>
+            <input
+              type="number"
+              className={clsx('input w-full')}
+              placeholder="Maximum swap fee in SOL"
+              value={maxFeeAmount}
+              onChange={(e) =>
+                setMaxFeeAmount(e.target.value ? Number(e.target.value) : '')
+              }
+            />
+          </fieldset>
+
+          <fieldset className="fieldset w-full">
+            <legend className="fieldset-legend text-black">
+              Enter the delay before fee increases apply, in hours
+            </legend>
+            <input
+              type="number"
+              className={clsx('input w-full')}
+              placeholder="Fee delay in hours"
+              value={feeDelayHours}
+              onChange={(e) =>
+                setFeeDelayHours(e.target.value ? Number(e.target.value) : '')
+              }
+            />
+          </fieldset>
         </div>
         <Button disabled={!canSubmit} onClick={onAction}>
           Create pool
@@ -189,51 +237,505 @@ function UpdateFee({ account }: { account: UiWalletAccount }) {
   );
 }
 
-function ToggleActive({
+function ApplyFee({ account }: { account: UiWalletAccount }) {
+  const [open, setOpen] = useState(false);
+  const signer = useWalletUiSigner({ account });
+  const { pool } = useSettings();
+  const client = useRpc();
+
+  async function onAction() {
+    const promise = applyFee({
+      client,
+      pool,
+      signer,
+    });
+
+    setOpen(false);
+
+    toast.promise(promise, {
+      loading: 'Applying fee',
+      success: 'Fee applied successfully',
+      error: (err) => err.message || 'Error applying fee',
+    });
+  }
+
+  return (
+    <Modal triggerLabel="Apply fee" open={open} setOpen={setOpen}>
+      <Container className="items-center justify-center flex-col gap-10">
+        <div className="flex flex-col gap-2 w-2/3 items-center">
+          <h1 className="text-2xl text-black font-bold">Apply fee</h1>
+          <p className="text-black">
+            Applies a proposed fee increase once its fee delay has passed
+          </p>
+          <Button onClick={onAction}>Apply fee</Button>
+        </div>
+      </Container>
+    </Modal>
+  );
+}
+
+// The statuses an authority can pick, Closing is reached by closing the pool.
+const OWNER_STATUSES: [PoolStatus, string][] = [
+  [PoolStatus.Active, 'Active'],
+  [PoolStatus.Paused, 'Paused'],
+  [PoolStatus.WindDown, 'Wind down'],
+];
+
+// Operators can only pause and resume the pool.
+const OPERATOR_STATUSES: [PoolStatus, string][] = [
+  [PoolStatus.Active, 'Active'],
+  [PoolStatus.Paused, 'Paused'],
+];
+
+function SetStatus({
   account,
   poolAcc,
+  statuses,
 }: {
   account: UiWalletAccount;
   poolAcc: Account<Pool>;
+  statuses: [PoolStatus, string][];
 }) {
   const [open, setOpen] = useState(false);
   const signer = useWalletUiSigner({ account });
-  const [isActive, setIsActive] = useState<boolean>(poolAcc.data.enabled);
+  const [status, setPoolStatus] = useState<PoolStatus>(poolAcc.data.status);
   const { pool } = useSettings();
   const client = useRpc();
 
-  async function onAction(isActive: boolean) {
-    const promise = setActive({
+  async function onAction(next: PoolStatus) {
+    const promise = setStatus({
       client,
       pool,
-      active: isActive,
+      status: next,
       signer,
     });
 
     setOpen(false);
 
     toast.promise(promise, {
-      loading: isActive ? 'Activating pool' : 'Disabling pool',
+      loading: 'Updating pool status',
       success: 'Pool updated successfully',
       error: (err) => err.message || 'Error updating pool',
     });
 
     await promise;
 
-    setIsActive(isActive);
+    setPoolStatus(next);
+  }
+
+  return (
+    <Modal triggerLabel="Set pool status" open={open} setOpen={setOpen}>
+      <Container className="items-center justify-center flex-col gap-10">
+        <div className="flex flex-col gap-2 w-2/3 items-center">
+          <h1 className="text-2xl text-black font-bold">Set pool status</h1>
+          <select
+            className="select w-full"
+            value={status}
+            onChange={(e) => onAction(Number(e.target.value) as PoolStatus)}
+          >
+            {status === PoolStatus.Pending && (
+              <option value={PoolStatus.Pending} disabled>
+                Pending
+              </option>
+            )}
+            {statuses.map(([value, label]) => (
+              <option key={value} value={value}>
+                {label}
+              </option>
+            ))}
+          </select>
+        </div>
+      </Container>
+    </Modal>
+  );
+}
+
+function UpdateTreasury({ account }: { account: UiWalletAccount }) {
+  const [open, setOpen] = useState(false);
+  const signer = useWalletUiSigner({ account });
+  const [treasury, setTreasury] = useState<string>('');
+  const [treasuryError, setTreasuryError] = useState<string | null>(null);
+  const { pool } = useSettings();
+  const client = useRpc();
+
+  const canSubmit = treasury && !treasuryError;
+
+  useEffect(() => {
+    if (!treasury) {
+      setTreasuryError(null);
+      return;
+    }
+    try {
+      address(treasury);
+      setTreasuryError(null);
+    } catch {
+      setTreasuryError('Invalid address');
+    }
+  }, [treasury]);
+
+  async function onAction() {
+    const promise = updateTreasury({
+      client,
+      pool,
+      treasury: address(treasury),
+      signer,
+    });
+
+    setOpen(false);
+
+    toast.promise(promise, {
+      loading: 'Updating treasury',
+      success: 'Treasury updated successfully',
+      error: (err) => err.message || 'Error updating treasury',
+    });
+
+    await promise;
+  }
+
+  return (
+    <Modal triggerLabel="Update treasury" open={open} setOpen={setOpen}>
+      <Container className="items-center justify-center flex-col gap-10">
+        <div className="flex flex-col gap-2 w-2/3 items-center">
+          <h1 className="text-2xl text-black font-bold">Update treasury</h1>
+
+          <fieldset className="fieldset w-full">
+            <legend className="fieldset-legend text-black">
+              Enter the new treasury wallet address
+            </legend>
+            <input
+              type="text"
+              className={clsx('input w-full', { 'input-error': treasuryError })}
+              placeholder="Treasury wallet"
+              value={treasury}
+              onChange={(e) => setTreasury(e.target.value)}
+            />
+            {treasuryError && (
+              <p className="label label-red">{treasuryError}</p>
+            )}
+          </fieldset>
+        </div>
+        <Button disabled={!canSubmit} onClick={onAction}>
+          Update treasury
+        </Button>
+      </Container>
+    </Modal>
+  );
+}
+
+function TransferAuthority({ account }: { account: UiWalletAccount }) {
+  const [open, setOpen] = useState(false);
+  const signer = useWalletUiSigner({ account });
+  const [newAuthority, setNewAuthority] = useState<string>('');
+  const [newAuthorityError, setNewAuthorityError] = useState<string | null>(
+    null
+  );
+  const { pool } = useSettings();
+  const client = useRpc();
+
+  const canSubmit = newAuthority && !newAuthorityError;
+
+  useEffect(() => {
+    if (!newAuthority) {
+      setNewAuthorityError(null);
+      return;
+    }
+    try {
+      address(newAuthority);
+      setNewAuthorityError(null);
+    } catch {
+      setNewAuthorityError('Invalid address');
+    }
+  }, [newAuthority]);
+
+  async function onAction() {
+    const promise = proposeAuthority({
+      client,
+      pool,
+      newAuthority: address(newAuthority),
+      signer,
+    });
+
+    setOpen(false);
+
+    toast.promise(promise, {
+      loading: 'Proposing authority',
+      success: 'Authority proposed, it takes over once it accepts',
+      error: (err) => err.message || 'Error proposing authority',
+    });
+
+    await promise;
+  }
+
+  return (
+    <Modal triggerLabel="Transfer authority" open={open} setOpen={setOpen}>
+      <Container className="items-center justify-center flex-col gap-10">
+        <div className="flex flex-col gap-2 w-2/3 items-center">
+          <h1 className="text-2xl text-black font-bold">Transfer authority</h1>
+
+          <fieldset className="fieldset w-full">
+            <legend className="fieldset-legend text-black">
+              Enter the new authority address
+            </legend>
+            <input
+              type="text"
+              className={clsx('input w-full', {
+                'input-error': newAuthorityError,
+              })}
+              placeholder="New authority"
+              value={newAuthority}
+              onChange={(e) => setNewAuthority(e.target.value)}
+            />
+            {newAuthorityError && (
+              <p className="label label-red">{newAuthorityError}</p>
+            )}
+          </fieldset>
+        </div>
+        <Button disabled={!canSubmit} onClick={onAction}>
+          Transfer authority
+        </Button>
+      </Container>
+    </Modal>
+  );
+}
+
+function AcceptAuthority({ account }: { account: UiWalletAccount }) {
+  const signer = useWalletUiSigner({ account });
+  const { pool } = useSettings();
+  const client = useRpc();
+
+  async function onAction() {
+    const promise = acceptAuthority({
+      client,
+      pool,
+      signer,
+    });
+
+    toast.promise(promise, {
+      loading: 'Accepting authority',
+      success: 'You are now the authority of the pool',
+      error: (err) => err.message || 'Error accepting authority',
+    });
+
+    await promise;
+  }
+
+  return <Button onClick={onAction}>Accept pool authority</Button>;
+}
+
+function SetRoles({ account }: { account: UiWalletAccount }) {
+  const [open, setOpen] = useState(false);
+  const signer = useWalletUiSigner({ account });
+  const [operator, setOperator] = useState<string>('');
+  const [feeManager, setFeeManager] = useState<string>('');
+  const { pool } = useSettings();
+  const client = useRpc();
+
+  function parse(value: string) {
+    if (!value) {
+      return undefined;
+    }
+    try {
+      return address(value);
+    } catch {
+      return null;
+    }
+  }
+
+  const canSubmit = parse(operator) !== null && parse(feeManager) !== null;
+
+  async function onAction() {
+    const promise = setRoles({
+      client,
+      pool,
+      operator: parse(operator) ?? undefined,
+      feeManager: parse(feeManager) ?? undefined,
+      signer,
+    });
+
+    setOpen(false);
+
+    toast.promise(promise, {
+      loading: 'Updating roles',
+      success: 'Roles updated successfully',
+      error: (err) => err.message || 'Error updating roles',
+    });
+
+    await promise;
   }
 
   return (
-    <Modal triggerLabel="Toggle pool active" open={open} setOpen={setOpen}>
+    <Modal triggerLabel="Set roles" open={open} setOpen={setOpen}>
       <Container className="items-center justify-center flex-col gap-10">
         <div className="flex flex-col gap-2 w-2/3 items-center">
-          <h1 className="text-2xl text-black font-bold">Toggle pool active</h1>
-          <input
-            type="checkbox"
-            checked={isActive}
-            onChange={(e) => onAction(e.target.checked)}
-            className="toggle toggle-xl"
-          />
+          <h1 className="text-2xl text-black font-bold">Set roles</h1>
+          <p className="text-black">Leave a role empty to revoke it</p>
+
+          <fieldset className="fieldset w-full">
+            <legend className="fieldset-legend text-black">
+              Operator, can pause and resume the pool
+            </legend>
+            <input
+              type="text"
+              className={clsx('input w-full', {
+                'input-error': parse(operator) === null,
+              })}
+              placeholder="Operator wallet"
+              value={operator}
+              onChange={(e) => setOperator(e.target.value)}
+            />
+          </fieldset>
+
+          <fieldset className="fieldset w-full">
+            <legend className="fieldset-legend text-black">
+              Fee manager, can lower and propose swap fees
+            </legend>
+            <input
+              type="text"
+              className={clsx('input w-full', {
+                'input-error': parse(feeManager) === null,
+              })}
+              placeholder="Fee manager wallet"
+              value={feeManager}
+              onChange={(e) => setFeeManager(e.target.value)}
+            />
+          </fieldset>
+        </div>
+        <Button disabled={!canSubmit} onClick={onAction}>
+          Set roles
+        </Button>
+      </Container>
+    </Modal>
+  );
+}
+
+function SetMultisig({ account }: { account: UiWalletAccount }) {
+  const [open, setOpen] = useState(false);
+  const signer = useWalletUiSigner({ account });
+  const [signers, setSigners] = useState<string>('');
+  const [threshold, setThreshold] = useState<number>(2);
+  const { pool } = useSettings();
+  const client = useRpc();
+
+  function parse(value: string) {
+    try {
+      return value
+        .split(/[\s,]+/)
+        .filter(Boolean)
+        .map((signer) => address(signer));
+    } catch {
+      return null;
+    }
+  }
+
+  const parsed = parse(signers);
+  const canSubmit =
+    !!parsed &&
+    parsed.length > 0 &&
+    parsed.length <= 5 &&
+    threshold > 0 &&
+    threshold <= parsed.length;
+
+  async function onAction() {
+    if (!parsed) {
+      return;
+    }
+
+    const promise = setMultisig({
+      client,
+      pool,
+      threshold,
+      signers: parsed,
+      signer,
+    });
+
+    setOpen(false);
+
+    toast.promise(promise, {
+      loading: 'Setting multisig',
+      success: 'Multisig set successfully',
+      error: (err) => err.message || 'Error setting multisig',
+    });
+
+    await promise;
+  }
+
+  return (
+    <Modal triggerLabel="Set multisig" open={open} setOpen={setOpen}>
+      <Container className="items-center justify-center flex-col gap-10">
+        <div className="flex flex-col gap-2 w-2/3 items-center">
+          <h1 className="text-2xl text-black font-bold">Set multisig</h1>
+          <p className="text-black">
+            Fee changes, withdrawals and closing the pool will need this many
+            signers. They have to co-sign those transactions, which this page
+            cannot collect.
+          </p>
+
+          <fieldset className="fieldset w-full">
+            <legend className="fieldset-legend text-black">
+              Signers, up to 5
+            </legend>
+            <textarea
+              className={clsx('textarea w-full', {
+                'textarea-error': !parsed,
+              })}
+              placeholder="One wallet per line"
+              value={signers}
+              onChange={(e) => setSigners(e.target.value)}
+            />
+          </fieldset>
+
+          <fieldset className="fieldset w-full">
+            <legend className="fieldset-legend text-black">Threshold</legend>
+            <input
+              type="number"
+              className="input w-full"
+              min={1}
+              max={5}
+              value={threshold}
+              onChange={(e) => setThreshold(Number(e.target.value))}
+            />
+          </fieldset>
+        </div>
+        <Button disabled={!canSubmit} onClick={onAction}>
+          Set multisig
+        </Button>
+      </Container>
+    </Modal>
+  );
+}
+
+function ClaimFees({ account }: { account: UiWalletAccount }) {
+  const [open, setOpen] = useState(false);
+  const signer = useWalletUiSigner({ account });
+  const { pool } = useSettings();
+  const client = useRpc();
+
+  async function onAction() {
+    const promise = claimFees({
+      client,
+      pool,
+      signer,
+    });
+
+    setOpen(false);
+
+    toast.promise(promise, {
+      loading: 'Claiming fees',
+      success: 'Fees claimed successfully',
+      error: (err) => err.message || 'Error claiming fees',
+    });
+  }
+
+  return (
+    <Modal triggerLabel="Claim fees" open={open} setOpen={setOpen}>
+      <Container className="items-center justify-center flex-col gap-10">
+        <div className="flex flex-col gap-2 w-2/3 items-center">
+          <h1 className="text-2xl text-black font-bold">Claim fees</h1>
+          <p className="text-black">
+            Sends the swap fees held by the pool to the treasury and the fee
+            split recipients
+          </p>
+          <Button onClick={onAction}>Claim fees</Button>
         </div>
       </Container>
     </Modal>
@@ -272,7 +774,7 @@ function ClosePool({ account }: { account: UiWalletAccount }) {
         <div className="flex flex-col gap-2 w-2/3 items-center">
           <h1 className="text-2xl text-black font-bold">Close pool</h1>
           <p className="text-black">
-            You should make sure all assets are removed before closing the pool
+            The remaining assets of the pool are sent back to your wallet
           </p>
           <Button onClick={onAction}>Close pool</Button>
         </div>
@@ -387,7 +889,26 @@ export default function Admin() {
     );
   }
 
-  if (account.address !== authority) {
+  if (
+    poolAcc &&
+    isSome(poolAcc.data.pendingAuthority) &&
+    account.address === poolAcc.data.pendingAuthority.value
+  ) {
+    return (
+      <Container>
+        <AcceptAuthority account={account} />
+      </Container>
+    );
+  }
+
+  // The pool can be transferred away from the authority that created it.
+  const isOwner = account.address === (poolAcc?.data.authority ?? authority);
+  const hasRole = (role: Option<Address> | undefined) =>
+    !!role && isSome(role) && role.value === account.address;
+  const isOperator = isOwner || hasRole(poolAcc?.data.operator);
+  const isFeeManager = isOwner || hasRole(poolAcc?.data.feeManager);
+
+  if (!isOperator && !isFeeManager) {
     return (
       <Container>
         <p className="text-xl text-red font-bold text-center">Unauthorized</p>
@@ -400,16 +921,38 @@ export default function Admin() {
       <div className="flex flex-col gap-2 w-2/3 justify-center">
         {poolAcc ? (
           <>
-            <UpdateFee account={account} />
-            <ToggleActive account={account} poolAcc={poolAcc} />
+            {isFeeManager && <UpdateFee account={account} />}
+            {isSome(poolAcc.data.pendingFee) && <ApplyFee account={account} />}
+            {isOperator && (
+              <SetStatus
+                account={account}
+                poolAcc={poolAcc}
+                statuses={isOwner ? OWNER_STATUSES : OPERATOR_STATUSES}
+              />
+            )}
+            {isOwner && (
+              <>
+                <UpdateTreasury account={account} />
+                <TransferAuthority account={account} />
+                <SetRoles account={account} />
+                {isNone(poolAcc.data.multisig) && (
+                  <SetMultisig account={account} />
+                )}
+              </>
+            )}
             <AssetsProvider owner={account.address}>
-              <Deposit account={account} />
-              <Withdraw account={account} />
+              {isOperator && <Deposit account={account} />}
+              {isOwner && <Withdraw account={account} />}
             </AssetsProvider>
-            <ClosePool account={account} />
+            {isOwner && (
+              <>
+                <ClaimFees account={account} />
+                <ClosePool account={account} />
+              </>
+            )}
           </>
         ) : (
-          <Create account={account} />
+          isOwner && <Create account={account} />
         )}
       </div>
     </Container>
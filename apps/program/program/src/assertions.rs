@@ -1,12 +1,27 @@
 use crate::{
     error::FloorSwapError,
-    state::{pool::Pool, Key},
+    state::{
+        global_config::{GlobalConfig, MAX_GUARDIANS, MAX_PROTOCOL_FEE_BPS, MAX_PROTOCOL_FEE_FLAT},
+        inventory::Inventory,
+        pool::{
+            FeeModel, FeeRecipient, Pool, PoolMode, PoolStatus, MAX_FEE_RECIPIENTS,
+            MAX_MULTISIG_SIGNERS,
+        },
+        Checked, Key,
+    },
+    utils::MAX_BPS,
 };
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    account_info::AccountInfo,
+    bpf_loader_upgradeable::{self, UpgradeableLoaderState},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
     pubkey::Pubkey,
 };
 
+use spl_token::state::Account as TokenAccount;
+
 use mpl_core::{
     types::{Key as MplCoreKey, UpdateAuthority},
     Asset, Collection, ID as MPL_CORE_ID,
@@ -32,13 +47,19 @@ pub fn assert_program_owner_either(
     }
 }
 
-/// Assert the pool is active
-pub fn assert_pool_active(pool: &Pool, account: &AccountInfo) -> ProgramResult {
-    if !pool.enabled {
+/// Assert the status of the pool is one of the statuses the instruction is allowed in.
+pub fn assert_pool_status(
+    pool: &Pool,
+    account: &AccountInfo,
+    statuses: &[PoolStatus],
+) -> ProgramResult {
+    if !statuses.contains(&pool.status) {
         msg!(
-            "Account \"{}\" [{}] expected pool to be active",
+            "Account \"{}\" [{}] expected pool status to be one of {:?}, got {:?}",
             "pool",
-            account.key
+            account.key,
+            statuses,
+            pool.status
         );
         Err(FloorSwapError::PoolInactive.into())
     } else {
@@ -46,6 +67,231 @@ pub fn assert_pool_active(pool: &Pool, account: &AccountInfo) -> ProgramResult {
     }
 }
 
+/// Assert swaps, deposits and withdrawals are not paused program-wide. The global config
+/// may not be initialised yet, in which case nothing is paused and `None` is returned.
+pub fn assert_program_not_paused(
+    account: &AccountInfo,
+) -> Result<Option<GlobalConfig>, ProgramError> {
+    if account.data_is_empty() {
+        assert_pda("global_config", account, &crate::ID, &GlobalConfig::seeds())?;
+        return Ok(None);
+    }
+
+    let global_config = Checked::<GlobalConfig>::load("global_config", account)?;
+    if global_config.paused {
+        msg!("The program is paused");
+        Err(FloorSwapError::ProgramPaused.into())
+    } else {
+        Ok(Some(global_config.clone()))
+    }
+}
+
+/// Assert the given account is the upgrade authority recorded in the program data account of
+/// this program.
+pub fn assert_upgrade_authority(
+    account_name: &str,
+    account: &AccountInfo,
+    program_data: &AccountInfo,
+) -> ProgramResult {
+    let (program_data_key, _) =
+        Pubkey::find_program_address(&[crate::ID.as_ref()], &bpf_loader_upgradeable::id());
+    assert_same_pubkeys("program_data", program_data, &program_data_key)?;
+    assert_program_owner("program_data", program_data, &bpf_loader_upgradeable::id())?;
+
+    // The metadata is the variant index, the deployment slot and the optional authority.
+    let data = program_data.data.borrow();
+    let metadata_len = UpgradeableLoaderState::size_of_programdata_metadata();
+    let upgrade_authority = match data.get(..metadata_len) {
+        Some([3, 0, 0, 0, metadata @ ..]) if metadata[8] == 1 => {
+            Pubkey::try_from(&metadata[9..]).ok()
+        }
+        _ => None,
+    };
+
+    if upgrade_authority != Some(*account.key) {
+        msg!(
+            "Account \"{}\" [{}] expected the upgrade authority of the program, got {:?}",
+            account_name,
+            account.key,
+            upgrade_authority
+        );
+        Err(FloorSwapError::InvalidUpgradeAuthority.into())
+    } else {
+        Ok(())
+    }
+}
+
+/// Assert that the given guardians fit in the global config.
+pub fn assert_guardians(name: &str, guardians: &[Pubkey]) -> ProgramResult {
+    if guardians.len() > MAX_GUARDIANS {
+        msg!(
+            "\"{}\" expected at most {} guardians, got {}",
+            name,
+            MAX_GUARDIANS,
+            guardians.len()
+        );
+        Err(FloorSwapError::TooManyGuardians.into())
+    } else {
+        Ok(())
+    }
+}
+
+/// Assert that the given protocol fee stays within the hard-coded maximum.
+pub fn assert_protocol_fee(name: &str, protocol_fee: &FeeModel) -> ProgramResult {
+    let flat = match *protocol_fee {
+        FeeModel::Flat { lamports } | FeeModel::FlatPlusBps { lamports, .. } => lamports,
+        FeeModel::Bps { .. } => 0,
+    };
+
+    if flat > MAX_PROTOCOL_FEE_FLAT || protocol_fee.bps() > MAX_PROTOCOL_FEE_BPS {
+        msg!(
+            "\"{}\" expected at most {} plus {} basis points, got {:?}",
+            name,
+            MAX_PROTOCOL_FEE_FLAT,
+            MAX_PROTOCOL_FEE_BPS,
+            protocol_fee
+        );
+        Err(FloorSwapError::ProtocolFeeAboveMaximum.into())
+    } else {
+        Ok(())
+    }
+}
+
+/// Assert no pool was created at the given account yet.
+///
+/// Lamports alone do not make a pool, as anyone can send them to its address beforehand.
+pub fn assert_pool_not_created(account: &AccountInfo, index: u8) -> ProgramResult {
+    if !account.data_is_empty() || account.owner == &crate::ID {
+        msg!(
+            "Account \"{}\" [{}] already holds the pool with index {}, use another index",
+            "pool",
+            account.key,
+            index
+        );
+        Err(FloorSwapError::PoolAlreadyExists.into())
+    } else {
+        Ok(())
+    }
+}
+
+/// Assert the given asset is in the inventory of the pool.
+pub fn assert_in_inventory(
+    account_name: &str,
+    account: &AccountInfo,
+    inventory: &Inventory,
+) -> ProgramResult {
+    if !inventory.contains(account.key) {
+        msg!(
+            "Account \"{}\" [{}] is not in the inventory of pool [{}]",
+            account_name,
+            account.key,
+            inventory.pool
+        );
+        Err(FloorSwapError::AssetNotInInventory.into())
+    } else {
+        Ok(())
+    }
+}
+
+/// Assert the pool can move from its current status to the given one.
+pub fn assert_status_transition(pool: &Pool, status: PoolStatus) -> ProgramResult {
+    if !pool.status.can_transition_to(status) {
+        msg!(
+            "Cannot move the pool from {:?} to {:?}",
+            pool.status,
+            status
+        );
+        Err(FloorSwapError::InvalidStatusTransition.into())
+    } else {
+        Ok(())
+    }
+}
+
+/// Assert the swap terms of the pool are no worse than the ones the user signed for.
+///
+/// The fee is everything charged to the payer: the swap fee, the protocol fee and the
+/// royalties.
+pub fn assert_swap_terms(
+    pool: &Pool,
+    account: &AccountInfo,
+    fee: u64,
+    max_fee: u64,
+    expected_sequence: Option<u64>,
+) -> ProgramResult {
+    if fee > max_fee {
+        msg!(
+            "Account \"{}\" [{}] expected fees of at most {}, got {}",
+            "pool",
+            account.key,
+            max_fee,
+            fee
+        );
+        return Err(FloorSwapError::SlippageExceeded.into());
+    }
+
+    match expected_sequence {
+        Some(sequence) if sequence != pool.sequence => {
+            msg!(
+                "Account \"{}\" [{}] expected sequence {}, got {}",
+                "pool",
+                account.key,
+                sequence,
+                pool.sequence
+            );
+            Err(FloorSwapError::SlippageExceeded.into())
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Assert the pool is in the given mode
+pub fn assert_pool_mode(pool: &Pool, account: &AccountInfo, mode: PoolMode) -> ProgramResult {
+    if pool.mode != mode {
+        msg!(
+            "Account \"{}\" [{}] expected pool mode {:?}, got {:?}",
+            "pool",
+            account.key,
+            mode,
+            pool.mode
+        );
+        Err(FloorSwapError::InvalidPoolMode.into())
+    } else {
+        Ok(())
+    }
+}
+
+/// Assert the pool has no open mystery swaps, used to check before changing the inventory
+pub fn assert_no_open_commits(pool: &Pool, account: &AccountInfo) -> ProgramResult {
+    if pool.open_commits != 0 {
+        msg!(
+            "Account \"{}\" [{}] expected no open mystery swaps, has {}",
+            "pool",
+            account.key,
+            pool.open_commits
+        );
+        Err(FloorSwapError::OpenSwapCommits.into())
+    } else {
+        Ok(())
+    }
+}
+
+/// Assert the given mystery swap is the oldest open one of the pool, so swaps are resolved
+/// in the order they were committed
+pub fn assert_next_commit(pool: &Pool, account: &AccountInfo, ticket: u64) -> ProgramResult {
+    if ticket != pool.next_commit() {
+        msg!(
+            "Account \"{}\" [{}] expected mystery swap {} to be resolved first, got {}",
+            "swap_commit",
+            account.key,
+            pool.next_commit(),
+            ticket
+        );
+        Err(FloorSwapError::SwapCommitNotNext.into())
+    } else {
+        Ok(())
+    }
+}
+
 /// Assert the pool is empty, used to check before closing
 pub fn assert_pool_empty(pool: &Pool, account: &AccountInfo) -> ProgramResult {
     if pool.num_assets != 0 {
@@ -61,6 +307,159 @@ pub fn assert_pool_empty(pool: &Pool, account: &AccountInfo) -> ProgramResult {
     }
 }
 
+/// Assert that a fee split is either empty or has at most the maximum number of recipients
+/// with weights summing to 100%.
+pub fn assert_fee_split(name: &str, fee_split: &[FeeRecipient]) -> ProgramResult {
+    if fee_split.len() > MAX_FEE_RECIPIENTS {
+        msg!(
+            "\"{}\" expected at most {} recipients, got {}",
+            name,
+            MAX_FEE_RECIPIENTS,
+            fee_split.len()
+        );
+        return Err(FloorSwapError::InvalidFeeSplit.into());
+    }
+
+    let total: u32 = fee_split.iter().map(|recipient| recipient.bps as u32).sum();
+    if !fee_split.is_empty() && total != MAX_BPS as u32 {
+        msg!(
+            "\"{}\" expected weights summing to {} basis points, got {}",
+            name,
+            MAX_BPS,
+            total
+        );
+        return Err(FloorSwapError::InvalidFeeSplit.into());
+    }
+
+    Ok(())
+}
+
+/// Assert that a multisig has at most the maximum number of distinct signers and a threshold
+/// they can meet, or no signers and a zero threshold.
+pub fn assert_multisig_config(name: &str, threshold: u8, signers: &[Pubkey]) -> ProgramResult {
+    let duplicated = signers
+        .iter()
+        .enumerate()
+        .any(|(index, signer)| signers[index + 1..].contains(signer));
+    let valid_threshold = if signers.is_empty() {
+        threshold == 0
+    } else {
+        threshold > 0 && threshold as usize <= signers.len()
+    };
+
+    if signers.len() > MAX_MULTISIG_SIGNERS || duplicated || !valid_threshold {
+        msg!(
+            "\"{}\" expected at most {} distinct signers and a threshold they can meet, got {} of {}",
+            name,
+            MAX_MULTISIG_SIGNERS,
+            threshold,
+            signers.len()
+        );
+        Err(FloorSwapError::InvalidMultisig.into())
+    } else {
+        Ok(())
+    }
+}
+
+/// Assert the multisig of the pool, if any, approved the instruction with the given accounts.
+pub fn assert_multisig(pool: &Pool, accounts: &[AccountInfo]) -> ProgramResult {
+    match &pool.multisig {
+        Some(multisig) => {
+            assert_signers("multisig", accounts, &multisig.signers, multisig.threshold)
+        }
+        None => Ok(()),
+    }
+}
+
+/// Assert that the given basis points do not exceed 100%.
+pub fn assert_bps(name: &str, bps: u16) -> ProgramResult {
+    if bps > MAX_BPS {
+        msg!(
+            "\"{}\" expected at most {} basis points, got {}",
+            name,
+            MAX_BPS,
+            bps
+        );
+        Err(FloorSwapError::InvalidBasisPoints.into())
+    } else {
+        Ok(())
+    }
+}
+
+/// Assert that the given swap fee does not exceed the fee ceiling of the pool.
+pub fn assert_fee_ceiling(name: &str, fee: u64, max_fee: u64) -> ProgramResult {
+    if fee > max_fee {
+        msg!(
+            "\"{}\" expected a swap fee of at most {}, got {}",
+            name,
+            max_fee,
+            fee
+        );
+        Err(FloorSwapError::FeeAboveMaximum.into())
+    } else {
+        Ok(())
+    }
+}
+
+/// Assert that the given referral share does not exceed the maximum a pool can give.
+pub fn assert_referral_bps(name: &str, bps: u16, max_bps: u16) -> ProgramResult {
+    if bps > max_bps {
+        msg!(
+            "\"{}\" expected at most {} basis points, got {}",
+            name,
+            max_bps,
+            bps
+        );
+        Err(FloorSwapError::InvalidReferralFee.into())
+    } else {
+        Ok(())
+    }
+}
+
+/// Assert that the referrer is none of the given accounts, which would get the referral share
+/// back to the payer or the pool.
+pub fn assert_referrer(referrer: &AccountInfo, accounts: &[(&str, &AccountInfo)]) -> ProgramResult {
+    match accounts
+        .iter()
+        .find(|(_, account)| account.key == referrer.key)
+    {
+        Some((name, _)) => {
+            msg!(
+                "Account \"{}\" [{}] cannot be the \"{}\" account",
+                "referrer",
+                referrer.key,
+                name
+            );
+            Err(FloorSwapError::InvalidReferrer.into())
+        }
+        None => Ok(()),
+    }
+}
+
+/// Assert that the given token account holds the given mint and is owned by the given wallet.
+pub fn assert_token_account(
+    account_name: &str,
+    account: &AccountInfo,
+    token_account: &TokenAccount,
+    mint: &Pubkey,
+    owner: &Pubkey,
+) -> ProgramResult {
+    if token_account.mint != *mint || token_account.owner != *owner {
+        msg!(
+            "Account \"{}\" [{}] expected mint [{}] and owner [{}], got mint [{}] and owner [{}]",
+            account_name,
+            account.key,
+            mint,
+            owner,
+            token_account.mint,
+            token_account.owner
+        );
+        Err(FloorSwapError::InvalidTokenAccount.into())
+    } else {
+        Ok(())
+    }
+}
+
 /// Assert that the given account is owned by the given program.
 pub fn assert_program_owner(
     account_name: &str,
@@ -87,7 +486,7 @@ pub fn assert_mpl_core_collection(
     account: &AccountInfo,
 ) -> Result<Collection, ProgramError> {
     assert_program_owner(account_name, account, &MPL_CORE_ID)?;
-    let collection = *Collection::from_bytes(&**account.data.borrow())
+    let collection = *Collection::from_bytes(&account.data.borrow())
         .map_err(|_| FloorSwapError::ExpectedMplCoreCollection)?;
     if collection.base.key != MplCoreKey::CollectionV1 {
         msg!(
@@ -110,7 +509,7 @@ pub fn assert_mpl_core_asset(
     collection: &Pubkey,
 ) -> Result<Box<Asset>, ProgramError> {
     assert_program_owner(account_name, account, &MPL_CORE_ID)?;
-    let asset = Asset::from_bytes(&**account.data.borrow())
+    let asset = Asset::from_bytes(&account.data.borrow())
         .map_err(|_| FloorSwapError::ExpectedMplCoreAsset)?;
     if asset.base.key != MplCoreKey::AssetV1 {
         msg!(
@@ -141,8 +540,8 @@ pub fn assert_asset_owner(account_name: &str, asset: Box<Asset>, owner: &Pubkey)
             "Account \"{}\" [{:?}] expected owner [{}], got [{}]",
             account_name,
             asset.base.key,
-            asset.base.owner,
-            owner
+            owner,
+            asset.base.owner
         );
         Err(FloorSwapError::InvalidAssetOwner.into())
     } else {
@@ -233,6 +632,35 @@ pub fn assert_signer(account_name: &str, account: &AccountInfo) -> ProgramResult
     }
 }
 
+/// Assert that at least `threshold` distinct keys of the given signer set signed, counting
+/// the given accounts.
+pub fn assert_signers(
+    account_name: &str,
+    accounts: &[AccountInfo],
+    signers: &[Pubkey],
+    threshold: u8,
+) -> ProgramResult {
+    let mut approvals: Vec<&Pubkey> = Vec::with_capacity(signers.len());
+    for account in accounts {
+        if account.is_signer && signers.contains(account.key) && !approvals.contains(&account.key) {
+            approvals.push(account.key);
+        }
+    }
+
+    if approvals.len() < threshold as usize {
+        msg!(
+            "\"{}\" expected {} distinct signers of {:?}, got {}",
+            account_name,
+            threshold,
+            signers,
+            approvals.len()
+        );
+        Err(FloorSwapError::MultisigThresholdNotMet.into())
+    } else {
+        Ok(())
+    }
+}
+
 /// Assert that the given account is writable.
 pub fn assert_writable(account_name: &str, account: &AccountInfo) -> ProgramResult {
     if !account.is_writable {
@@ -266,6 +694,91 @@ pub fn assert_same_pubkeys(
     }
 }
 
+/// Assert that the given account matches one of the given public keys.
+pub fn assert_same_pubkeys_either(
+    account_name: &str,
+    account: &AccountInfo,
+    expected: &[Pubkey],
+) -> ProgramResult {
+    if !expected.contains(account.key) {
+        msg!(
+            "Account \"{}\" [{}] must match either {:?}",
+            account_name,
+            account.key,
+            expected
+        );
+        Err(FloorSwapError::AccountMismatch.into())
+    } else {
+        Ok(())
+    }
+}
+
+/// Assert that the given accounts are not the same account.
+pub fn assert_different_pubkeys(
+    account_name: &str,
+    account: &AccountInfo,
+    other_name: &str,
+    other: &AccountInfo,
+) -> ProgramResult {
+    if account.key == other.key {
+        msg!(
+            "Account \"{}\" [{}] must be different from account \"{}\"",
+            account_name,
+            account.key,
+            other_name
+        );
+        Err(FloorSwapError::DuplicateAccount.into())
+    } else {
+        Ok(())
+    }
+}
+
+/// Assert that none of the given accounts appears more than once.
+pub fn assert_unique_accounts(account_name: &str, accounts: &[AccountInfo]) -> ProgramResult {
+    for (index, account) in accounts.iter().enumerate() {
+        for other in &accounts[index + 1..] {
+            assert_different_pubkeys(account_name, account, account_name, other)?;
+        }
+    }
+    Ok(())
+}
+
+/// Assert that the given account has the expected data length.
+pub fn assert_account_len(account_name: &str, account: &AccountInfo, len: usize) -> ProgramResult {
+    if account.data_len() != len {
+        msg!(
+            "Account \"{}\" [{}] expected data length [{}], got [{}]",
+            account_name,
+            account.key,
+            len,
+            account.data_len()
+        );
+        Err(FloorSwapError::InvalidAccountLength.into())
+    } else {
+        Ok(())
+    }
+}
+
+/// Assert that the given resizable account is at least as long as the given length.
+pub fn assert_account_min_len(
+    account_name: &str,
+    account: &AccountInfo,
+    len: usize,
+) -> ProgramResult {
+    if account.data_len() < len {
+        msg!(
+            "Account \"{}\" [{}] expected data length of at least [{}], got [{}]",
+            account_name,
+            account.key,
+            len,
+            account.data_len()
+        );
+        Err(FloorSwapError::InvalidAccountLength.into())
+    } else {
+        Ok(())
+    }
+}
+
 /// Assert that the given account has the expected account key.
 pub fn assert_account_key(account_name: &str, account: &AccountInfo, key: Key) -> ProgramResult {
     let key_number = key as u8;
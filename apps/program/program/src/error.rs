@@ -54,11 +54,113 @@ pub enum FloorSwapError {
     #[error("Invalid owner for asset")]
     InvalidAssetOwner,
     /// 15 - Pool inactive
-    #[error("Pool inactive")]
+    #[error("The pool status does not allow this instruction")]
     PoolInactive,
-    /// 15 - Pool not empty
+    /// 16 - Pool not empty
     #[error("Pool not empty")]
     PoolNotEmpty,
+    /// 17 - Duplicate account
+    #[error("Duplicate account")]
+    DuplicateAccount,
+    /// 18 - Invalid account length
+    #[error("Invalid account length")]
+    InvalidAccountLength,
+    /// 19 - Invalid remaining accounts
+    #[error("Invalid remaining accounts")]
+    InvalidRemainingAccounts,
+    /// 20 - Invalid pool mode
+    #[error("Invalid pool mode")]
+    InvalidPoolMode,
+    /// 21 - Swap not revealable yet
+    #[error("Swap not revealable yet")]
+    SwapNotRevealable,
+    /// 22 - Swap commit expired
+    #[error("Swap commit expired")]
+    SwapCommitExpired,
+    /// 23 - Swap commit not expired
+    #[error("Swap commit not expired")]
+    SwapCommitNotExpired,
+    /// 24 - Invalid basis points
+    #[error("Invalid basis points")]
+    InvalidBasisPoints,
+    /// 25 - Invalid token account
+    #[error("Invalid token account")]
+    InvalidTokenAccount,
+    /// 26 - Missing fee token accounts
+    #[error("Missing accounts to pay the fee in the pool fee mint")]
+    MissingFeeTokenAccounts,
+    /// 27 - Invalid fee split
+    #[error("Fee split weights must sum to 10000 basis points")]
+    InvalidFeeSplit,
+    /// 28 - Unclaimed fees
+    #[error("The fee vault holds unclaimed fees")]
+    UnclaimedFees,
+    /// 29 - Invalid referral fee
+    #[error("Referral fee exceeds the maximum share of the swap fee")]
+    InvalidReferralFee,
+    /// 30 - Slippage exceeded
+    #[error("The swap terms of the pool are worse than expected")]
+    SlippageExceeded,
+    /// 31 - Fee above maximum
+    #[error("The swap fee exceeds the fee ceiling of the pool")]
+    FeeAboveMaximum,
+    /// 32 - Fee increase not proposed
+    #[error("Fee increases must be proposed with ProposeFee")]
+    FeeIncreaseNotProposed,
+    /// 33 - Pending fee not effective
+    #[error("The pending fee is not effective yet")]
+    PendingFeeNotEffective,
+    /// 34 - No pending fee
+    #[error("The pool has no pending fee")]
+    NoPendingFee,
+    /// 35 - Invalid status transition
+    #[error("The pool cannot move to the requested status")]
+    InvalidStatusTransition,
+    /// 36 - No pending authority
+    #[error("The pool has no pending authority")]
+    NoPendingAuthority,
+    /// 37 - Operator status not allowed
+    #[error("Operators can only pause and resume the pool")]
+    OperatorStatusNotAllowed,
+    /// 38 - Invalid multisig
+    #[error("The multisig signers or threshold are invalid")]
+    InvalidMultisig,
+    /// 39 - Multisig threshold not met
+    #[error("Not enough multisig signers approved the instruction")]
+    MultisigThresholdNotMet,
+    /// 40 - Program paused
+    #[error("Swaps, deposits and withdrawals are paused program-wide")]
+    ProgramPaused,
+    /// 41 - Invalid upgrade authority
+    #[error("The signer is not the upgrade authority of the program")]
+    InvalidUpgradeAuthority,
+    /// 42 - Too many guardians
+    #[error("Too many guardians for the global config")]
+    TooManyGuardians,
+    /// 43 - Protocol fee above maximum
+    #[error("Protocol fee is above the hard-coded maximum")]
+    ProtocolFeeAboveMaximum,
+    /// 44 - Missing protocol treasury
+    #[error("Missing the protocol treasury accounts to pay the protocol fee")]
+    MissingProtocolTreasury,
+    /// 45 - Pool already exists
+    #[error("A pool already exists for this creator, collection and index")]
+    PoolAlreadyExists,
+    /// 46 - Asset not in inventory
+    #[error("The asset is not in the inventory of the pool")]
+    AssetNotInInventory,
+    /// 47 - Open swap commits
+    #[error("The pool has mystery swaps waiting to be revealed or cancelled")]
+    OpenSwapCommits,
+    /// 48 - Pool has no assets
+    #[error("The pool has no assets to swap for")]
+    PoolHasNoAssets,
+    /// 49 - Swap commit not next
+    #[error("Mystery swaps must be revealed or cancelled in the order they were committed")]
+    SwapCommitNotNext,
+    /// 50 - Invalid referrer
+    #[error("The referrer cannot be the payer or an account of the pool")]
+    InvalidReferrer,
 }
 
 impl PrintProgramError for FloorSwapError {
@@ -1,59 +1,295 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use shank::{ShankContext, ShankInstruction};
+use solana_program::pubkey::Pubkey;
+
+use crate::state::pool::{FeeModel, FeeRecipient, PoolMode, PoolStatus, RoyaltyBase};
 
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, ShankContext, ShankInstruction)]
 #[rustfmt::skip]
 pub enum AppInstruction {
-    /// Creates the app account derived from the provided collection.
-    #[account(0, writable, name="pool", desc = "The program derived address of the Pool account to create (seeds: ['floor_swap', authority, collection])")]
-    #[account(1, name="collection", desc = "The mpl-core collection")]
-    #[account(2, signer, name="authority", desc = "The authority of the pool")]
-    #[account(3, name="treasury", desc = "The treasury where fees are sent")]
-    #[account(4, writable, signer, name="payer", desc = "The account paying for the storage fees")]
-    #[account(5, name="system_program", desc = "The system program")]
-    Create { fee_amount: u64 },
+    /// Creates the app account derived from the provided collection. Fails if the pool with the given index already exists, use another index to run several pools on the same collection
+    #[account(0, writable, name="pool", desc = "The program derived address of the Pool account to create (seeds: ['floor_swap', creator, collection], plus the index when above 0)")]
+    #[account(1, writable, name="fee_vault", desc = "The program derived address of the FeeVault account to create (seeds: ['fee_vault', pool])")]
+    #[account(2, name="collection", desc = "The mpl-core collection")]
+    #[account(3, signer, name="authority", desc = "The authority of the pool")]
+    #[account(4, name="treasury", desc = "The treasury where claimed fees are sent")]
+    #[account(5, writable, signer, name="payer", desc = "The account paying for the storage fees")]
+    #[account(6, name="system_program", desc = "The system program")]
+    #[account(7, writable, name="inventory", desc = "The program derived address of the Inventory account of the pool (seeds: ['inventory', pool])")]
+    Create { fee_model: FeeModel, fee_mint: Option<Pubkey>, max_fee: u64, fee_delay: u32, max_referral_bps: u16, index: u8 },
 
-    /// Toggles the app on/off
-    #[account(0, writable, name="pool", desc = "The program derived address of the Pool account to toggle (seeds: ['floor_swap', authority, collection])")]
-    #[account(1, signer, name="authority", desc = "The authority of the app")]
-    SetActive { active: bool },
+    /// Moves the pool to another status of its lifecycle. The operator can only pause and resume the pool
+    #[account(0, writable, name="pool", desc = "The program derived address of the Pool account (seeds: ['floor_swap', creator, collection])")]
+    #[account(1, signer, name="authority", desc = "The authority or the operator of the pool")]
+    SetStatus { status: PoolStatus },
 
-    // Updates the swap fee
-    #[account(0, writable, name="pool", desc = "The program derived address of the Pool account (seeds: ['floor_swap', authority, collection])")]
-    #[account(1, signer, name="authority", desc = "The authority of the app")]
-    SetFee { fee_amount: u64 },
+    /// Lowers the swap fee immediately, discarding any pending fee. Increases must go through ProposeFee. Takes the multisig signers as remaining accounts when the pool has one
+    #[account(0, writable, name="pool", desc = "The program derived address of the Pool account (seeds: ['floor_swap', creator, collection])")]
+    #[account(1, signer, name="authority", desc = "The authority or the fee manager of the pool")]
+    SetFee { fee_model: FeeModel, fee_mint: Option<Pubkey> },
 
-    /// Performs a swap
-    #[account(0, name="pool", desc = "The program derived address of the Pool account to toggle (seeds: ['floor_swap', authority, collection])")]
+    /// Performs a swap, taking the fee recipients when the pool has a fee mint then the royalty creators as remaining accounts. Fails if the fees and royalties exceed max_fee or the pool sequence differs from expected_sequence
+    #[account(0, name="pool", desc = "The program derived address of the Pool account to toggle (seeds: ['floor_swap', creator, collection])")]
     #[account(1, writable, name="source_asset", desc = "The mpl asset to send to the protocol")]
     #[account(2, writable, name="dest_asset", desc = "The mpl asset to receive from the protocol")]
     #[account(3, writable, signer, name="payer", desc = "The user performing the swap")]
-    #[account(4, writable, name="treasury", desc = "The treasury where fees are sent")]
+    #[account(4, writable, name="fee_vault", desc = "The program derived address of the FeeVault account of the pool (seeds: ['fee_vault', pool])")]
     #[account(5, name="collection", desc = "The collection of the pool")]
     #[account(6, name="core_program", desc = "The MPL Core program")]
     #[account(7, name="system_program", desc = "The system program")]
-    Swap,
+    #[account(8, optional, name="fee_mint", desc = "The mint fees are paid in, when the pool has one")]
+    #[account(9, optional, writable, name="payer_token_account", desc = "The payer token account of the fee mint")]
+    #[account(10, optional, writable, name="treasury_token_account", desc = "The treasury token account of the fee mint")]
+    #[account(11, optional, name="token_program", desc = "The token program of the fee mint")]
+    #[account(12, optional, writable, name="referrer", desc = "The wallet of the front-end that referred the swap, receiving the referral share of the fee")]
+    #[account(13, optional, writable, name="referrer_token_account", desc = "The referrer token account of the fee mint")]
+    #[account(14, name="global_config", desc = "The program derived address of the GlobalConfig account (seeds: ['global_config'])")]
+    #[account(15, optional, writable, name="protocol_treasury", desc = "The protocol treasury of the global config, required when a protocol fee is charged")]
+    #[account(16, optional, writable, name="protocol_treasury_token_account", desc = "The protocol treasury token account of the fee mint")]
+    #[account(17, writable, name="inventory", desc = "The program derived address of the Inventory account of the pool (seeds: ['inventory', pool])")]
+    Swap { max_fee: u64, expected_sequence: Option<u64> },
 
     /// Deposits an asset
-    #[account(0, writable, name="pool", desc = "The PDA of the Pool account (seeds: ['floor_swap', authority, collection])")]
+    #[account(0, writable, name="pool", desc = "The PDA of the Pool account (seeds: ['floor_swap', creator, collection])")]
     #[account(1, writable, name="asset", desc = "The mpl-core asset to deposit")]
     #[account(2, name="collection", desc = "The collection of the asset")]
     #[account(3, signer, name="payer", desc = "The user depositing the asset")]
     #[account(4, name="core_program", desc = "The MPL Core program")]
+    #[account(5, name="global_config", desc = "The program derived address of the GlobalConfig account (seeds: ['global_config'])")]
+    #[account(6, writable, name="inventory", desc = "The program derived address of the Inventory account of the pool (seeds: ['inventory', pool])")]
+    #[account(7, name="system_program", desc = "The system program")]
     Deposit,
 
-    /// Withdraws an asset
-    #[account(0, writable, name="pool", desc = "The PDA of the Pool account (seeds: ['floor_swap', authority, collection])")]
+    /// Withdraws an asset of the inventory, taking the multisig signers as remaining accounts when the pool has one
+    #[account(0, writable, name="pool", desc = "The PDA of the Pool account (seeds: ['floor_swap', creator, collection])")]
     #[account(1, signer, name="authority", desc = "The authority of the pool")]
     #[account(2, writable, name="asset", desc = "The mpl-core asset to deposit")]
     #[account(3, name="collection", desc = "The collection of the asset")]
     #[account(4, optional, name="destination", desc = "The wallet to receive the asset")]
     #[account(5, name="core_program", desc = "The MPL Core program")]
+    #[account(6, name="global_config", desc = "The program derived address of the GlobalConfig account (seeds: ['global_config'])")]
+    #[account(7, writable, name="inventory", desc = "The program derived address of the Inventory account of the pool (seeds: ['inventory', pool])")]
     Withdraw,
 
-    /// Closes a pool
-    #[account(0, writable, name="pool", desc = "The PDA of the Pool account (seeds: ['floor_swap', authority, collection])")]
+    /// Closes a pool in the Closing status with its fee vault and inventory, the fee vault must not hold unclaimed fees. Takes the multisig signers as remaining accounts when the pool has one
+    #[account(0, writable, name="pool", desc = "The PDA of the Pool account (seeds: ['floor_swap', creator, collection])")]
+    #[account(1, writable, name="fee_vault", desc = "The program derived address of the FeeVault account of the pool (seeds: ['fee_vault', pool])")]
+    #[account(2, signer, name="authority", desc = "The authority of the pool")]
+    #[account(3, name="system_program", desc = "The system program")]
+    #[account(4, writable, name="inventory", desc = "The program derived address of the Inventory account of the pool (seeds: ['inventory', pool])")]
+    Close,
+
+    /// Performs several swaps at once, taking the fee recipients when the pool has a fee mint then (source_asset, dest_asset) pairs each followed by its royalty creators as remaining accounts. Fails if the fees and royalties of the whole batch exceed max_fee or the pool sequence differs from expected_sequence
+    #[account(0, name="pool", desc = "The program derived address of the Pool account (seeds: ['floor_swap', creator, collection])")]
+    #[account(1, writable, signer, name="payer", desc = "The user performing the swaps")]
+    #[account(2, writable, name="fee_vault", desc = "The program derived address of the FeeVault account of the pool (seeds: ['fee_vault', pool])")]
+    #[account(3, name="collection", desc = "The collection of the pool")]
+    #[account(4, name="core_program", desc = "The MPL Core program")]
+    #[account(5, name="system_program", desc = "The system program")]
+    #[account(6, optional, name="fee_mint", desc = "The mint fees are paid in, when the pool has one")]
+    #[account(7, optional, writable, name="payer_token_account", desc = "The payer token account of the fee mint")]
+    #[account(8, optional, writable, name="treasury_token_account", desc = "The treasury token account of the fee mint")]
+    #[account(9, optional, name="token_program", desc = "The token program of the fee mint")]
+    #[account(10, name="global_config", desc = "The program derived address of the GlobalConfig account (seeds: ['global_config'])")]
+    #[account(11, optional, writable, name="protocol_treasury", desc = "The protocol treasury of the global config, required when a protocol fee is charged")]
+    #[account(12, optional, writable, name="protocol_treasury_token_account", desc = "The protocol treasury token account of the fee mint")]
+    #[account(13, writable, name="inventory", desc = "The program derived address of the Inventory account of the pool (seeds: ['inventory', pool])")]
+    SwapBatch { max_fee: u64, expected_sequence: Option<u64> },
+
+    /// Deposits several assets, passed as remaining accounts
+    #[account(0, writable, name="pool", desc = "The PDA of the Pool account (seeds: ['floor_swap', creator, collection])")]
+    #[account(1, name="collection", desc = "The collection of the assets")]
+    #[account(2, writable, signer, name="payer", desc = "The user depositing the assets")]
+    #[account(3, name="core_program", desc = "The MPL Core program")]
+    #[account(4, name="global_config", desc = "The program derived address of the GlobalConfig account (seeds: ['global_config'])")]
+    #[account(5, writable, name="inventory", desc = "The program derived address of the Inventory account of the pool (seeds: ['inventory', pool])")]
+    #[account(6, name="system_program", desc = "The system program")]
+    DepositMany,
+
+    /// Withdraws several assets of the inventory, passed as remaining accounts after as many multisig signers as the threshold when the pool has a multisig
+    #[account(0, writable, name="pool", desc = "The PDA of the Pool account (seeds: ['floor_swap', creator, collection])")]
+    #[account(1, signer, name="authority", desc = "The authority of the pool")]
+    #[account(2, name="collection", desc = "The collection of the assets")]
+    #[account(3, optional, name="destination", desc = "The wallet to receive the assets")]
+    #[account(4, name="core_program", desc = "The MPL Core program")]
+    #[account(5, name="global_config", desc = "The program derived address of the GlobalConfig account (seeds: ['global_config'])")]
+    #[account(6, writable, name="inventory", desc = "The program derived address of the Inventory account of the pool (seeds: ['inventory', pool])")]
+    WithdrawMany,
+
+    /// Sets how the destination asset of a swap is picked. Takes the multisig signers as remaining accounts when the pool has one
+    #[account(0, writable, name="pool", desc = "The program derived address of the Pool account (seeds: ['floor_swap', creator, collection])")]
+    #[account(1, signer, name="authority", desc = "The authority of the pool")]
+    SetMode { mode: PoolMode },
+
+    /// Commits a mystery swap, escrowing the source asset and paying the fee recipients when the pool has a fee mint then the royalty creators passed as remaining accounts. Fails if the fees and royalties exceed max_fee or the pool sequence differs from expected_sequence
+    #[account(0, writable, name="pool", desc = "The program derived address of the Pool account (seeds: ['floor_swap', creator, collection])")]
+    #[account(1, writable, name="swap_commit", desc = "The program derived address of the SwapCommit account to create (seeds: ['swap_commit', pool, source_asset])")]
+    #[account(2, writable, name="source_asset", desc = "The mpl asset to send to the protocol")]
+    #[account(3, writable, signer, name="payer", desc = "The user performing the swap")]
+    #[account(4, writable, name="fee_vault", desc = "The program derived address of the FeeVault account of the pool (seeds: ['fee_vault', pool])")]
+    #[account(5, name="collection", desc = "The collection of the pool")]
+    #[account(6, name="core_program", desc = "The MPL Core program")]
+    #[account(7, name="system_program", desc = "The system program")]
+    #[account(8, optional, name="fee_mint", desc = "The mint fees are paid in, when the pool has one")]
+    #[account(9, optional, writable, name="payer_token_account", desc = "The payer token account of the fee mint")]
+    #[account(10, optional, writable, name="treasury_token_account", desc = "The treasury token account of the fee mint")]
+    #[account(11, optional, name="token_program", desc = "The token program of the fee mint")]
+    #[account(12, name="global_config", desc = "The program derived address of the GlobalConfig account (seeds: ['global_config'])")]
+    #[account(13, optional, writable, name="protocol_treasury", desc = "The protocol treasury of the global config, required when a protocol fee is charged")]
+    #[account(14, optional, writable, name="protocol_treasury_token_account", desc = "The protocol treasury token account of the fee mint")]
+    CommitSwap { max_fee: u64, expected_sequence: Option<u64> },
+
+    /// Reveals a mystery swap, sending the owner the inventory asset picked by the slot hash following the commit. Swaps are revealed in the order they were committed
+    #[account(0, writable, name="pool", desc = "The program derived address of the Pool account (seeds: ['floor_swap', creator, collection])")]
+    #[account(1, writable, name="swap_commit", desc = "The program derived address of the SwapCommit account (seeds: ['swap_commit', pool, source_asset])")]
+    #[account(2, writable, name="source_asset", desc = "The mpl asset escrowed by the swap commit")]
+    #[account(3, writable, name="owner", desc = "The user who committed the swap, receiving the picked asset and the commit rent")]
+    #[account(4, writable, signer, name="payer", desc = "The account paying for the reveal")]
+    #[account(5, name="collection", desc = "The collection of the pool")]
+    #[account(6, name="slot_hashes", desc = "The SlotHashes sysvar")]
+    #[account(7, name="core_program", desc = "The MPL Core program")]
+    #[account(8, writable, name="inventory", desc = "The program derived address of the Inventory account of the pool (seeds: ['inventory', pool])")]
+    #[account(9, writable, name="dest_asset", desc = "The inventory asset picked for the swap")]
+    #[account(10, name="global_config", desc = "The program derived address of the GlobalConfig account (seeds: ['global_config'])")]
+    RevealSwap,
+
+    /// Cancels an expired mystery swap, forfeiting the source asset to the pool and refunding the commit rent to the owner. Swaps are revealed or cancelled in the order they were committed, and anyone can cancel
+    #[account(0, writable, name="pool", desc = "The program derived address of the Pool account (seeds: ['floor_swap', creator, collection])")]
+    #[account(1, writable, name="swap_commit", desc = "The program derived address of the SwapCommit account (seeds: ['swap_commit', pool, source_asset])")]
+    #[account(2, writable, name="source_asset", desc = "The mpl asset escrowed by the swap commit")]
+    #[account(3, writable, name="owner", desc = "The user who committed the swap, receiving the commit rent")]
+    #[account(4, name="collection", desc = "The collection of the pool")]
+    #[account(5, name="slot_hashes", desc = "The SlotHashes sysvar")]
+    #[account(6, name="core_program", desc = "The MPL Core program")]
+    #[account(7, writable, name="inventory", desc = "The program derived address of the Inventory account of the pool (seeds: ['inventory', pool])")]
+    #[account(8, writable, signer, name="payer", desc = "The account paying for the cancellation")]
+    #[account(9, name="system_program", desc = "The system program")]
+    #[account(10, name="global_config", desc = "The program derived address of the GlobalConfig account (seeds: ['global_config'])")]
+    CancelSwap,
+
+    /// Sets the reference price used by basis point fee models and reference price royalties. Increases that raise the swap fee or royalties must go through ProposeFee. Takes the multisig signers as remaining accounts when the pool has one
+    #[account(0, writable, name="pool", desc = "The program derived address of the Pool account (seeds: ['floor_swap', creator, collection])")]
     #[account(1, signer, name="authority", desc = "The authority of the pool")]
-    #[account(2, name="system_program", desc = "The system program")]
-    Close
+    SetReferencePrice { reference_price: u64 },
+
+    /// Sets the amount creator royalties are charged on. Switches that raise the royalties must go through ProposeFee. Takes the multisig signers as remaining accounts when the pool has one
+    #[account(0, writable, name="pool", desc = "The program derived address of the Pool account (seeds: ['floor_swap', creator, collection])")]
+    #[account(1, signer, name="authority", desc = "The authority of the pool")]
+    SetRoyaltyBase { royalty_base: RoyaltyBase },
+
+    /// Sets how the swap fee is split between recipients, the treasury receiving the rounding dust. Takes the multisig signers as remaining accounts when the pool has one
+    #[account(0, writable, name="pool", desc = "The program derived address of the Pool account (seeds: ['floor_swap', creator, collection])")]
+    #[account(1, signer, name="authority", desc = "The authority of the pool")]
+    SetFeeSplit { fee_split: Vec<FeeRecipient> },
+
+    /// Claims the fees accrued in the fee vault, splitting them between the fee recipients passed as remaining accounts and the treasury
+    #[account(0, name="pool", desc = "The program derived address of the Pool account (seeds: ['floor_swap', creator, collection])")]
+    #[account(1, writable, name="fee_vault", desc = "The program derived address of the FeeVault account of the pool (seeds: ['fee_vault', pool])")]
+    #[account(2, signer, name="claimer", desc = "The authority or the treasury of the pool")]
+    #[account(3, writable, name="treasury", desc = "The treasury receiving the rounding dust of the fee split")]
+    ClaimFees,
+
+    /// Sets the share of the swap fee paid to the referrer of a swap, up to the maximum set when the pool was created. Takes the multisig signers as remaining accounts when the pool has one
+    #[account(0, writable, name="pool", desc = "The program derived address of the Pool account (seeds: ['floor_swap', creator, collection])")]
+    #[account(1, signer, name="authority", desc = "The authority of the pool")]
+    SetReferralFee { referral_bps: u16 },
+
+    /// Proposes a new swap fee, and optionally a new reference price and royalty base, which can be applied once the fee delay of the pool has elapsed. Takes the multisig signers as remaining accounts when the pool has one
+    #[account(0, writable, name="pool", desc = "The program derived address of the Pool account (seeds: ['floor_swap', creator, collection])")]
+    #[account(1, signer, name="authority", desc = "The authority or the fee manager of the pool")]
+    ProposeFee { fee_model: FeeModel, fee_mint: Option<Pubkey>, reference_price: Option<u64>, royalty_base: Option<RoyaltyBase> },
+
+    /// Applies the pending fee of a pool once it is effective
+    #[account(0, writable, name="pool", desc = "The program derived address of the Pool account (seeds: ['floor_swap', creator, collection])")]
+    ApplyFee,
+
+    /// Updates the given settings of a pool at once, with the same rules as their own instructions. The fee can only be lowered, including through the reference price. Takes the multisig signers as remaining accounts when the pool has one
+    #[account(0, writable, name="pool", desc = "The program derived address of the Pool account (seeds: ['floor_swap', creator, collection])")]
+    #[account(1, signer, name="authority", desc = "The authority of the pool")]
+    #[account(2, optional, name="treasury", desc = "The new treasury where claimed fees are sent")]
+    UpdatePool {
+        fee_model: Option<FeeModel>,
+        status: Option<PoolStatus>,
+        mode: Option<PoolMode>,
+        reference_price: Option<u64>,
+        royalty_base: Option<RoyaltyBase>,
+        referral_bps: Option<u16>,
+    },
+
+    /// Proposes a new authority for the pool, which takes over once it accepts. Omitting the new authority cancels the pending transfer
+    #[account(0, writable, name="pool", desc = "The program derived address of the Pool account (seeds: ['floor_swap', creator, collection])")]
+    #[account(1, signer, name="authority", desc = "The authority of the pool")]
+    #[account(2, optional, name="new_authority", desc = "The proposed authority of the pool")]
+    ProposeAuthority,
+
+    /// Accepts the authority of a pool proposed with ProposeAuthority
+    #[account(0, writable, name="pool", desc = "The program derived address of the Pool account (seeds: ['floor_swap', creator, collection])")]
+    #[account(1, signer, name="new_authority", desc = "The proposed authority of the pool")]
+    AcceptAuthority,
+
+    /// Sets the keys holding the operator and fee manager roles of the pool. Omitted roles are revoked. Takes the multisig signers as remaining accounts when the pool has one
+    #[account(0, writable, name="pool", desc = "The program derived address of the Pool account (seeds: ['floor_swap', creator, collection])")]
+    #[account(1, signer, name="authority", desc = "The authority of the pool")]
+    #[account(2, optional, name="operator", desc = "The key allowed to pause and resume the pool")]
+    #[account(3, optional, name="fee_manager", desc = "The key allowed to lower and propose swap fees")]
+    SetRoles,
+
+    /// Sets the signers and threshold of the pool multisig, or removes it with no signers. Takes the signers of the current multisig as remaining accounts when the pool has one
+    #[account(0, writable, name="pool", desc = "The program derived address of the Pool account (seeds: ['floor_swap', creator, collection])")]
+    #[account(1, signer, name="authority", desc = "The authority of the pool")]
+    SetMultisig { threshold: u8, signers: Vec<Pubkey> },
+
+    /// Creates the program-wide config, signed by the upgrade authority of the program
+    #[account(0, writable, name="global_config", desc = "The program derived address of the GlobalConfig account (seeds: ['global_config'])")]
+    #[account(1, signer, name="admin", desc = "The upgrade authority of the program")]
+    #[account(2, writable, signer, name="payer", desc = "The account paying for the config")]
+    #[account(3, name="program_data", desc = "The program data account of the program, holding its upgrade authority")]
+    #[account(4, name="system_program", desc = "The system program")]
+    InitGlobalConfig { guardians: Vec<Pubkey> },
+
+    /// Pauses or resumes the program, replaces its guardians and sets the protocol fee and treasury, signed by the upgrade authority of the program
+    #[account(0, writable, name="global_config", desc = "The program derived address of the GlobalConfig account (seeds: ['global_config'])")]
+    #[account(1, signer, name="admin", desc = "The upgrade authority of the program")]
+    #[account(2, name="program_data", desc = "The program data account of the program, holding its upgrade authority")]
+    UpdateGlobalConfig {
+        paused: Option<bool>,
+        guardians: Option<Vec<Pubkey>>,
+        protocol_fee: Option<FeeModel>,
+        protocol_treasury: Option<Pubkey>,
+    },
+
+    /// Halts swaps, deposits and withdrawals on every pool until the admin resumes the program
+    #[account(0, writable, name="global_config", desc = "The program derived address of the GlobalConfig account (seeds: ['global_config'])")]
+    #[account(1, signer, name="guardian", desc = "The admin or a guardian of the program")]
+    PauseProgram,
+
+    /// Adds the assets held by the pool but missing from its inventory, such as assets sent without Deposit, drops the tracked assets it no longer holds, and resyncs its asset count. Takes the assets as remaining accounts and can be called by anyone
+    #[account(0, writable, name="pool", desc = "The program derived address of the Pool account (seeds: ['floor_swap', creator, collection])")]
+    #[account(1, writable, name="inventory", desc = "The program derived address of the Inventory account of the pool (seeds: ['inventory', pool])")]
+    #[account(2, name="collection", desc = "The collection of the pool")]
+    #[account(3, writable, signer, name="payer", desc = "The account paying for the inventory to grow")]
+    #[account(4, name="system_program", desc = "The system program")]
+    Reconcile,
+
+    /// Withdraws every asset of the inventory then closes a pool in the Closing status with its fee vault and inventory, the fee vault must not hold unclaimed fees. Takes the assets as remaining accounts after as many multisig signers as the threshold when the pool has a multisig
+    #[account(0, writable, name="pool", desc = "The program derived address of the Pool account (seeds: ['floor_swap', creator, collection])")]
+    #[account(1, writable, name="fee_vault", desc = "The program derived address of the FeeVault account of the pool (seeds: ['fee_vault', pool])")]
+    #[account(2, writable, name="inventory", desc = "The program derived address of the Inventory account of the pool (seeds: ['inventory', pool])")]
+    #[account(3, writable, signer, name="authority", desc = "The authority of the pool")]
+    #[account(4, name="collection", desc = "The collection of the assets")]
+    #[account(5, optional, name="destination", desc = "The wallet to receive the assets")]
+    #[account(6, optional, writable, name="rent_receiver", desc = "The account to receive the rent of the closed accounts")]
+    #[account(7, name="core_program", desc = "The MPL Core program")]
+    #[account(8, name="global_config", desc = "The program derived address of the GlobalConfig account (seeds: ['global_config'])")]
+    CloseAndDrain,
+
+    /// Rewrites a pool created with the original layout to the current one, creating its fee vault and inventory. The flat fee and the enabled flag carry over, and the pool keeps its address. Takes the assets held by the pool as remaining accounts, more can be added later with Reconcile
+    #[account(0, writable, name="pool", desc = "The program derived address of the Pool account (seeds: ['floor_swap', creator, collection])")]
+    #[account(1, writable, name="fee_vault", desc = "The program derived address of the FeeVault account to create (seeds: ['fee_vault', pool])")]
+    #[account(2, writable, name="inventory", desc = "The program derived address of the Inventory account to create (seeds: ['inventory', pool])")]
+    #[account(3, signer, name="authority", desc = "The authority of the pool")]
+    #[account(4, name="collection", desc = "The collection of the pool")]
+    #[account(5, writable, signer, name="payer", desc = "The account paying for the storage fees")]
+    #[account(6, name="system_program", desc = "The system program")]
+    Migrate { max_fee: u64, fee_delay: u32, max_referral_bps: u16 },
 }
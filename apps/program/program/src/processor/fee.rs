@@ -0,0 +1,364 @@
+use mpl_core::{types::Royalties, Asset, Collection};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::assertions::{
+    assert_program_owner, assert_program_owner_either, assert_same_pubkeys, assert_token_account,
+    assert_writable,
+};
+use crate::error::FloorSwapError;
+use crate::state::{fee_vault::FeeVault, global_config::GlobalConfig, pool::Pool, Checked};
+use crate::utils::{
+    apply_bps, pay_fee, transfer_tokens_checked, unpack_mint, unpack_token_account, MAX_BPS,
+    TOKEN_PROGRAM_IDS,
+};
+
+/// Creator shares of the royalties are expressed in percent.
+const MAX_CREATOR_PERCENTAGE: u128 = 100;
+
+/// Get the royalties of a swap from the collection, falling back to the asset.
+pub(crate) fn find_royalties(collection: &Collection, asset: &Asset) -> Option<Royalties> {
+    collection
+        .plugin_list
+        .royalties
+        .as_ref()
+        .or(asset.plugin_list.royalties.as_ref())
+        .map(|plugin| plugin.royalties.clone())
+}
+
+/// Get the royalties a creator with the given share receives on the given base amount.
+fn creator_royalty(
+    royalties: &Royalties,
+    percentage: u8,
+    base_amount: u64,
+) -> Result<u64, ProgramError> {
+    (base_amount as u128)
+        .checked_mul(royalties.basis_points as u128)
+        .and_then(|value| value.checked_mul(percentage as u128))
+        .map(|value| value / (MAX_BPS as u128 * MAX_CREATOR_PERCENTAGE))
+        .and_then(|value| u64::try_from(value).ok())
+        .ok_or(FloorSwapError::NumericalOverflow.into())
+}
+
+/// Get the total royalties charged on the given base amount, as paid out to the creators.
+pub(crate) fn total_royalties(
+    royalties: Option<&Royalties>,
+    base_amount: u64,
+) -> Result<u64, ProgramError> {
+    royalties.map_or(Ok(0), |royalties| {
+        royalties.creators.iter().try_fold(0u64, |total, creator| {
+            total
+                .checked_add(creator_royalty(royalties, creator.percentage, base_amount)?)
+                .ok_or(FloorSwapError::NumericalOverflow.into())
+        })
+    })
+}
+
+/// Split the accounts of the pool fee split off the front of the remaining accounts.
+///
+/// Fees paid in lamports accrue in the fee vault and are split when claimed, so fee
+/// recipients are only passed to swaps when the pool has a fee mint.
+pub(crate) fn split_fee_recipients<'a, 'b>(
+    pool: &Pool,
+    accounts: &'a [AccountInfo<'b>],
+) -> Result<(&'a [AccountInfo<'b>], &'a [AccountInfo<'b>]), ProgramError> {
+    let num_recipients = if pool.fee_mint.is_some() {
+        pool.fee_split.len()
+    } else {
+        0
+    };
+
+    if accounts.len() < num_recipients {
+        msg!(
+            "Expected {} fee recipient accounts, got {}",
+            num_recipients,
+            accounts.len()
+        );
+        return Err(FloorSwapError::InvalidRemainingAccounts.into());
+    }
+
+    Ok(accounts.split_at(num_recipients))
+}
+
+/// The accounts a swap fee can be paid with.
+///
+/// The token accounts are only required when the pool has a fee mint.
+pub(crate) struct FeeAccounts<'a, 'b> {
+    pub payer: &'a AccountInfo<'b>,
+    pub fee_vault: &'a AccountInfo<'b>,
+    pub fee_mint: Option<&'a AccountInfo<'b>>,
+    pub payer_token_account: Option<&'a AccountInfo<'b>>,
+    pub treasury_token_account: Option<&'a AccountInfo<'b>>,
+    pub token_program: Option<&'a AccountInfo<'b>>,
+    /// The fee split recipients, in the order of the pool fee split, when the pool has a
+    /// fee mint.
+    pub fee_recipients: &'a [AccountInfo<'b>],
+    /// The wallet of the front-end that referred the swap, if any.
+    pub referrer: Option<&'a AccountInfo<'b>>,
+    /// The referrer token account, required with a referrer when the pool has a fee mint.
+    pub referrer_token_account: Option<&'a AccountInfo<'b>>,
+    /// The protocol treasury, required when a protocol fee is charged.
+    pub protocol_treasury: Option<&'a AccountInfo<'b>>,
+    /// The protocol treasury token account, required when a protocol fee is charged and
+    /// the pool has a fee mint.
+    pub protocol_treasury_token_account: Option<&'a AccountInfo<'b>>,
+}
+
+/// The validated accounts used to pay in the pool fee mint.
+struct FeeToken<'a, 'b> {
+    payer: &'a AccountInfo<'b>,
+    mint: &'a AccountInfo<'b>,
+    payer_token_account: &'a AccountInfo<'b>,
+    token_program: &'a AccountInfo<'b>,
+    decimals: u8,
+}
+
+impl<'a, 'b> FeeToken<'a, 'b> {
+    /// Transfer tokens from the payer to a token account of the given owner.
+    fn transfer(
+        &self,
+        account_name: &str,
+        destination: &'a AccountInfo<'b>,
+        owner: &Pubkey,
+        amount: u64,
+    ) -> ProgramResult {
+        assert_program_owner(account_name, destination, self.mint.owner)?;
+        assert_token_account(
+            account_name,
+            destination,
+            &unpack_token_account(account_name, destination)?,
+            self.mint.key,
+            owner,
+        )?;
+        assert_writable(account_name, destination)?;
+
+        transfer_tokens_checked(
+            self.token_program,
+            self.payer_token_account,
+            self.mint,
+            destination,
+            self.payer,
+            amount,
+            self.decimals,
+        )
+    }
+}
+
+impl<'a, 'b> FeeAccounts<'a, 'b> {
+    /// Pay the given fee, in lamports to the pool fee vault or in the pool fee mint.
+    ///
+    /// The referral share of the pool goes to the referrer, if any. Fees paid in the fee
+    /// mint are split between the fee recipients of the pool right away, with the rounding
+    /// dust going to the treasury.
+    pub(crate) fn pay(&self, pool: &Checked<Pool>, amount: u64) -> ProgramResult {
+        let mut fee_vault = Checked::<FeeVault>::load_mut("fee_vault", self.fee_vault)?;
+        assert_same_pubkeys("pool", pool.info(), &fee_vault.pool)?;
+
+        let fee_token = self.fee_token(pool)?;
+        let amount = self.pay_referral(pool, fee_token.as_ref(), amount)?;
+
+        let Some(fee_token) = fee_token else {
+            pay_fee(self.payer, self.fee_vault, amount)?;
+            fee_vault.lifetime_fees = fee_vault
+                .lifetime_fees
+                .checked_add(amount)
+                .ok_or(FloorSwapError::NumericalOverflow)?;
+            return fee_vault.save();
+        };
+
+        if self.fee_recipients.len() != pool.fee_split.len() {
+            msg!(
+                "Expected {} fee recipient accounts, got {}",
+                pool.fee_split.len(),
+                self.fee_recipients.len()
+            );
+            return Err(FloorSwapError::InvalidRemainingAccounts.into());
+        }
+
+        let (shares, dust) = pool.split_fee(amount)?;
+        for ((recipient, account), share) in
+            pool.fee_split.iter().zip(self.fee_recipients).zip(shares)
+        {
+            fee_token.transfer(
+                "fee_recipient_token_account",
+                account,
+                &recipient.address,
+                share,
+            )?;
+        }
+
+        fee_token.transfer(
+            "treasury_token_account",
+            self.treasury_token_account
+                .ok_or(FloorSwapError::MissingFeeTokenAccounts)?,
+            &pool.treasury,
+            dust,
+        )
+    }
+
+    /// Pay the referral share of the given fee to the referrer, returning what is left.
+    fn pay_referral(
+        &self,
+        pool: &Pool,
+        fee_token: Option<&FeeToken<'a, 'b>>,
+        amount: u64,
+    ) -> Result<u64, ProgramError> {
+        let Some(referrer) = self.referrer else {
+            return Ok(amount);
+        };
+
+        let referral = apply_bps(amount, pool.referral_bps)?;
+
+        match fee_token {
+            Some(fee_token) => fee_token.transfer(
+                "referrer_token_account",
+                self.referrer_token_account
+                    .ok_or(FloorSwapError::MissingFeeTokenAccounts)?,
+                referrer.key,
+                referral,
+            )?,
+            None => {
+                assert_writable("referrer", referrer)?;
+                if referral > 0 {
+                    pay_fee(self.payer, referrer, referral)?;
+                }
+            }
+        }
+
+        amount
+            .checked_sub(referral)
+            .ok_or(FloorSwapError::NumericalOverflow.into())
+    }
+
+    /// Pay the protocol fee to the protocol treasury of the global config.
+    ///
+    /// Nothing is charged before the global config is initialised or while the protocol
+    /// fee is zero, so the protocol treasury accounts can be omitted.
+    pub(crate) fn pay_protocol_fee(
+        &self,
+        pool: &Pool,
+        global_config: Option<&GlobalConfig>,
+        amount: u64,
+    ) -> ProgramResult {
+        let Some(global_config) = global_config else {
+            return Ok(());
+        };
+        if amount == 0 {
+            return Ok(());
+        }
+
+        let protocol_treasury = self
+            .protocol_treasury
+            .ok_or(FloorSwapError::MissingProtocolTreasury)?;
+        assert_same_pubkeys(
+            "protocol_treasury",
+            protocol_treasury,
+            &global_config.protocol_treasury,
+        )?;
+
+        match self.fee_token(pool)? {
+            Some(fee_token) => fee_token.transfer(
+                "protocol_treasury_token_account",
+                self.protocol_treasury_token_account
+                    .ok_or(FloorSwapError::MissingProtocolTreasury)?,
+                protocol_treasury.key,
+                amount,
+            ),
+            None => {
+                assert_writable("protocol_treasury", protocol_treasury)?;
+                pay_fee(self.payer, protocol_treasury, amount)
+            }
+        }
+    }
+
+    /// Pay every creator their share of the royalties charged on the given base amount.
+    ///
+    /// Creator accounts are expected in the order of the plugin: wallets when fees are
+    /// paid in lamports, or token accounts of the fee mint otherwise.
+    pub(crate) fn pay_royalties(
+        &self,
+        pool: &Pool,
+        royalties: &Royalties,
+        base_amount: u64,
+        creators: &'a [AccountInfo<'b>],
+    ) -> ProgramResult {
+        if creators.len() != royalties.creators.len() {
+            msg!(
+                "Expected {} creator accounts, got {}",
+                royalties.creators.len(),
+                creators.len()
+            );
+            return Err(FloorSwapError::InvalidRemainingAccounts.into());
+        }
+
+        let fee_token = self.fee_token(pool)?;
+
+        for (creator, account) in royalties.creators.iter().zip(creators) {
+            let amount = creator_royalty(royalties, creator.percentage, base_amount)?;
+
+            match &fee_token {
+                Some(fee_token) => fee_token.transfer(
+                    "creator_token_account",
+                    account,
+                    &creator.address,
+                    amount,
+                )?,
+                None => {
+                    assert_same_pubkeys("creator", account, &creator.address)?;
+                    assert_writable("creator", account)?;
+                    if amount > 0 {
+                        pay_fee(self.payer, account, amount)?;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Validate the fee mint accounts when the pool has a fee mint.
+    fn fee_token(&self, pool: &Pool) -> Result<Option<FeeToken<'a, 'b>>, ProgramError> {
+        let fee_mint = match pool.fee_mint {
+            Some(fee_mint) => fee_mint,
+            None => return Ok(None),
+        };
+
+        let (Some(mint), Some(payer_token_account), Some(_), Some(token_program)) = (
+            self.fee_mint,
+            self.payer_token_account,
+            self.treasury_token_account,
+            self.token_program,
+        ) else {
+            msg!(
+                "Fees are paid in mint [{}], expected fee_mint, payer_token_account, treasury_token_account and token_program",
+                fee_mint
+            );
+            return Err(FloorSwapError::MissingFeeTokenAccounts.into());
+        };
+
+        assert_same_pubkeys("fee_mint", mint, &fee_mint)?;
+        assert_program_owner_either("fee_mint", mint, &TOKEN_PROGRAM_IDS)?;
+        assert_same_pubkeys("token_program", token_program, mint.owner)?;
+        let decimals = unpack_mint("fee_mint", mint)?.decimals;
+
+        assert_program_owner("payer_token_account", payer_token_account, mint.owner)?;
+        assert_token_account(
+            "payer_token_account",
+            payer_token_account,
+            &unpack_token_account("payer_token_account", payer_token_account)?,
+            &fee_mint,
+            self.payer.key,
+        )?;
+        assert_writable("payer_token_account", payer_token_account)?;
+
+        Ok(Some(FeeToken {
+            payer: self.payer,
+            mint,
+            payer_token_account,
+            token_program,
+            decimals,
+        }))
+    }
+}
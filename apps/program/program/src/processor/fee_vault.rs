@@ -0,0 +1,52 @@
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg};
+
+use crate::assertions::{
+    assert_same_pubkeys, assert_same_pubkeys_either, assert_signer, assert_writable,
+};
+use crate::error::FloorSwapError;
+use crate::instruction::accounts::ClaimFeesAccounts;
+use crate::state::fee_vault::FeeVault;
+use crate::state::pool::Pool;
+use crate::state::Checked;
+use crate::utils::transfer_lamports_from_pdas;
+
+pub(crate) fn claim_fees<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    // Accounts.
+    let ctx = ClaimFeesAccounts::context(accounts)?;
+
+    // Guards.
+    let pool = Checked::<Pool>::load("pool", ctx.accounts.pool)?;
+    let fee_vault = Checked::<FeeVault>::load_mut("fee_vault", ctx.accounts.fee_vault)?;
+    assert_same_pubkeys("pool", ctx.accounts.pool, &fee_vault.pool)?;
+
+    assert_signer("claimer", ctx.accounts.claimer)?;
+    assert_same_pubkeys_either(
+        "claimer",
+        ctx.accounts.claimer,
+        &[pool.authority, pool.treasury],
+    )?;
+
+    assert_same_pubkeys("treasury", ctx.accounts.treasury, &pool.treasury)?;
+    assert_writable("treasury", ctx.accounts.treasury)?;
+
+    // Remaining accounts are the fee recipients.
+    let fee_recipients = ctx.remaining_accounts;
+    if fee_recipients.len() != pool.fee_split.len() {
+        msg!(
+            "Expected {} fee recipient accounts, got {}",
+            pool.fee_split.len(),
+            fee_recipients.len()
+        );
+        return Err(FloorSwapError::InvalidRemainingAccounts.into());
+    }
+
+    let (shares, dust) = pool.split_fee(fee_vault.claimable()?)?;
+
+    for ((recipient, account), share) in pool.fee_split.iter().zip(fee_recipients).zip(shares) {
+        assert_same_pubkeys("fee_recipient", account, &recipient.address)?;
+        assert_writable("fee_recipient", account)?;
+        transfer_lamports_from_pdas(ctx.accounts.fee_vault, account, share)?;
+    }
+
+    transfer_lamports_from_pdas(ctx.accounts.fee_vault, ctx.accounts.treasury, dust)
+}
@@ -0,0 +1,124 @@
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey, system_program,
+};
+
+use crate::assertions::{
+    assert_empty, assert_guardians, assert_pda, assert_protocol_fee, assert_same_pubkeys,
+    assert_same_pubkeys_either, assert_signer, assert_upgrade_authority, assert_writable,
+};
+use crate::instruction::accounts::{
+    InitGlobalConfigAccounts, PauseProgramAccounts, UpdateGlobalConfigAccounts,
+};
+use crate::state::global_config::GlobalConfig;
+use crate::state::pool::FeeModel;
+use crate::state::{Checked, Key, SolanaAccount};
+use crate::utils::create_account;
+
+pub(crate) fn init_global_config<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    guardians: Vec<Pubkey>,
+) -> ProgramResult {
+    // Accounts.
+    let ctx = InitGlobalConfigAccounts::context(accounts)?;
+
+    // Guards.
+    let bump = assert_pda(
+        "global_config",
+        ctx.accounts.global_config,
+        &crate::ID,
+        &GlobalConfig::seeds(),
+    )?;
+    assert_writable("global_config", ctx.accounts.global_config)?;
+    assert_empty("global_config", ctx.accounts.global_config)?;
+
+    assert_signer("admin", ctx.accounts.admin)?;
+    assert_upgrade_authority("admin", ctx.accounts.admin, ctx.accounts.program_data)?;
+    assert_signer("payer", ctx.accounts.payer)?;
+    assert_writable("payer", ctx.accounts.payer)?;
+    assert_same_pubkeys(
+        "system_program",
+        ctx.accounts.system_program,
+        &system_program::id(),
+    )?;
+    assert_guardians("guardians", &guardians)?;
+
+    // Create GlobalConfig PDA.
+    let global_config = GlobalConfig {
+        key: Key::GlobalConfig,
+        admin: *ctx.accounts.admin.key,
+        paused: false,
+        bump,
+        // The protocol fee starts at zero, paid to the admin once it is raised.
+        protocol_treasury: *ctx.accounts.admin.key,
+        protocol_fee: FeeModel::Flat { lamports: 0 },
+        guardians,
+    };
+    let mut seeds = GlobalConfig::seeds();
+    let bump = [bump];
+    seeds.push(&bump);
+    create_account(
+        ctx.accounts.global_config,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+        GlobalConfig::LEN,
+        &crate::ID,
+        Some(&[&seeds]),
+    )?;
+
+    global_config.save(ctx.accounts.global_config, 0)
+}
+
+pub(crate) fn update_global_config<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    paused: Option<bool>,
+    guardians: Option<Vec<Pubkey>>,
+    protocol_fee: Option<FeeModel>,
+    protocol_treasury: Option<Pubkey>,
+) -> ProgramResult {
+    // Accounts.
+    let ctx = UpdateGlobalConfigAccounts::context(accounts)?;
+
+    // Guards.
+    let mut global_config =
+        Checked::<GlobalConfig>::load_mut("global_config", ctx.accounts.global_config)?;
+    assert_signer("admin", ctx.accounts.admin)?;
+    assert_upgrade_authority("admin", ctx.accounts.admin, ctx.accounts.program_data)?;
+
+    // The upgrade authority may have changed since the config was last touched.
+    global_config.admin = *ctx.accounts.admin.key;
+
+    if let Some(paused) = paused {
+        global_config.paused = paused;
+    }
+
+    if let Some(guardians) = guardians {
+        assert_guardians("guardians", &guardians)?;
+        global_config.guardians = guardians;
+    }
+
+    if let Some(protocol_fee) = protocol_fee {
+        assert_protocol_fee("protocol_fee", &protocol_fee)?;
+        global_config.protocol_fee = protocol_fee;
+    }
+
+    if let Some(protocol_treasury) = protocol_treasury {
+        global_config.protocol_treasury = protocol_treasury;
+    }
+
+    global_config.save()
+}
+
+pub(crate) fn pause_program<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    // Accounts.
+    let ctx = PauseProgramAccounts::context(accounts)?;
+
+    // Guards.
+    let mut global_config =
+        Checked::<GlobalConfig>::load_mut("global_config", ctx.accounts.global_config)?;
+    assert_signer("guardian", ctx.accounts.guardian)?;
+    assert_same_pubkeys_either("guardian", ctx.accounts.guardian, &global_config.pausers())?;
+
+    // Only the admin can resume the program, with `UpdateGlobalConfig`.
+    global_config.paused = true;
+    global_config.save()
+}
@@ -1,6 +1,18 @@
+mod fee;
+pub(crate) use fee::*;
+
+mod fee_vault;
+pub(crate) use fee_vault::*;
+
+mod global_config;
+pub(crate) use global_config::*;
+
 mod pool;
 pub(crate) use pool::*;
 
+mod swap_commit;
+pub(crate) use swap_commit::*;
+
 use borsh::BorshDeserialize;
 
 use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg, pubkey::Pubkey};
@@ -14,21 +26,42 @@ pub fn process_instruction<'a>(
 ) -> ProgramResult {
     let instruction: AppInstruction = AppInstruction::try_from_slice(instruction_data)?;
     match instruction {
-        AppInstruction::Create { fee_amount } => {
+        AppInstruction::Create {
+            fee_model,
+            fee_mint,
+            max_fee,
+            fee_delay,
+            max_referral_bps,
+            index,
+        } => {
             msg!("Instruction: Create");
-            create(accounts, fee_amount)
+            create(
+                accounts,
+                fee_model,
+                fee_mint,
+                max_fee,
+                fee_delay,
+                max_referral_bps,
+                index,
+            )
         }
-        AppInstruction::SetActive { active } => {
-            msg!("Instruction: Increment");
-            set_active(accounts, active)
+        AppInstruction::SetStatus { status } => {
+            msg!("Instruction: SetStatus");
+            set_status(accounts, status)
         }
-        AppInstruction::SetFee { fee_amount } => {
+        AppInstruction::SetFee {
+            fee_model,
+            fee_mint,
+        } => {
             msg!("Instruction: SetFee");
-            set_fee(accounts, fee_amount)
+            set_fee(accounts, fee_model, fee_mint)
         }
-        AppInstruction::Swap => {
+        AppInstruction::Swap {
+            max_fee,
+            expected_sequence,
+        } => {
             msg!("Instruction: Swap");
-            swap(accounts)
+            swap(accounts, max_fee, expected_sequence)
         }
         AppInstruction::Deposit => {
             msg!("Instruction: Deposit");
@@ -42,5 +75,140 @@ pub fn process_instruction<'a>(
             msg!("Instruction: Close");
             close(accounts)
         }
+        AppInstruction::SwapBatch {
+            max_fee,
+            expected_sequence,
+        } => {
+            msg!("Instruction: SwapBatch");
+            swap_batch(accounts, max_fee, expected_sequence)
+        }
+        AppInstruction::DepositMany => {
+            msg!("Instruction: DepositMany");
+            deposit_many(accounts)
+        }
+        AppInstruction::WithdrawMany => {
+            msg!("Instruction: WithdrawMany");
+            withdraw_many(accounts)
+        }
+        AppInstruction::SetMode { mode } => {
+            msg!("Instruction: SetMode");
+            set_mode(accounts, mode)
+        }
+        AppInstruction::CommitSwap {
+            max_fee,
+            expected_sequence,
+        } => {
+            msg!("Instruction: CommitSwap");
+            commit_swap(accounts, max_fee, expected_sequence)
+        }
+        AppInstruction::RevealSwap => {
+            msg!("Instruction: RevealSwap");
+            reveal_swap(accounts)
+        }
+        AppInstruction::CancelSwap => {
+            msg!("Instruction: CancelSwap");
+            cancel_swap(accounts)
+        }
+        AppInstruction::SetReferencePrice { reference_price } => {
+            msg!("Instruction: SetReferencePrice");
+            set_reference_price(accounts, reference_price)
+        }
+        AppInstruction::SetRoyaltyBase { royalty_base } => {
+            msg!("Instruction: SetRoyaltyBase");
+            set_royalty_base(accounts, royalty_base)
+        }
+        AppInstruction::SetFeeSplit { fee_split } => {
+            msg!("Instruction: SetFeeSplit");
+            set_fee_split(accounts, fee_split)
+        }
+        AppInstruction::ClaimFees => {
+            msg!("Instruction: ClaimFees");
+            claim_fees(accounts)
+        }
+        AppInstruction::SetReferralFee { referral_bps } => {
+            msg!("Instruction: SetReferralFee");
+            set_referral_fee(accounts, referral_bps)
+        }
+        AppInstruction::ProposeFee {
+            fee_model,
+            fee_mint,
+            reference_price,
+            royalty_base,
+        } => {
+            msg!("Instruction: ProposeFee");
+            propose_fee(accounts, fee_model, fee_mint, reference_price, royalty_base)
+        }
+        AppInstruction::ApplyFee => {
+            msg!("Instruction: ApplyFee");
+            apply_fee(accounts)
+        }
+        AppInstruction::UpdatePool {
+            fee_model,
+            status,
+            mode,
+            reference_price,
+            royalty_base,
+            referral_bps,
+        } => {
+            msg!("Instruction: UpdatePool");
+            update_pool(
+                accounts,
+                fee_model,
+                status,
+                mode,
+                reference_price,
+                royalty_base,
+                referral_bps,
+            )
+        }
+        AppInstruction::ProposeAuthority => {
+            msg!("Instruction: ProposeAuthority");
+            propose_authority(accounts)
+        }
+        AppInstruction::AcceptAuthority => {
+            msg!("Instruction: AcceptAuthority");
+            accept_authority(accounts)
+        }
+        AppInstruction::SetRoles => {
+            msg!("Instruction: SetRoles");
+            set_roles(accounts)
+        }
+        AppInstruction::SetMultisig { threshold, signers } => {
+            msg!("Instruction: SetMultisig");
+            set_multisig(accounts, threshold, signers)
+        }
+        AppInstruction::InitGlobalConfig { guardians } => {
+            msg!("Instruction: InitGlobalConfig");
+            init_global_config(accounts, guardians)
+        }
+        AppInstruction::UpdateGlobalConfig {
+            paused,
+            guardians,
+            protocol_fee,
+            protocol_treasury,
+        } => {
+            msg!("Instruction: UpdateGlobalConfig");
+            update_global_config(accounts, paused, guardians, protocol_fee, protocol_treasury)
+        }
+        AppInstruction::PauseProgram => {
+            msg!("Instruction: PauseProgram");
+            pause_program(accounts)
+        }
+        AppInstruction::Reconcile => {
+            msg!("Instruction: Reconcile");
+            reconcile(accounts)
+        }
+        AppInstruction::CloseAndDrain => {
+            msg!("Instruction: CloseAndDrain");
+            close_and_drain(accounts)
+        }
+        AppInstruction::Migrate {
+            max_fee,
+            fee_delay,
+            max_referral_bps,
+        } => {
+            msg!("Instruction: Migrate");
+            migrate(accounts, max_fee, fee_delay, max_referral_bps)
+        }
     }
 }
@@ -1,22 +1,49 @@
 use mpl_core::instructions::TransferV1CpiBuilder;
+use mpl_core::Asset;
 use mpl_core::ID as MPL_CORE_ID;
-use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, system_program};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, system_program, sysvar::Sysvar,
+};
 
 use crate::assertions::{
-    assert_asset_owner, assert_mpl_core_asset, assert_mpl_core_collection, assert_pda,
-    assert_pool_active, assert_pool_empty, assert_program_owner, assert_same_pubkeys,
-    assert_signer, assert_writable,
+    assert_account_key, assert_account_len, assert_asset_owner, assert_bps,
+    assert_different_pubkeys, assert_fee_ceiling, assert_fee_split, assert_in_inventory,
+    assert_mpl_core_asset, assert_mpl_core_collection, assert_multisig, assert_multisig_config,
+    assert_no_open_commits, assert_pda, assert_pool_empty, assert_pool_mode,
+    assert_pool_not_created, assert_pool_status, assert_program_not_paused, assert_program_owner,
+    assert_referral_bps, assert_referrer, assert_same_pubkeys, assert_same_pubkeys_either,
+    assert_signer, assert_status_transition, assert_swap_terms, assert_unique_accounts,
+    assert_writable,
 };
 use crate::error::FloorSwapError;
 use crate::instruction::accounts::{
-    CloseAccounts, CreateAccounts, DepositAccounts, SetActiveAccounts, SetFeeAccounts,
-    SwapAccounts, WithdrawAccounts,
+    AcceptAuthorityAccounts, ApplyFeeAccounts, CloseAccounts, CloseAndDrainAccounts,
+    CreateAccounts, DepositAccounts, DepositManyAccounts, MigrateAccounts,
+    ProposeAuthorityAccounts, ProposeFeeAccounts, ReconcileAccounts, SetFeeAccounts,
+    SetFeeSplitAccounts, SetModeAccounts, SetMultisigAccounts, SetReferencePriceAccounts,
+    SetReferralFeeAccounts, SetRolesAccounts, SetRoyaltyBaseAccounts, SetStatusAccounts,
+    SwapAccounts, SwapBatchAccounts, UpdatePoolAccounts, WithdrawAccounts, WithdrawManyAccounts,
+};
+use crate::processor::{find_royalties, split_fee_recipients, total_royalties, FeeAccounts};
+use crate::state::fee_vault::FeeVault;
+use crate::state::inventory::Inventory;
+use crate::state::pool::{
+    FeeModel, FeeRecipient, LegacyPool, Multisig, PendingFee, Pool, PoolMode, PoolRole, PoolStatus,
+    RoyaltyBase, MAX_REFERRAL_BPS,
 };
-use crate::state::pool::Pool;
-use crate::state::Key;
-use crate::utils::{close_account, create_account, pay_fee};
+use crate::state::{Checked, Key, SolanaAccount};
+use crate::utils::{close_account, create_account, resize_account};
 
-pub(crate) fn create<'a>(accounts: &'a [AccountInfo<'a>], fee_amount: u64) -> ProgramResult {
+pub(crate) fn create<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    fee_model: FeeModel,
+    fee_mint: Option<Pubkey>,
+    max_fee: u64,
+    fee_delay: u32,
+    max_referral_bps: u16,
+    index: u8,
+) -> ProgramResult {
     // Accounts.
     let ctx = CreateAccounts::context(accounts)?;
 
@@ -25,9 +52,33 @@ pub(crate) fn create<'a>(accounts: &'a [AccountInfo<'a>], fee_amount: u64) -> Pr
         "pool",
         ctx.accounts.pool,
         &crate::ID,
-        &Pool::seeds(ctx.accounts.authority.key, ctx.accounts.collection.key),
+        &Pool::seeds(
+            ctx.accounts.authority.key,
+            ctx.accounts.collection.key,
+            &index,
+        ),
+    )?;
+    assert_writable("pool", ctx.accounts.pool)?;
+    assert_pool_not_created(ctx.accounts.pool, index)?;
+    let fee_vault_bump = assert_pda(
+        "fee_vault",
+        ctx.accounts.fee_vault,
+        &crate::ID,
+        &FeeVault::seeds(ctx.accounts.pool.key),
     )?;
+    assert_writable("fee_vault", ctx.accounts.fee_vault)?;
+    let inventory_bump = assert_pda(
+        "inventory",
+        ctx.accounts.inventory,
+        &crate::ID,
+        &Inventory::seeds(ctx.accounts.pool.key),
+    )?;
+    assert_writable("inventory", ctx.accounts.inventory)?;
     assert_mpl_core_collection("collection", ctx.accounts.collection)?;
+    assert_bps("fee_model", fee_model.bps())?;
+    // New pools have no reference price yet.
+    assert_fee_ceiling("fee_model", fee_model.compute(0)?, max_fee)?;
+    assert_referral_bps("max_referral_bps", max_referral_bps, MAX_REFERRAL_BPS)?;
 
     assert_signer("authority", ctx.accounts.authority)?;
     assert_signer("payer", ctx.accounts.payer)?;
@@ -38,22 +89,41 @@ pub(crate) fn create<'a>(accounts: &'a [AccountInfo<'a>], fee_amount: u64) -> Pr
         &system_program::id(),
     )?;
 
-    // Do nothing if the domain already exists.
-    if !ctx.accounts.pool.data_is_empty() {
-        return Ok(());
-    }
-
     // Create Pool PDA.
     let pool = Pool {
         key: Key::Pool,
         authority: *ctx.accounts.authority.key,
         collection: *ctx.accounts.collection.key,
         treasury: *ctx.accounts.treasury.key,
-        fee_amount,
-        enabled: false,
+        status: PoolStatus::Pending,
         num_assets: 0,
+        bump,
+        mode: PoolMode::Standard,
+        reference_price: 0,
+        royalty_base: RoyaltyBase::Fee,
+        referral_bps: 0,
+        sequence: 0,
+        max_fee,
+        fee_delay,
+        max_referral_bps,
+        creator: *ctx.accounts.authority.key,
+        index,
+        open_commits: 0,
+        commit_count: 0,
+        fee_model,
+        fee_mint,
+        pending_fee: None,
+        pending_authority: None,
+        operator: None,
+        fee_manager: None,
+        multisig: None,
+        fee_split: vec![],
     };
-    let mut seeds = Pool::seeds(ctx.accounts.authority.key, ctx.accounts.collection.key);
+    let mut seeds = Pool::seeds(
+        ctx.accounts.authority.key,
+        ctx.accounts.collection.key,
+        &index,
+    );
     let bump = [bump];
     seeds.push(&bump);
     create_account(
@@ -64,83 +134,542 @@ pub(crate) fn create<'a>(accounts: &'a [AccountInfo<'a>], fee_amount: u64) -> Pr
         &crate::ID,
         Some(&[&seeds]),
     )?;
+    pool.save(ctx.accounts.pool, 0)?;
 
-    pool.save(ctx.accounts.pool)
+    // Create FeeVault PDA.
+    let fee_vault = FeeVault {
+        key: Key::FeeVault,
+        pool: *ctx.accounts.pool.key,
+        lifetime_fees: 0,
+        bump: fee_vault_bump,
+    };
+    let mut seeds = FeeVault::seeds(ctx.accounts.pool.key);
+    let bump = [fee_vault_bump];
+    seeds.push(&bump);
+    create_account(
+        ctx.accounts.fee_vault,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+        FeeVault::LEN,
+        &crate::ID,
+        Some(&[&seeds]),
+    )?;
+    fee_vault.save(ctx.accounts.fee_vault, 0)?;
+
+    // Create Inventory PDA.
+    let inventory = Inventory {
+        key: Key::Inventory,
+        pool: *ctx.accounts.pool.key,
+        bump: inventory_bump,
+        assets: vec![],
+    };
+    let mut seeds = Inventory::seeds(ctx.accounts.pool.key);
+    let bump = [inventory_bump];
+    seeds.push(&bump);
+    create_account(
+        ctx.accounts.inventory,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+        Inventory::LEN,
+        &crate::ID,
+        Some(&[&seeds]),
+    )?;
+
+    inventory.save(ctx.accounts.inventory, 0)
 }
 
-pub(crate) fn set_active<'a>(accounts: &'a [AccountInfo<'a>], active: bool) -> ProgramResult {
+pub(crate) fn set_status<'a>(accounts: &'a [AccountInfo<'a>], status: PoolStatus) -> ProgramResult {
     // Accounts.
-    let ctx = SetActiveAccounts::context(accounts)?;
+    let ctx = SetStatusAccounts::context(accounts)?;
 
     // Guards.
     assert_signer("authority", ctx.accounts.authority)?;
-    assert_program_owner("pool", ctx.accounts.pool, &crate::ID)?;
-    let mut pool = Pool::load(ctx.accounts.pool)?;
-    assert_same_pubkeys("authority", ctx.accounts.authority, &pool.authority)?;
+    let mut pool = Checked::<Pool>::load_mut("pool", ctx.accounts.pool)?;
+    assert_same_pubkeys_either(
+        "authority",
+        ctx.accounts.authority,
+        &pool.signers_for(PoolRole::Operator),
+    )?;
+    assert_status_transition(&pool, status)?;
+
+    // Operators only toggle between active and paused.
+    let pause_or_resume = matches!(
+        (pool.status, status),
+        (PoolStatus::Active, PoolStatus::Paused) | (PoolStatus::Paused, PoolStatus::Active)
+    );
+    if ctx.accounts.authority.key != &pool.authority && !pause_or_resume {
+        msg!("Only the authority can move the pool to {:?}", status);
+        return Err(FloorSwapError::OperatorStatusNotAllowed.into());
+    }
 
-    // Toggle PDA active
-    pool.enabled = active;
-    pool.save(ctx.accounts.pool)
+    pool.status = status;
+    pool.save()
 }
 
-pub(crate) fn set_fee<'a>(accounts: &'a [AccountInfo<'a>], fee_amount: u64) -> ProgramResult {
+pub(crate) fn set_fee<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    fee_model: FeeModel,
+    fee_mint: Option<Pubkey>,
+) -> ProgramResult {
     // Accounts.
     let ctx = SetFeeAccounts::context(accounts)?;
 
     // Guards.
     assert_signer("authority", ctx.accounts.authority)?;
-    assert_program_owner("pool", ctx.accounts.pool, &crate::ID)?;
-    let mut pool: Pool = Pool::load(ctx.accounts.pool)?;
+    let mut pool = Checked::<Pool>::load_mut("pool", ctx.accounts.pool)?;
+    assert_same_pubkeys_either(
+        "authority",
+        ctx.accounts.authority,
+        &pool.signers_for(PoolRole::FeeManager),
+    )?;
+    assert_multisig(&pool, ctx.remaining_accounts)?;
+    assert_bps("fee_model", fee_model.bps())?;
+
+    let (reference_price, royalty_base) = (pool.reference_price, pool.royalty_base);
+    if pool.is_fee_increase(&fee_model, &fee_mint, reference_price, royalty_base)? {
+        msg!("Fee increases must wait for the fee delay of the pool");
+        return Err(FloorSwapError::FeeIncreaseNotProposed.into());
+    }
+
+    pool.fee_model = fee_model;
+    pool.fee_mint = fee_mint;
+    pool.pending_fee = None;
+    pool.increment_sequence();
+    pool.save()
+}
+
+pub(crate) fn propose_fee<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    fee_model: FeeModel,
+    fee_mint: Option<Pubkey>,
+    reference_price: Option<u64>,
+    royalty_base: Option<RoyaltyBase>,
+) -> ProgramResult {
+    // Accounts.
+    let ctx = ProposeFeeAccounts::context(accounts)?;
+
+    // Guards.
+    assert_signer("authority", ctx.accounts.authority)?;
+    let mut pool = Checked::<Pool>::load_mut("pool", ctx.accounts.pool)?;
+    assert_same_pubkeys_either(
+        "authority",
+        ctx.accounts.authority,
+        &pool.signers_for(PoolRole::FeeManager),
+    )?;
+    assert_multisig(&pool, ctx.remaining_accounts)?;
+    assert_bps("fee_model", fee_model.bps())?;
+    assert_fee_ceiling(
+        "fee_model",
+        fee_model.compute(reference_price.unwrap_or(pool.reference_price))?,
+        pool.max_fee,
+    )?;
+
+    let effective_at = Clock::get()?
+        .unix_timestamp
+        .checked_add(pool.fee_delay as i64)
+        .ok_or(FloorSwapError::NumericalOverflow)?;
+
+    pool.pending_fee = Some(PendingFee {
+        fee_model,
+        fee_mint,
+        effective_at,
+        reference_price,
+        royalty_base,
+    });
+    pool.save()
+}
+
+pub(crate) fn apply_fee<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    // Accounts.
+    let ctx = ApplyFeeAccounts::context(accounts)?;
+
+    // Guards.
+    let mut pool = Checked::<Pool>::load_mut("pool", ctx.accounts.pool)?;
+    let pending_fee = pool.pending_fee.ok_or(FloorSwapError::NoPendingFee)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    if now < pending_fee.effective_at {
+        msg!(
+            "The pending fee is effective at {}, current time is {}",
+            pending_fee.effective_at,
+            now
+        );
+        return Err(FloorSwapError::PendingFeeNotEffective.into());
+    }
+
+    pool.fee_model = pending_fee.fee_model;
+    pool.fee_mint = pending_fee.fee_mint;
+    if let Some(reference_price) = pending_fee.reference_price {
+        pool.reference_price = reference_price;
+    }
+    if let Some(royalty_base) = pending_fee.royalty_base {
+        pool.royalty_base = royalty_base;
+    }
+    pool.pending_fee = None;
+    assert_fee_ceiling("pending_fee", pool.swap_fee()?, pool.max_fee)?;
+
+    pool.increment_sequence();
+    pool.save()
+}
+
+pub(crate) fn set_reference_price<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    reference_price: u64,
+) -> ProgramResult {
+    // Accounts.
+    let ctx = SetReferencePriceAccounts::context(accounts)?;
+
+    // Guards.
+    assert_signer("authority", ctx.accounts.authority)?;
+    let mut pool = Checked::<Pool>::load_mut("pool", ctx.accounts.pool)?;
+    assert_same_pubkeys("authority", ctx.accounts.authority, &pool.authority)?;
+    assert_multisig(&pool, ctx.remaining_accounts)?;
+
+    // Basis point fees and royalties charged on the reference price follow it, so raising
+    // it can raise what swaps pay.
+    let (fee_model, fee_mint, royalty_base) = (pool.fee_model, pool.fee_mint, pool.royalty_base);
+    if pool.is_fee_increase(&fee_model, &fee_mint, reference_price, royalty_base)? {
+        msg!(
+            "Reference price increases that raise the fee must wait for the fee delay of the pool"
+        );
+        return Err(FloorSwapError::FeeIncreaseNotProposed.into());
+    }
+
+    pool.reference_price = reference_price;
+    assert_fee_ceiling("reference_price", pool.swap_fee()?, pool.max_fee)?;
+    pool.increment_sequence();
+    pool.save()
+}
+
+pub(crate) fn set_royalty_base<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    royalty_base: RoyaltyBase,
+) -> ProgramResult {
+    // Accounts.
+    let ctx = SetRoyaltyBaseAccounts::context(accounts)?;
+
+    // Guards.
+    assert_signer("authority", ctx.accounts.authority)?;
+    let mut pool = Checked::<Pool>::load_mut("pool", ctx.accounts.pool)?;
+    assert_same_pubkeys("authority", ctx.accounts.authority, &pool.authority)?;
+    assert_multisig(&pool, ctx.remaining_accounts)?;
+
+    let (fee_model, fee_mint, reference_price) =
+        (pool.fee_model, pool.fee_mint, pool.reference_price);
+    if pool.is_fee_increase(&fee_model, &fee_mint, reference_price, royalty_base)? {
+        msg!("Royalty base switches that raise the royalties must wait for the fee delay of the pool");
+        return Err(FloorSwapError::FeeIncreaseNotProposed.into());
+    }
+
+    pool.royalty_base = royalty_base;
+    pool.increment_sequence();
+    pool.save()
+}
+
+pub(crate) fn set_referral_fee<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    referral_bps: u16,
+) -> ProgramResult {
+    // Accounts.
+    let ctx = SetReferralFeeAccounts::context(accounts)?;
+
+    // Guards.
+    assert_signer("authority", ctx.accounts.authority)?;
+    let mut pool = Checked::<Pool>::load_mut("pool", ctx.accounts.pool)?;
+    assert_same_pubkeys("authority", ctx.accounts.authority, &pool.authority)?;
+    assert_multisig(&pool, ctx.remaining_accounts)?;
+    assert_referral_bps("referral_bps", referral_bps, pool.max_referral_bps)?;
+
+    pool.referral_bps = referral_bps;
+    pool.increment_sequence();
+    pool.save()
+}
+
+pub(crate) fn set_fee_split<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    fee_split: Vec<FeeRecipient>,
+) -> ProgramResult {
+    // Accounts.
+    let ctx = SetFeeSplitAccounts::context(accounts)?;
+
+    // Guards.
+    assert_signer("authority", ctx.accounts.authority)?;
+    let mut pool = Checked::<Pool>::load_mut("pool", ctx.accounts.pool)?;
     assert_same_pubkeys("authority", ctx.accounts.authority, &pool.authority)?;
+    assert_multisig(&pool, ctx.remaining_accounts)?;
+    assert_fee_split("fee_split", &fee_split)?;
 
-    pool.fee_amount = fee_amount;
-    pool.save(ctx.accounts.pool)
+    pool.fee_split = fee_split;
+    pool.increment_sequence();
+    pool.save()
 }
 
-pub(crate) fn swap<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+pub(crate) fn set_mode<'a>(accounts: &'a [AccountInfo<'a>], mode: PoolMode) -> ProgramResult {
+    // Accounts.
+    let ctx = SetModeAccounts::context(accounts)?;
+
+    // Guards.
+    assert_signer("authority", ctx.accounts.authority)?;
+    let mut pool = Checked::<Pool>::load_mut("pool", ctx.accounts.pool)?;
+    assert_no_open_commits(&pool, ctx.accounts.pool)?;
+    assert_same_pubkeys("authority", ctx.accounts.authority, &pool.authority)?;
+    assert_multisig(&pool, ctx.remaining_accounts)?;
+
+    pool.mode = mode;
+    pool.increment_sequence();
+    pool.save()
+}
+
+pub(crate) fn update_pool<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    fee_model: Option<FeeModel>,
+    status: Option<PoolStatus>,
+    mode: Option<PoolMode>,
+    reference_price: Option<u64>,
+    royalty_base: Option<RoyaltyBase>,
+    referral_bps: Option<u16>,
+) -> ProgramResult {
+    // Accounts.
+    let ctx = UpdatePoolAccounts::context(accounts)?;
+
+    // Guards.
+    assert_signer("authority", ctx.accounts.authority)?;
+    let mut pool = Checked::<Pool>::load_mut("pool", ctx.accounts.pool)?;
+    assert_same_pubkeys("authority", ctx.accounts.authority, &pool.authority)?;
+    assert_multisig(&pool, ctx.remaining_accounts)?;
+
+    if let Some(treasury) = ctx.accounts.treasury {
+        pool.treasury = *treasury.key;
+    }
+
+    if let Some(status) = status {
+        assert_status_transition(&pool, status)?;
+        pool.status = status;
+    }
+
+    // Only changes to the swap terms bump the sequence.
+    let terms_changed = fee_model.is_some()
+        || mode.is_some()
+        || reference_price.is_some()
+        || royalty_base.is_some()
+        || referral_bps.is_some();
+
+    // The fee, reference price and royalty base are checked together against the terms
+    // before the update, so raising the fee or royalties cannot skip the fee delay.
+    if fee_model.is_some() || reference_price.is_some() || royalty_base.is_some() {
+        let new_fee_model = fee_model.unwrap_or(pool.fee_model);
+        let fee_mint = pool.fee_mint;
+        assert_bps("fee_model", new_fee_model.bps())?;
+        if pool.is_fee_increase(
+            &new_fee_model,
+            &fee_mint,
+            reference_price.unwrap_or(pool.reference_price),
+            royalty_base.unwrap_or(pool.royalty_base),
+        )? {
+            msg!("Fee increases must wait for the fee delay of the pool");
+            return Err(FloorSwapError::FeeIncreaseNotProposed.into());
+        }
+    }
+
+    if let Some(mode) = mode {
+        pool.mode = mode;
+    }
+
+    if let Some(reference_price) = reference_price {
+        pool.reference_price = reference_price;
+    }
+
+    if let Some(royalty_base) = royalty_base {
+        pool.royalty_base = royalty_base;
+    }
+
+    if let Some(referral_bps) = referral_bps {
+        assert_referral_bps("referral_bps", referral_bps, pool.max_referral_bps)?;
+        pool.referral_bps = referral_bps;
+    }
+
+    if let Some(fee_model) = fee_model {
+        pool.fee_model = fee_model;
+        pool.pending_fee = None;
+    }
+
+    if terms_changed {
+        assert_fee_ceiling("pool", pool.swap_fee()?, pool.max_fee)?;
+        pool.increment_sequence();
+    }
+    pool.save()
+}
+
+pub(crate) fn propose_authority<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    // Accounts.
+    let ctx = ProposeAuthorityAccounts::context(accounts)?;
+
+    // Guards.
+    assert_signer("authority", ctx.accounts.authority)?;
+    let mut pool = Checked::<Pool>::load_mut("pool", ctx.accounts.pool)?;
+    assert_same_pubkeys("authority", ctx.accounts.authority, &pool.authority)?;
+
+    // Omitting the new authority cancels the pending transfer.
+    pool.pending_authority = ctx.accounts.new_authority.map(|account| *account.key);
+    pool.save()
+}
+
+pub(crate) fn accept_authority<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    // Accounts.
+    let ctx = AcceptAuthorityAccounts::context(accounts)?;
+
+    // Guards.
+    assert_signer("new_authority", ctx.accounts.new_authority)?;
+    let mut pool = Checked::<Pool>::load_mut("pool", ctx.accounts.pool)?;
+    let pending_authority = pool
+        .pending_authority
+        .ok_or(FloorSwapError::NoPendingAuthority)?;
+    assert_same_pubkeys(
+        "new_authority",
+        ctx.accounts.new_authority,
+        &pending_authority,
+    )?;
+
+    pool.authority = pending_authority;
+    pool.pending_authority = None;
+    pool.save()
+}
+
+pub(crate) fn set_roles<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    // Accounts.
+    let ctx = SetRolesAccounts::context(accounts)?;
+
+    // Guards.
+    assert_signer("authority", ctx.accounts.authority)?;
+    let mut pool = Checked::<Pool>::load_mut("pool", ctx.accounts.pool)?;
+    assert_same_pubkeys("authority", ctx.accounts.authority, &pool.authority)?;
+    assert_multisig(&pool, ctx.remaining_accounts)?;
+
+    // Omitted roles are revoked.
+    pool.operator = ctx.accounts.operator.map(|account| *account.key);
+    pool.fee_manager = ctx.accounts.fee_manager.map(|account| *account.key);
+    pool.save()
+}
+
+pub(crate) fn set_multisig<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    threshold: u8,
+    signers: Vec<Pubkey>,
+) -> ProgramResult {
+    // Accounts.
+    let ctx = SetMultisigAccounts::context(accounts)?;
+
+    // Guards.
+    assert_signer("authority", ctx.accounts.authority)?;
+    let mut pool = Checked::<Pool>::load_mut("pool", ctx.accounts.pool)?;
+    assert_same_pubkeys("authority", ctx.accounts.authority, &pool.authority)?;
+    assert_multisig(&pool, ctx.remaining_accounts)?;
+    assert_multisig_config("signers", threshold, &signers)?;
+
+    // Without signers the pool goes back to the authority alone.
+    pool.multisig = if signers.is_empty() {
+        None
+    } else {
+        Some(Multisig { threshold, signers })
+    };
+    pool.save()
+}
+
+pub(crate) fn swap<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    max_fee: u64,
+    expected_sequence: Option<u64>,
+) -> ProgramResult {
     // Accounts.
     let ctx = SwapAccounts::context(accounts)?;
 
     // Guards.
-    assert_mpl_core_collection("collection", ctx.accounts.collection)?;
+    let global_config = assert_program_not_paused(ctx.accounts.global_config)?;
     assert_same_pubkeys("core_program", ctx.accounts.core_program, &MPL_CORE_ID)?;
-    let pool: Pool = Pool::load(ctx.accounts.pool)?;
+    assert_same_pubkeys(
+        "system_program",
+        ctx.accounts.system_program,
+        &system_program::id(),
+    )?;
+
+    let pool = Checked::<Pool>::load("pool", ctx.accounts.pool)?;
 
-    assert_pool_active(&pool, ctx.accounts.pool)?;
+    assert_pool_status(&pool, ctx.accounts.pool, &[PoolStatus::Active])?;
+    assert_pool_mode(&pool, ctx.accounts.pool, PoolMode::Standard)?;
+    let protocol_fee = global_config.as_ref().map_or(Ok(0), |global_config| {
+        global_config.protocol_swap_fee(&pool)
+    })?;
 
+    let collection = assert_mpl_core_collection("collection", ctx.accounts.collection)?;
+    assert_same_pubkeys("collection", ctx.accounts.collection, &pool.collection)?;
+
+    assert_different_pubkeys(
+        "source_asset",
+        ctx.accounts.source_asset,
+        "dest_asset",
+        ctx.accounts.dest_asset,
+    )?;
     let source_asset =
         assert_mpl_core_asset("source_asset", ctx.accounts.source_asset, &pool.collection)?;
     assert_asset_owner("source_asset", source_asset, ctx.accounts.payer.key)?;
     let dest_asset =
         assert_mpl_core_asset("dest_asset", ctx.accounts.dest_asset, &pool.collection)?;
+    let royalties = find_royalties(&collection, &dest_asset);
     assert_asset_owner("dest_asset", dest_asset, ctx.accounts.pool.key)?;
 
+    let royalty_fee = total_royalties(royalties.as_ref(), pool.royalty_base_amount()?)?;
+    let fee = pool
+        .swap_fee()?
+        .checked_add(protocol_fee)
+        .and_then(|fee| fee.checked_add(royalty_fee))
+        .ok_or(FloorSwapError::NumericalOverflow)?;
+    assert_swap_terms(&pool, ctx.accounts.pool, fee, max_fee, expected_sequence)?;
+
+    let mut inventory = Checked::<Inventory>::load_mut("inventory", ctx.accounts.inventory)?;
+    assert_same_pubkeys("pool", ctx.accounts.pool, &inventory.pool)?;
+    assert_in_inventory("dest_asset", ctx.accounts.dest_asset, &inventory)?;
+
     assert_signer("payer", ctx.accounts.payer)?;
 
     assert_writable("payer", ctx.accounts.payer)?;
     assert_writable("source_asset", ctx.accounts.source_asset)?;
     assert_writable("dest_asset", ctx.accounts.dest_asset)?;
 
-    assert_same_pubkeys("collection", ctx.accounts.collection, &pool.collection)?;
+    if let Some(referrer) = ctx.accounts.referrer {
+        assert_referrer(
+            referrer,
+            &[
+                ("payer", ctx.accounts.payer),
+                ("pool", ctx.accounts.pool),
+                ("fee_vault", ctx.accounts.fee_vault),
+            ],
+        )?;
+    }
 
-    assert_same_pubkeys(
-        "system_program",
-        ctx.accounts.system_program,
-        &system_program::id(),
-    )?;
+    let seeds = pool.signer_seeds();
 
-    let bump = assert_pda(
-        "pool",
-        ctx.accounts.pool,
-        &crate::ID,
-        &Pool::seeds(&pool.authority, &pool.collection),
-    )?;
+    // Remaining accounts are the fee recipients followed by the royalty creators.
+    let (fee_recipients, creators) = split_fee_recipients(&pool, ctx.remaining_accounts)?;
 
-    let mut seeds = Pool::seeds(&pool.authority, &pool.collection);
-    let bump = [bump];
-    seeds.push(&bump);
+    let fee_accounts = FeeAccounts {
+        payer: ctx.accounts.payer,
+        fee_vault: ctx.accounts.fee_vault,
+        fee_mint: ctx.accounts.fee_mint,
+        payer_token_account: ctx.accounts.payer_token_account,
+        treasury_token_account: ctx.accounts.treasury_token_account,
+        token_program: ctx.accounts.token_program,
+        fee_recipients,
+        referrer: ctx.accounts.referrer,
+        referrer_token_account: ctx.accounts.referrer_token_account,
+        protocol_treasury: ctx.accounts.protocol_treasury,
+        protocol_treasury_token_account: ctx.accounts.protocol_treasury_token_account,
+    };
+    fee_accounts.pay(&pool, pool.swap_fee()?)?;
+    fee_accounts.pay_protocol_fee(&pool, global_config.as_ref(), protocol_fee)?;
 
-    pay_fee(ctx.accounts.payer, ctx.accounts.treasury, pool.fee_amount)?;
+    if let Some(royalties) = &royalties {
+        fee_accounts.pay_royalties(&pool, royalties, pool.royalty_base_amount()?, creators)?;
+    }
 
     TransferV1CpiBuilder::new(ctx.accounts.core_program)
         .asset(ctx.accounts.source_asset)
@@ -156,7 +685,11 @@ pub(crate) fn swap<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
         .collection(Some(ctx.accounts.collection))
         .payer(ctx.accounts.payer)
         .authority(Some(ctx.accounts.pool))
-        .invoke_signed(&[&seeds])
+        .invoke_signed(&[&seeds])?;
+
+    // The source asset takes the place of the destination asset in the pool.
+    inventory.replace(ctx.accounts.dest_asset.key, *ctx.accounts.source_asset.key);
+    inventory.save()
 }
 
 pub(crate) fn deposit<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
@@ -164,15 +697,32 @@ pub(crate) fn deposit<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
     let ctx = DepositAccounts::context(accounts)?;
 
     // Guards.
+    assert_program_not_paused(ctx.accounts.global_config)?;
     assert_same_pubkeys("core_program", ctx.accounts.core_program, &MPL_CORE_ID)?;
+    assert_same_pubkeys(
+        "system_program",
+        ctx.accounts.system_program,
+        &system_program::id(),
+    )?;
     assert_writable("asset", ctx.accounts.asset)?;
     assert_writable("payer", ctx.accounts.payer)?;
     assert_signer("payer", ctx.accounts.payer)?;
 
-    let mut pool = Pool::load(ctx.accounts.pool)?;
+    let mut pool = Checked::<Pool>::load_mut("pool", ctx.accounts.pool)?;
+    assert_pool_status(
+        &pool,
+        ctx.accounts.pool,
+        &[PoolStatus::Pending, PoolStatus::Active, PoolStatus::Paused],
+    )?;
+    assert_no_open_commits(&pool, ctx.accounts.pool)?;
+
     assert_mpl_core_collection("collection", ctx.accounts.collection)?;
     assert_same_pubkeys("collection", ctx.accounts.collection, &pool.collection)?;
-    assert_mpl_core_asset("asset", ctx.accounts.asset, &pool.collection)?;
+    let asset = assert_mpl_core_asset("asset", ctx.accounts.asset, &pool.collection)?;
+    assert_asset_owner("asset", asset, ctx.accounts.payer.key)?;
+
+    let mut inventory = Checked::<Inventory>::load_mut("inventory", ctx.accounts.inventory)?;
+    assert_same_pubkeys("pool", ctx.accounts.pool, &inventory.pool)?;
 
     TransferV1CpiBuilder::new(ctx.accounts.core_program)
         .asset(ctx.accounts.asset)
@@ -182,11 +732,12 @@ pub(crate) fn deposit<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
         .authority(Some(ctx.accounts.payer))
         .invoke()?;
 
-    pool.num_assets = pool
-        .num_assets
-        .checked_add(1)
-        .ok_or(FloorSwapError::NumericalOverflow)?;
-    pool.save(ctx.accounts.pool)
+    // A stale entry of the asset is reused rather than duplicated.
+    inventory.insert(*ctx.accounts.asset.key);
+    inventory.resize_and_save(ctx.accounts.payer)?;
+
+    pool.num_assets = inventory.num_assets()?;
+    pool.save()
 }
 
 pub(crate) fn withdraw<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
@@ -194,11 +745,13 @@ pub(crate) fn withdraw<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
     let ctx = WithdrawAccounts::context(accounts)?;
 
     // Guards.
-    let mut pool = Pool::load(ctx.accounts.pool)?;
+    assert_program_not_paused(ctx.accounts.global_config)?;
+    let mut pool = Checked::<Pool>::load_mut("pool", ctx.accounts.pool)?;
+    assert_no_open_commits(&pool, ctx.accounts.pool)?;
 
     let asset = assert_mpl_core_asset("asset", ctx.accounts.asset, &pool.collection)?;
     assert_mpl_core_collection("collection", ctx.accounts.collection)?;
-    assert_asset_owner("asset", asset, &ctx.accounts.pool.key)?;
+    assert_asset_owner("asset", asset, ctx.accounts.pool.key)?;
 
     assert_same_pubkeys("core_program", ctx.accounts.core_program, &MPL_CORE_ID)?;
     assert_same_pubkeys("collection", ctx.accounts.collection, &pool.collection)?;
@@ -206,17 +759,14 @@ pub(crate) fn withdraw<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
 
     assert_writable("asset", ctx.accounts.asset)?;
     assert_signer("authority", ctx.accounts.authority)?;
+    assert_multisig(&pool, ctx.remaining_accounts)?;
 
-    let bump = assert_pda(
-        "pool",
-        ctx.accounts.pool,
-        &crate::ID,
-        &Pool::seeds(ctx.accounts.authority.key, ctx.accounts.collection.key),
-    )?;
+    // Assets sent to the pool without the deposit ix must be reconciled first.
+    let mut inventory = Checked::<Inventory>::load_mut("inventory", ctx.accounts.inventory)?;
+    assert_same_pubkeys("pool", ctx.accounts.pool, &inventory.pool)?;
+    assert_in_inventory("asset", ctx.accounts.asset, &inventory)?;
 
-    let mut seeds = Pool::seeds(ctx.accounts.authority.key, ctx.accounts.collection.key);
-    let bump = [bump];
-    seeds.push(&bump);
+    let seeds = pool.signer_seeds();
 
     let destination = ctx.accounts.destination.unwrap_or(ctx.accounts.authority);
 
@@ -228,22 +778,621 @@ pub(crate) fn withdraw<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
         .authority(Some(ctx.accounts.pool))
         .invoke_signed(&[&seeds])?;
 
-    // We allow a withdrawal even if num_assets is zero, this is because
-    // it's possible assets were sent to the pool not using the deposit ix
-    pool.num_assets = pool.num_assets.checked_sub(1).unwrap_or(0);
-    pool.save(ctx.accounts.pool)
+    inventory.remove(ctx.accounts.asset.key);
+    inventory.resize_and_save(ctx.accounts.authority)?;
+
+    pool.num_assets = inventory.num_assets()?;
+    pool.save()
 }
 
 pub(crate) fn close<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    // Accounts.
     let ctx = CloseAccounts::context(accounts)?;
-    let pool = Pool::load(ctx.accounts.pool)?;
 
+    // Guards.
+    let pool = Checked::<Pool>::load_mut("pool", ctx.accounts.pool)?;
+
+    assert_pool_status(&pool, ctx.accounts.pool, &[PoolStatus::Closing])?;
+    assert_no_open_commits(&pool, ctx.accounts.pool)?;
     assert_pool_empty(&pool, ctx.accounts.pool)?;
 
+    let fee_vault = Checked::<FeeVault>::load_mut("fee_vault", ctx.accounts.fee_vault)?;
+    assert_same_pubkeys("pool", ctx.accounts.pool, &fee_vault.pool)?;
+    if fee_vault.claimable()? > 0 {
+        msg!("Claim the fees of the fee vault before closing the pool");
+        return Err(FloorSwapError::UnclaimedFees.into());
+    }
+
+    // The inventory is kept in sync with the asset count checked above.
+    let inventory = Checked::<Inventory>::load_mut("inventory", ctx.accounts.inventory)?;
+    assert_same_pubkeys("pool", ctx.accounts.pool, &inventory.pool)?;
+
     assert_same_pubkeys("authority", ctx.accounts.authority, &pool.authority)?;
     assert_signer("authority", ctx.accounts.authority)?;
+    assert_multisig(&pool, ctx.remaining_accounts)?;
+    assert_same_pubkeys(
+        "system_program",
+        ctx.accounts.system_program,
+        &system_program::id(),
+    )?;
 
     close_account(ctx.accounts.pool, ctx.accounts.authority)?;
+    close_account(ctx.accounts.fee_vault, ctx.accounts.authority)?;
+    close_account(ctx.accounts.inventory, ctx.accounts.authority)?;
+
+    Ok(())
+}
+
+pub(crate) fn close_and_drain<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    // Accounts.
+    let ctx = CloseAndDrainAccounts::context(accounts)?;
+
+    // Guards.
+    assert_program_not_paused(ctx.accounts.global_config)?;
+    let pool = Checked::<Pool>::load_mut("pool", ctx.accounts.pool)?;
+
+    assert_pool_status(&pool, ctx.accounts.pool, &[PoolStatus::Closing])?;
+    assert_no_open_commits(&pool, ctx.accounts.pool)?;
+
+    let fee_vault = Checked::<FeeVault>::load_mut("fee_vault", ctx.accounts.fee_vault)?;
+    assert_same_pubkeys("pool", ctx.accounts.pool, &fee_vault.pool)?;
+    if fee_vault.claimable()? > 0 {
+        msg!("Claim the fees of the fee vault before closing the pool");
+        return Err(FloorSwapError::UnclaimedFees.into());
+    }
+
+    assert_mpl_core_collection("collection", ctx.accounts.collection)?;
+
+    assert_same_pubkeys("core_program", ctx.accounts.core_program, &MPL_CORE_ID)?;
+    assert_same_pubkeys("collection", ctx.accounts.collection, &pool.collection)?;
+    assert_same_pubkeys("authority", ctx.accounts.authority, &pool.authority)?;
+
+    assert_writable("authority", ctx.accounts.authority)?;
+    assert_signer("authority", ctx.accounts.authority)?;
+
+    // Remaining accounts are the multisig signers, if any, then every asset of the inventory.
+    let (signers, assets) = split_multisig_signers(&pool, ctx.remaining_accounts)?;
+    assert_multisig(&pool, signers)?;
+    assert_unique_accounts("asset", assets)?;
+
+    for account in assets {
+        let asset = assert_mpl_core_asset("asset", account, &pool.collection)?;
+        assert_asset_owner("asset", asset, ctx.accounts.pool.key)?;
+        assert_writable("asset", account)?;
+    }
+
+    let inventory = Checked::<Inventory>::load_mut("inventory", ctx.accounts.inventory)?;
+    assert_same_pubkeys("pool", ctx.accounts.pool, &inventory.pool)?;
+    for account in assets {
+        assert_in_inventory("asset", account, &inventory)?;
+    }
+    // The assets are unique and all in the inventory, so no asset is left behind when the
+    // counts match.
+    if assets.len() != inventory.assets.len() {
+        msg!(
+            "Expected the {} assets of the inventory as remaining accounts, got {}",
+            inventory.assets.len(),
+            assets.len()
+        );
+        return Err(FloorSwapError::PoolNotEmpty.into());
+    }
+
+    let seeds = pool.signer_seeds();
+
+    let destination = ctx.accounts.destination.unwrap_or(ctx.accounts.authority);
+
+    for account in assets {
+        TransferV1CpiBuilder::new(ctx.accounts.core_program)
+            .asset(account)
+            .new_owner(destination)
+            .collection(Some(ctx.accounts.collection))
+            .payer(ctx.accounts.authority)
+            .authority(Some(ctx.accounts.pool))
+            .invoke_signed(&[&seeds])?;
+    }
+
+    let rent_receiver = ctx.accounts.rent_receiver.unwrap_or(ctx.accounts.authority);
+    assert_writable("rent_receiver", rent_receiver)?;
+
+    close_account(ctx.accounts.pool, rent_receiver)?;
+    close_account(ctx.accounts.fee_vault, rent_receiver)?;
+    close_account(ctx.accounts.inventory, rent_receiver)?;
 
     Ok(())
 }
+
+pub(crate) fn swap_batch<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    max_fee: u64,
+    expected_sequence: Option<u64>,
+) -> ProgramResult {
+    // Accounts.
+    let ctx = SwapBatchAccounts::context(accounts)?;
+
+    // Guards.
+    let global_config = assert_program_not_paused(ctx.accounts.global_config)?;
+    assert_same_pubkeys("core_program", ctx.accounts.core_program, &MPL_CORE_ID)?;
+    assert_same_pubkeys(
+        "system_program",
+        ctx.accounts.system_program,
+        &system_program::id(),
+    )?;
+
+    let pool = Checked::<Pool>::load("pool", ctx.accounts.pool)?;
+
+    assert_pool_status(&pool, ctx.accounts.pool, &[PoolStatus::Active])?;
+    assert_pool_mode(&pool, ctx.accounts.pool, PoolMode::Standard)?;
+
+    let collection = assert_mpl_core_collection("collection", ctx.accounts.collection)?;
+    assert_same_pubkeys("collection", ctx.accounts.collection, &pool.collection)?;
+
+    assert_signer("payer", ctx.accounts.payer)?;
+    assert_writable("payer", ctx.accounts.payer)?;
+
+    let mut inventory = Checked::<Inventory>::load_mut("inventory", ctx.accounts.inventory)?;
+    assert_same_pubkeys("pool", ctx.accounts.pool, &inventory.pool)?;
+
+    // Remaining accounts are the fee recipients, then (source_asset, dest_asset) pairs
+    // each followed by the creators of the royalties of that swap.
+    let (fee_recipients, mut remaining) = split_fee_recipients(&pool, ctx.remaining_accounts)?;
+    let mut swaps = Vec::new();
+    while !remaining.is_empty() {
+        let [source_account, dest_account, rest @ ..] = remaining else {
+            msg!("Expected (source_asset, dest_asset) pairs as remaining accounts");
+            return Err(FloorSwapError::InvalidRemainingAccounts.into());
+        };
+
+        let source_asset = assert_mpl_core_asset("source_asset", source_account, &pool.collection)?;
+        assert_asset_owner("source_asset", source_asset, ctx.accounts.payer.key)?;
+        let dest_asset = assert_mpl_core_asset("dest_asset", dest_account, &pool.collection)?;
+        let royalties = find_royalties(&collection, &dest_asset);
+        assert_asset_owner("dest_asset", dest_asset, ctx.accounts.pool.key)?;
+        assert_in_inventory("dest_asset", dest_account, &inventory)?;
+
+        assert_writable("source_asset", source_account)?;
+        assert_writable("dest_asset", dest_account)?;
+
+        let num_creators = royalties
+            .as_ref()
+            .map_or(0, |royalties| royalties.creators.len());
+        if rest.len() < num_creators {
+            msg!(
+                "Expected {} creator accounts after dest_asset [{}]",
+                num_creators,
+                dest_account.key
+            );
+            return Err(FloorSwapError::InvalidRemainingAccounts.into());
+        }
+        let (creators, rest) = rest.split_at(num_creators);
+
+        swaps.push((source_account, dest_account, royalties, creators));
+        remaining = rest;
+    }
+
+    if swaps.is_empty() {
+        msg!("Expected at least one (source_asset, dest_asset) pair as remaining accounts");
+        return Err(FloorSwapError::InvalidRemainingAccounts.into());
+    }
+
+    let assets: Vec<_> = swaps
+        .iter()
+        .flat_map(|(source_account, dest_account, ..)| {
+            [(*source_account).clone(), (*dest_account).clone()]
+        })
+        .collect();
+    assert_unique_accounts("asset", &assets)?;
+
+    let seeds = pool.signer_seeds();
+
+    let fee_amount = pool
+        .swap_fee()?
+        .checked_mul(swaps.len() as u64)
+        .ok_or(FloorSwapError::NumericalOverflow)?;
+    let protocol_fee = global_config
+        .as_ref()
+        .map_or(Ok(0), |global_config| {
+            global_config.protocol_swap_fee(&pool)
+        })?
+        .checked_mul(swaps.len() as u64)
+        .ok_or(FloorSwapError::NumericalOverflow)?;
+
+    // The max fee covers every swap of the batch.
+    let royalty_base_amount = pool.royalty_base_amount()?;
+    let fee = swaps.iter().try_fold(
+        fee_amount
+            .checked_add(protocol_fee)
+            .ok_or(FloorSwapError::NumericalOverflow)?,
+        |fee, (_, _, royalties, _)| {
+            fee.checked_add(total_royalties(royalties.as_ref(), royalty_base_amount)?)
+                .ok_or(ProgramError::from(FloorSwapError::NumericalOverflow))
+        },
+    )?;
+    assert_swap_terms(&pool, ctx.accounts.pool, fee, max_fee, expected_sequence)?;
+
+    let fee_accounts = FeeAccounts {
+        payer: ctx.accounts.payer,
+        fee_vault: ctx.accounts.fee_vault,
+        fee_mint: ctx.accounts.fee_mint,
+        payer_token_account: ctx.accounts.payer_token_account,
+        treasury_token_account: ctx.accounts.treasury_token_account,
+        token_program: ctx.accounts.token_program,
+        fee_recipients,
+        referrer: None,
+        referrer_token_account: None,
+        protocol_treasury: ctx.accounts.protocol_treasury,
+        protocol_treasury_token_account: ctx.accounts.protocol_treasury_token_account,
+    };
+    fee_accounts.pay(&pool, fee_amount)?;
+    fee_accounts.pay_protocol_fee(&pool, global_config.as_ref(), protocol_fee)?;
+
+    for (source_account, dest_account, royalties, creators) in swaps {
+        if let Some(royalties) = &royalties {
+            fee_accounts.pay_royalties(&pool, royalties, pool.royalty_base_amount()?, creators)?;
+        }
+
+        TransferV1CpiBuilder::new(ctx.accounts.core_program)
+            .asset(source_account)
+            .new_owner(ctx.accounts.pool)
+            .collection(Some(ctx.accounts.collection))
+            .payer(ctx.accounts.payer)
+            .authority(Some(ctx.accounts.payer))
+            .invoke()?;
+
+        TransferV1CpiBuilder::new(ctx.accounts.core_program)
+            .asset(dest_account)
+            .new_owner(ctx.accounts.payer)
+            .collection(Some(ctx.accounts.collection))
+            .payer(ctx.accounts.payer)
+            .authority(Some(ctx.accounts.pool))
+            .invoke_signed(&[&seeds])?;
+
+        inventory.replace(dest_account.key, *source_account.key);
+    }
+
+    inventory.save()
+}
+
+pub(crate) fn deposit_many<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    // Accounts.
+    let ctx = DepositManyAccounts::context(accounts)?;
+
+    // Guards.
+    assert_program_not_paused(ctx.accounts.global_config)?;
+    assert_same_pubkeys("core_program", ctx.accounts.core_program, &MPL_CORE_ID)?;
+    assert_same_pubkeys(
+        "system_program",
+        ctx.accounts.system_program,
+        &system_program::id(),
+    )?;
+    assert_writable("payer", ctx.accounts.payer)?;
+    assert_signer("payer", ctx.accounts.payer)?;
+
+    let mut pool = Checked::<Pool>::load_mut("pool", ctx.accounts.pool)?;
+    assert_pool_status(
+        &pool,
+        ctx.accounts.pool,
+        &[PoolStatus::Pending, PoolStatus::Active, PoolStatus::Paused],
+    )?;
+    assert_no_open_commits(&pool, ctx.accounts.pool)?;
+
+    assert_mpl_core_collection("collection", ctx.accounts.collection)?;
+    assert_same_pubkeys("collection", ctx.accounts.collection, &pool.collection)?;
+
+    // Remaining accounts are the assets to deposit.
+    let assets = ctx.remaining_accounts;
+    if assets.is_empty() {
+        msg!("Expected at least one asset as remaining accounts");
+        return Err(FloorSwapError::InvalidRemainingAccounts.into());
+    }
+    assert_unique_accounts("asset", assets)?;
+
+    for account in assets {
+        let asset = assert_mpl_core_asset("asset", account, &pool.collection)?;
+        assert_asset_owner("asset", asset, ctx.accounts.payer.key)?;
+        assert_writable("asset", account)?;
+    }
+
+    let mut inventory = Checked::<Inventory>::load_mut("inventory", ctx.accounts.inventory)?;
+    assert_same_pubkeys("pool", ctx.accounts.pool, &inventory.pool)?;
+
+    for account in assets {
+        TransferV1CpiBuilder::new(ctx.accounts.core_program)
+            .asset(account)
+            .new_owner(ctx.accounts.pool)
+            .collection(Some(ctx.accounts.collection))
+            .payer(ctx.accounts.payer)
+            .authority(Some(ctx.accounts.payer))
+            .invoke()?;
+    }
+
+    for account in assets {
+        inventory.insert(*account.key);
+    }
+    inventory.resize_and_save(ctx.accounts.payer)?;
+
+    pool.num_assets = inventory.num_assets()?;
+    pool.save()
+}
+
+pub(crate) fn withdraw_many<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    // Accounts.
+    let ctx = WithdrawManyAccounts::context(accounts)?;
+
+    // Guards.
+    assert_program_not_paused(ctx.accounts.global_config)?;
+    let mut pool = Checked::<Pool>::load_mut("pool", ctx.accounts.pool)?;
+    assert_no_open_commits(&pool, ctx.accounts.pool)?;
+
+    assert_mpl_core_collection("collection", ctx.accounts.collection)?;
+
+    assert_same_pubkeys("core_program", ctx.accounts.core_program, &MPL_CORE_ID)?;
+    assert_same_pubkeys("collection", ctx.accounts.collection, &pool.collection)?;
+    assert_same_pubkeys("authority", ctx.accounts.authority, &pool.authority)?;
+
+    assert_signer("authority", ctx.accounts.authority)?;
+
+    // Remaining accounts are the multisig signers, if any, then the assets to withdraw.
+    let (signers, assets) = split_multisig_signers(&pool, ctx.remaining_accounts)?;
+    assert_multisig(&pool, signers)?;
+    if assets.is_empty() {
+        msg!("Expected at least one asset as remaining accounts");
+        return Err(FloorSwapError::InvalidRemainingAccounts.into());
+    }
+    assert_unique_accounts("asset", assets)?;
+
+    for account in assets {
+        let asset = assert_mpl_core_asset("asset", account, &pool.collection)?;
+        assert_asset_owner("asset", asset, ctx.accounts.pool.key)?;
+        assert_writable("asset", account)?;
+    }
+
+    // As with single withdrawals, assets sent to the pool without the
+    // deposit ix must be reconciled first.
+    let mut inventory = Checked::<Inventory>::load_mut("inventory", ctx.accounts.inventory)?;
+    assert_same_pubkeys("pool", ctx.accounts.pool, &inventory.pool)?;
+    for account in assets {
+        assert_in_inventory("asset", account, &inventory)?;
+    }
+
+    let seeds = pool.signer_seeds();
+
+    let destination = ctx.accounts.destination.unwrap_or(ctx.accounts.authority);
+
+    for account in assets {
+        TransferV1CpiBuilder::new(ctx.accounts.core_program)
+            .asset(account)
+            .new_owner(destination)
+            .collection(Some(ctx.accounts.collection))
+            .payer(ctx.accounts.authority)
+            .authority(Some(ctx.accounts.pool))
+            .invoke_signed(&[&seeds])?;
+    }
+
+    for account in assets {
+        inventory.remove(account.key);
+    }
+    inventory.resize_and_save(ctx.accounts.authority)?;
+
+    pool.num_assets = inventory.num_assets()?;
+    pool.save()
+}
+
+pub(crate) fn reconcile<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    // Accounts.
+    let ctx = ReconcileAccounts::context(accounts)?;
+
+    // Guards.
+    assert_same_pubkeys(
+        "system_program",
+        ctx.accounts.system_program,
+        &system_program::id(),
+    )?;
+    assert_writable("payer", ctx.accounts.payer)?;
+    assert_signer("payer", ctx.accounts.payer)?;
+
+    let mut pool = Checked::<Pool>::load_mut("pool", ctx.accounts.pool)?;
+    assert_no_open_commits(&pool, ctx.accounts.pool)?;
+
+    assert_mpl_core_collection("collection", ctx.accounts.collection)?;
+    assert_same_pubkeys("collection", ctx.accounts.collection, &pool.collection)?;
+
+    // Remaining accounts are assets held by the pool, whether tracked or not, and tracked
+    // assets the pool no longer holds.
+    let assets = ctx.remaining_accounts;
+    if assets.is_empty() {
+        msg!("Expected at least one asset as remaining accounts");
+        return Err(FloorSwapError::InvalidRemainingAccounts.into());
+    }
+    assert_unique_accounts("asset", assets)?;
+
+    let mut inventory = Checked::<Inventory>::load_mut("inventory", ctx.accounts.inventory)?;
+    assert_same_pubkeys("pool", ctx.accounts.pool, &inventory.pool)?;
+
+    for account in assets {
+        if inventory.contains(account.key) {
+            // Tracked assets that were burned or left the pool are dropped.
+            let held = account.owner == &MPL_CORE_ID
+                && Asset::from_bytes(&account.data.borrow())
+                    .is_ok_and(|asset| asset.base.owner == *ctx.accounts.pool.key);
+            if !held {
+                inventory.remove(account.key);
+            }
+        } else {
+            let asset = assert_mpl_core_asset("asset", account, &pool.collection)?;
+            assert_asset_owner("asset", asset, ctx.accounts.pool.key)?;
+            inventory.insert(*account.key);
+        }
+    }
+    inventory.resize_and_save(ctx.accounts.payer)?;
+
+    pool.num_assets = inventory.num_assets()?;
+    pool.save()
+}
+
+/// Split the remaining accounts into the multisig signers, as many as the threshold of the
+/// pool multisig, and the accounts that follow them.
+fn split_multisig_signers<'a, 'b>(
+    pool: &Pool,
+    accounts: &'a [AccountInfo<'b>],
+) -> Result<(&'a [AccountInfo<'b>], &'a [AccountInfo<'b>]), ProgramError> {
+    let num_signers = pool
+        .multisig
+        .as_ref()
+        .map_or(0, |multisig| multisig.threshold as usize);
+
+    if accounts.len() < num_signers {
+        msg!(
+            "Expected {} multisig signer accounts, got {}",
+            num_signers,
+            accounts.len()
+        );
+        return Err(FloorSwapError::InvalidRemainingAccounts.into());
+    }
+
+    Ok(accounts.split_at(num_signers))
+}
+
+pub(crate) fn migrate<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    max_fee: u64,
+    fee_delay: u32,
+    max_referral_bps: u16,
+) -> ProgramResult {
+    // Accounts.
+    let ctx = MigrateAccounts::context(accounts)?;
+
+    // Guards.
+    assert_program_owner("pool", ctx.accounts.pool, &crate::ID)?;
+    assert_account_key("pool", ctx.accounts.pool, Key::Pool)?;
+    // Pools already on the current layout are longer.
+    assert_account_len("pool", ctx.accounts.pool, LegacyPool::LEN)?;
+    let legacy = LegacyPool::load(ctx.accounts.pool, 0)?;
+
+    assert_same_pubkeys("authority", ctx.accounts.authority, &legacy.authority)?;
+    assert_signer("authority", ctx.accounts.authority)?;
+
+    // Legacy pools have the seeds of the first pool of the authority.
+    let bump = assert_pda(
+        "pool",
+        ctx.accounts.pool,
+        &crate::ID,
+        &Pool::seeds(&legacy.authority, &legacy.collection, &0),
+    )?;
+    assert_writable("pool", ctx.accounts.pool)?;
+    let fee_vault_bump = assert_pda(
+        "fee_vault",
+        ctx.accounts.fee_vault,
+        &crate::ID,
+        &FeeVault::seeds(ctx.accounts.pool.key),
+    )?;
+    assert_writable("fee_vault", ctx.accounts.fee_vault)?;
+    let inventory_bump = assert_pda(
+        "inventory",
+        ctx.accounts.inventory,
+        &crate::ID,
+        &Inventory::seeds(ctx.accounts.pool.key),
+    )?;
+    assert_writable("inventory", ctx.accounts.inventory)?;
+
+    assert_mpl_core_collection("collection", ctx.accounts.collection)?;
+    assert_same_pubkeys("collection", ctx.accounts.collection, &legacy.collection)?;
+
+    let fee_model = FeeModel::Flat {
+        lamports: legacy.fee_amount,
+    };
+    assert_fee_ceiling("fee_model", fee_model.compute(0)?, max_fee)?;
+    assert_referral_bps("max_referral_bps", max_referral_bps, MAX_REFERRAL_BPS)?;
+
+    assert_signer("payer", ctx.accounts.payer)?;
+    assert_writable("payer", ctx.accounts.payer)?;
+    assert_same_pubkeys(
+        "system_program",
+        ctx.accounts.system_program,
+        &system_program::id(),
+    )?;
+
+    // Remaining accounts are the assets held by the pool.
+    let assets = ctx.remaining_accounts;
+    assert_unique_accounts("asset", assets)?;
+    for account in assets {
+        let asset = assert_mpl_core_asset("asset", account, &legacy.collection)?;
+        assert_asset_owner("asset", asset, ctx.accounts.pool.key)?;
+    }
+
+    // Create FeeVault PDA.
+    let fee_vault = FeeVault {
+        key: Key::FeeVault,
+        pool: *ctx.accounts.pool.key,
+        lifetime_fees: 0,
+        bump: fee_vault_bump,
+    };
+    let mut seeds = FeeVault::seeds(ctx.accounts.pool.key);
+    let fee_vault_bump = [fee_vault_bump];
+    seeds.push(&fee_vault_bump);
+    create_account(
+        ctx.accounts.fee_vault,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+        FeeVault::LEN,
+        &crate::ID,
+        Some(&[&seeds]),
+    )?;
+    fee_vault.save(ctx.accounts.fee_vault, 0)?;
+
+    // Create Inventory PDA.
+    let inventory = Inventory {
+        key: Key::Inventory,
+        pool: *ctx.accounts.pool.key,
+        bump: inventory_bump,
+        assets: assets.iter().map(|account| *account.key).collect(),
+    };
+    let mut seeds = Inventory::seeds(ctx.accounts.pool.key);
+    let inventory_bump = [inventory_bump];
+    seeds.push(&inventory_bump);
+    create_account(
+        ctx.accounts.inventory,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+        inventory.size(),
+        &crate::ID,
+        Some(&[&seeds]),
+    )?;
+    inventory.save(ctx.accounts.inventory, 0)?;
+
+    // Rewrite the pool with the current layout.
+    let pool = Pool {
+        key: Key::Pool,
+        authority: legacy.authority,
+        collection: legacy.collection,
+        treasury: legacy.treasury,
+        status: if legacy.enabled {
+            PoolStatus::Active
+        } else {
+            PoolStatus::Paused
+        },
+        num_assets: inventory.num_assets()?,
+        bump,
+        mode: PoolMode::Standard,
+        reference_price: 0,
+        royalty_base: RoyaltyBase::Fee,
+        referral_bps: 0,
+        sequence: 0,
+        max_fee,
+        fee_delay,
+        max_referral_bps,
+        creator: legacy.authority,
+        index: 0,
+        open_commits: 0,
+        commit_count: 0,
+        fee_model,
+        fee_mint: None,
+        pending_fee: None,
+        pending_authority: None,
+        operator: None,
+        fee_manager: None,
+        multisig: None,
+        fee_split: vec![],
+    };
+    resize_account(ctx.accounts.pool, ctx.accounts.payer, Pool::LEN)?;
+    pool.save(ctx.accounts.pool, 0)
+}
@@ -0,0 +1,311 @@
+use mpl_core::instructions::TransferV1CpiBuilder;
+use mpl_core::ID as MPL_CORE_ID;
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, hash::hashv, msg,
+    system_program, sysvar::slot_hashes, sysvar::Sysvar,
+};
+
+use crate::assertions::{
+    assert_asset_owner, assert_empty, assert_mpl_core_asset, assert_mpl_core_collection,
+    assert_next_commit, assert_pda, assert_pool_mode, assert_pool_status,
+    assert_program_not_paused, assert_same_pubkeys, assert_signer, assert_swap_terms,
+    assert_writable,
+};
+use crate::error::FloorSwapError;
+use crate::instruction::accounts::{CancelSwapAccounts, CommitSwapAccounts, RevealSwapAccounts};
+use crate::processor::{find_royalties, split_fee_recipients, total_royalties, FeeAccounts};
+use crate::state::inventory::Inventory;
+use crate::state::pool::{Pool, PoolMode, PoolStatus};
+use crate::state::swap_commit::SwapCommit;
+use crate::state::{Checked, Key, SolanaAccount};
+use crate::utils::{close_account, create_account, find_slot_hash_after, SlotHashLookup};
+
+pub(crate) fn commit_swap<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    max_fee: u64,
+    expected_sequence: Option<u64>,
+) -> ProgramResult {
+    // Accounts.
+    let ctx = CommitSwapAccounts::context(accounts)?;
+
+    // Guards.
+    let global_config = assert_program_not_paused(ctx.accounts.global_config)?;
+    assert_same_pubkeys("core_program", ctx.accounts.core_program, &MPL_CORE_ID)?;
+    assert_same_pubkeys(
+        "system_program",
+        ctx.accounts.system_program,
+        &system_program::id(),
+    )?;
+
+    let mut pool = Checked::<Pool>::load_mut("pool", ctx.accounts.pool)?;
+
+    assert_pool_status(&pool, ctx.accounts.pool, &[PoolStatus::Active])?;
+    assert_pool_mode(&pool, ctx.accounts.pool, PoolMode::Mystery)?;
+    if pool.num_assets == 0 {
+        msg!("Pool [{}] has no assets to swap for", ctx.accounts.pool.key);
+        return Err(FloorSwapError::PoolHasNoAssets.into());
+    }
+    let protocol_fee = global_config.as_ref().map_or(Ok(0), |global_config| {
+        global_config.protocol_swap_fee(&pool)
+    })?;
+
+    let collection = assert_mpl_core_collection("collection", ctx.accounts.collection)?;
+    assert_same_pubkeys("collection", ctx.accounts.collection, &pool.collection)?;
+
+    let bump = assert_pda(
+        "swap_commit",
+        ctx.accounts.swap_commit,
+        &crate::ID,
+        &SwapCommit::seeds(ctx.accounts.pool.key, ctx.accounts.source_asset.key),
+    )?;
+    assert_empty("swap_commit", ctx.accounts.swap_commit)?;
+
+    let source_asset =
+        assert_mpl_core_asset("source_asset", ctx.accounts.source_asset, &pool.collection)?;
+    // The destination is not known yet, so asset royalties come from the source asset.
+    let royalties = find_royalties(&collection, &source_asset);
+    assert_asset_owner("source_asset", source_asset, ctx.accounts.payer.key)?;
+
+    let royalty_fee = total_royalties(royalties.as_ref(), pool.royalty_base_amount()?)?;
+    let fee = pool
+        .swap_fee()?
+        .checked_add(protocol_fee)
+        .and_then(|fee| fee.checked_add(royalty_fee))
+        .ok_or(FloorSwapError::NumericalOverflow)?;
+    assert_swap_terms(&pool, ctx.accounts.pool, fee, max_fee, expected_sequence)?;
+
+    assert_signer("payer", ctx.accounts.payer)?;
+
+    assert_writable("payer", ctx.accounts.payer)?;
+    assert_writable("swap_commit", ctx.accounts.swap_commit)?;
+    assert_writable("source_asset", ctx.accounts.source_asset)?;
+
+    // Remaining accounts are the fee recipients followed by the royalty creators.
+    let (fee_recipients, creators) = split_fee_recipients(&pool, ctx.remaining_accounts)?;
+
+    let fee_accounts = FeeAccounts {
+        payer: ctx.accounts.payer,
+        fee_vault: ctx.accounts.fee_vault,
+        fee_mint: ctx.accounts.fee_mint,
+        payer_token_account: ctx.accounts.payer_token_account,
+        treasury_token_account: ctx.accounts.treasury_token_account,
+        token_program: ctx.accounts.token_program,
+        fee_recipients,
+        referrer: None,
+        referrer_token_account: None,
+        protocol_treasury: ctx.accounts.protocol_treasury,
+        protocol_treasury_token_account: ctx.accounts.protocol_treasury_token_account,
+    };
+    fee_accounts.pay(&pool, pool.swap_fee()?)?;
+    fee_accounts.pay_protocol_fee(&pool, global_config.as_ref(), protocol_fee)?;
+
+    if let Some(royalties) = &royalties {
+        fee_accounts.pay_royalties(&pool, royalties, pool.royalty_base_amount()?, creators)?;
+    }
+
+    // Create SwapCommit PDA.
+    let swap_commit = SwapCommit {
+        key: Key::SwapCommit,
+        pool: *ctx.accounts.pool.key,
+        owner: *ctx.accounts.payer.key,
+        source_asset: *ctx.accounts.source_asset.key,
+        slot: Clock::get()?.slot,
+        ticket: pool.commit_count,
+        bump,
+    };
+    let mut seeds = SwapCommit::seeds(ctx.accounts.pool.key, ctx.accounts.source_asset.key);
+    let bump = [bump];
+    seeds.push(&bump);
+    create_account(
+        ctx.accounts.swap_commit,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+        SwapCommit::LEN,
+        &crate::ID,
+        Some(&[&seeds]),
+    )?;
+    swap_commit.save(ctx.accounts.swap_commit, 0)?;
+
+    // The inventory only changes through open swaps until they are all resolved.
+    pool.commit_count = pool
+        .commit_count
+        .checked_add(1)
+        .ok_or(FloorSwapError::NumericalOverflow)?;
+    pool.open_commits = pool
+        .open_commits
+        .checked_add(1)
+        .ok_or(FloorSwapError::NumericalOverflow)?;
+    pool.save()?;
+
+    // Escrow the source asset until the swap is revealed.
+    TransferV1CpiBuilder::new(ctx.accounts.core_program)
+        .asset(ctx.accounts.source_asset)
+        .new_owner(ctx.accounts.swap_commit)
+        .collection(Some(ctx.accounts.collection))
+        .payer(ctx.accounts.payer)
+        .authority(Some(ctx.accounts.payer))
+        .invoke()
+}
+
+pub(crate) fn reveal_swap<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    // Accounts.
+    let ctx = RevealSwapAccounts::context(accounts)?;
+
+    // Guards.
+    assert_program_not_paused(ctx.accounts.global_config)?;
+    assert_same_pubkeys("core_program", ctx.accounts.core_program, &MPL_CORE_ID)?;
+    assert_same_pubkeys("slot_hashes", ctx.accounts.slot_hashes, &slot_hashes::ID)?;
+
+    let mut pool = Checked::<Pool>::load_mut("pool", ctx.accounts.pool)?;
+    let swap_commit = Checked::<SwapCommit>::load_mut("swap_commit", ctx.accounts.swap_commit)?;
+
+    assert_same_pubkeys("pool", ctx.accounts.pool, &swap_commit.pool)?;
+    assert_same_pubkeys(
+        "source_asset",
+        ctx.accounts.source_asset,
+        &swap_commit.source_asset,
+    )?;
+    assert_same_pubkeys("owner", ctx.accounts.owner, &swap_commit.owner)?;
+    assert_next_commit(&pool, ctx.accounts.swap_commit, swap_commit.ticket)?;
+
+    assert_mpl_core_collection("collection", ctx.accounts.collection)?;
+    assert_same_pubkeys("collection", ctx.accounts.collection, &pool.collection)?;
+
+    assert_signer("payer", ctx.accounts.payer)?;
+
+    assert_writable("payer", ctx.accounts.payer)?;
+    assert_writable("owner", ctx.accounts.owner)?;
+    assert_writable("source_asset", ctx.accounts.source_asset)?;
+
+    let slot_hash = match find_slot_hash_after(ctx.accounts.slot_hashes, swap_commit.slot)? {
+        SlotHashLookup::Found(hash) => hash,
+        SlotHashLookup::Pending => {
+            msg!(
+                "Swap committed at slot {} cannot be revealed yet",
+                swap_commit.slot
+            );
+            return Err(FloorSwapError::SwapNotRevealable.into());
+        }
+        SlotHashLookup::Expired => {
+            msg!("Swap committed at slot {} has expired", swap_commit.slot);
+            return Err(FloorSwapError::SwapCommitExpired.into());
+        }
+    };
+
+    // Each reveal replaces the picked asset with the source asset, so swaps are resolved
+    // in the order they were committed. The inventory a swap picks from then only depends
+    // on the swaps committed before it, not on who reveals first.
+    let mut inventory = Checked::<Inventory>::load_mut("inventory", ctx.accounts.inventory)?;
+    assert_same_pubkeys("pool", ctx.accounts.pool, &inventory.pool)?;
+    if inventory.assets.is_empty() {
+        msg!("Pool [{}] has no assets to swap for", ctx.accounts.pool.key);
+        return Err(FloorSwapError::PoolHasNoAssets.into());
+    }
+
+    let seed = hashv(&[&slot_hash, ctx.accounts.swap_commit.key.as_ref()]).to_bytes();
+    let mut index = [0u8; 8];
+    index.copy_from_slice(&seed[..8]);
+    let picked =
+        inventory.assets[(u64::from_le_bytes(index) % inventory.assets.len() as u64) as usize];
+    assert_same_pubkeys("dest_asset", ctx.accounts.dest_asset, &picked)?;
+
+    let dest_asset =
+        assert_mpl_core_asset("dest_asset", ctx.accounts.dest_asset, &pool.collection)?;
+    assert_asset_owner("dest_asset", dest_asset, ctx.accounts.pool.key)?;
+    assert_writable("dest_asset", ctx.accounts.dest_asset)?;
+
+    TransferV1CpiBuilder::new(ctx.accounts.core_program)
+        .asset(ctx.accounts.dest_asset)
+        .new_owner(ctx.accounts.owner)
+        .collection(Some(ctx.accounts.collection))
+        .payer(ctx.accounts.payer)
+        .authority(Some(ctx.accounts.pool))
+        .invoke_signed(&[&pool.signer_seeds()])?;
+
+    TransferV1CpiBuilder::new(ctx.accounts.core_program)
+        .asset(ctx.accounts.source_asset)
+        .new_owner(ctx.accounts.pool)
+        .collection(Some(ctx.accounts.collection))
+        .payer(ctx.accounts.payer)
+        .authority(Some(ctx.accounts.swap_commit))
+        .invoke_signed(&[&swap_commit.signer_seeds()])?;
+
+    inventory.replace(&picked, *ctx.accounts.source_asset.key);
+    inventory.save()?;
+
+    pool.open_commits = pool.open_commits.saturating_sub(1);
+    pool.save()?;
+
+    close_account(ctx.accounts.swap_commit, ctx.accounts.owner)
+}
+
+pub(crate) fn cancel_swap<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    // Accounts.
+    let ctx = CancelSwapAccounts::context(accounts)?;
+
+    // Guards.
+    assert_program_not_paused(ctx.accounts.global_config)?;
+    assert_same_pubkeys("core_program", ctx.accounts.core_program, &MPL_CORE_ID)?;
+    assert_same_pubkeys("slot_hashes", ctx.accounts.slot_hashes, &slot_hashes::ID)?;
+    assert_same_pubkeys(
+        "system_program",
+        ctx.accounts.system_program,
+        &system_program::id(),
+    )?;
+
+    let mut pool = Checked::<Pool>::load_mut("pool", ctx.accounts.pool)?;
+    let swap_commit = Checked::<SwapCommit>::load_mut("swap_commit", ctx.accounts.swap_commit)?;
+
+    assert_same_pubkeys("pool", ctx.accounts.pool, &swap_commit.pool)?;
+    assert_same_pubkeys(
+        "source_asset",
+        ctx.accounts.source_asset,
+        &swap_commit.source_asset,
+    )?;
+    assert_same_pubkeys("owner", ctx.accounts.owner, &swap_commit.owner)?;
+    assert_next_commit(&pool, ctx.accounts.swap_commit, swap_commit.ticket)?;
+
+    assert_mpl_core_collection("collection", ctx.accounts.collection)?;
+    assert_same_pubkeys("collection", ctx.accounts.collection, &pool.collection)?;
+
+    assert_signer("payer", ctx.accounts.payer)?;
+
+    assert_writable("payer", ctx.accounts.payer)?;
+    assert_writable("owner", ctx.accounts.owner)?;
+    assert_writable("source_asset", ctx.accounts.source_asset)?;
+
+    // Only swaps that can no longer be revealed may be cancelled, otherwise
+    // the owner could cancel any swap whose outcome they do not like.
+    if !matches!(
+        find_slot_hash_after(ctx.accounts.slot_hashes, swap_commit.slot)?,
+        SlotHashLookup::Expired
+    ) {
+        msg!(
+            "Swap committed at slot {} has not expired",
+            swap_commit.slot
+        );
+        return Err(FloorSwapError::SwapCommitNotExpired.into());
+    }
+
+    let mut inventory = Checked::<Inventory>::load_mut("inventory", ctx.accounts.inventory)?;
+    assert_same_pubkeys("pool", ctx.accounts.pool, &inventory.pool)?;
+
+    // The source asset is forfeited to the pool, so letting a swap expire cannot be used
+    // to retry it with another slot hash.
+    TransferV1CpiBuilder::new(ctx.accounts.core_program)
+        .asset(ctx.accounts.source_asset)
+        .new_owner(ctx.accounts.pool)
+        .collection(Some(ctx.accounts.collection))
+        .payer(ctx.accounts.payer)
+        .authority(Some(ctx.accounts.swap_commit))
+        .invoke_signed(&[&swap_commit.signer_seeds()])?;
+
+    inventory.insert(*ctx.accounts.source_asset.key);
+    inventory.resize_and_save(ctx.accounts.payer)?;
+
+    pool.num_assets = inventory.num_assets()?;
+    pool.open_commits = pool.open_commits.saturating_sub(1);
+    pool.save()?;
+
+    close_account(ctx.accounts.swap_commit, ctx.accounts.owner)
+}
@@ -0,0 +1,57 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use shank::ShankAccount;
+use solana_program::{program_error::ProgramError, pubkey::Pubkey, rent::Rent, sysvar::Sysvar};
+
+use crate::state::{Checked, Key, PdaAccount, SolanaAccount};
+
+pub(crate) const PREFIX: &str = "fee_vault";
+
+/// The program account where the swap fees of a pool paid in lamports accrue
+/// until they are claimed.
+#[repr(C)]
+#[derive(Clone, BorshSerialize, BorshDeserialize, Debug, ShankAccount)]
+pub struct FeeVault {
+    pub key: Key,
+    pub pool: Pubkey,
+    /// The total lamports of fees ever paid into the vault.
+    pub lifetime_fees: u64,
+    pub bump: u8,
+}
+
+impl FeeVault {
+    pub const LEN: usize = 1 + 32 + 8 + 1;
+
+    pub fn seeds(pool: &Pubkey) -> Vec<&[u8]> {
+        vec![PREFIX.as_bytes(), pool.as_ref()]
+    }
+
+    pub fn find_pda(pool: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&Self::seeds(pool), &crate::ID)
+    }
+}
+
+impl Checked<'_, '_, FeeVault> {
+    /// Get the lamports of the vault above its rent exemption.
+    pub fn claimable(&self) -> Result<u64, ProgramError> {
+        let rent = Rent::get()?.minimum_balance(FeeVault::LEN);
+        Ok(self.info().lamports().saturating_sub(rent))
+    }
+}
+
+impl SolanaAccount for FeeVault {
+    fn key() -> Key {
+        Key::FeeVault
+    }
+}
+
+impl PdaAccount for FeeVault {
+    const LEN: usize = FeeVault::LEN;
+
+    fn pda_seeds(&self) -> Vec<&[u8]> {
+        Self::seeds(&self.pool)
+    }
+
+    fn pda_bump(&self) -> u8 {
+        self.bump
+    }
+}
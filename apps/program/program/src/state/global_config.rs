@@ -0,0 +1,92 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use shank::ShankAccount;
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+
+use crate::error::FloorSwapError;
+use crate::state::{
+    pool::{FeeModel, Pool},
+    Key, PdaAccount, SolanaAccount,
+};
+use crate::utils::apply_bps;
+
+pub(crate) const PREFIX: &str = "global_config";
+
+/// The maximum number of guardians allowed to pause the program.
+pub const MAX_GUARDIANS: usize = 5;
+
+/// The maximum protocol fee in basis points of the pool swap fee.
+pub const MAX_PROTOCOL_FEE_BPS: u16 = 500;
+
+/// The maximum flat protocol fee, in lamports.
+pub const MAX_PROTOCOL_FEE_FLAT: u64 = 100_000_000;
+
+/// The program-wide settings, a singleton managed by the program upgrade authority.
+#[repr(C)]
+#[derive(Clone, BorshSerialize, BorshDeserialize, Debug, ShankAccount)]
+pub struct GlobalConfig {
+    pub key: Key,
+    /// The upgrade authority that last initialised or updated the config.
+    pub admin: Pubkey,
+    /// Halts swaps, deposits and withdrawals on every pool.
+    pub paused: bool,
+    pub bump: u8,
+    /// The wallet receiving the protocol fee.
+    pub protocol_treasury: Pubkey,
+    // Variable-length fields are kept last.
+    /// The fee charged on every swap on top of the pool fee, in the pool fee currency. Its
+    /// basis points apply to the pool swap fee and its flat part is only charged in SOL.
+    pub protocol_fee: FeeModel,
+    /// The keys allowed to pause the program besides the admin.
+    pub guardians: Vec<Pubkey>,
+}
+
+impl GlobalConfig {
+    pub const LEN: usize = 1 + 32 + 1 + 1 + 32 + FeeModel::LEN + 4 + MAX_GUARDIANS * 32;
+
+    pub fn seeds<'a>() -> Vec<&'a [u8]> {
+        vec![PREFIX.as_bytes()]
+    }
+
+    pub fn find_pda() -> (Pubkey, u8) {
+        Pubkey::find_program_address(&Self::seeds(), &crate::ID)
+    }
+
+    /// Compute the protocol fee charged on a single swap of the given pool.
+    ///
+    /// The flat part is in lamports, so pools charging their fee in a token only pay the
+    /// basis points of their swap fee.
+    pub fn protocol_swap_fee(&self, pool: &Pool) -> Result<u64, ProgramError> {
+        let fee = apply_bps(pool.swap_fee()?, self.protocol_fee.bps())?;
+        match (self.protocol_fee, pool.fee_mint) {
+            (FeeModel::Flat { lamports } | FeeModel::FlatPlusBps { lamports, .. }, None) => fee
+                .checked_add(lamports)
+                .ok_or(FloorSwapError::NumericalOverflow.into()),
+            _ => Ok(fee),
+        }
+    }
+
+    /// Get the keys allowed to pause the program.
+    pub fn pausers(&self) -> Vec<Pubkey> {
+        std::iter::once(self.admin)
+            .chain(self.guardians.iter().copied())
+            .collect()
+    }
+}
+
+impl SolanaAccount for GlobalConfig {
+    fn key() -> Key {
+        Key::GlobalConfig
+    }
+}
+
+impl PdaAccount for GlobalConfig {
+    const LEN: usize = GlobalConfig::LEN;
+
+    fn pda_seeds(&self) -> Vec<&[u8]> {
+        Self::seeds()
+    }
+
+    fn pda_bump(&self) -> u8 {
+        self.bump
+    }
+}
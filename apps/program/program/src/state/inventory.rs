@@ -0,0 +1,113 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use shank::ShankAccount;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::error::FloorSwapError;
+use crate::state::{Checked, Key, PdaAccount, SolanaAccount};
+use crate::utils::resize_account;
+
+pub(crate) const PREFIX: &str = "inventory";
+
+/// The registry of the assets held by a pool, resized as assets come and go.
+#[repr(C)]
+#[derive(Clone, BorshSerialize, BorshDeserialize, Debug, ShankAccount)]
+pub struct Inventory {
+    pub key: Key,
+    pub pool: Pubkey,
+    pub bump: u8,
+    // Variable-length fields are kept last.
+    /// The assets deposited in the pool, in no particular order.
+    pub assets: Vec<Pubkey>,
+}
+
+impl Inventory {
+    /// The length of an empty inventory.
+    pub const LEN: usize = 1 + 32 + 1 + 4;
+
+    pub fn seeds(pool: &Pubkey) -> Vec<&[u8]> {
+        vec![PREFIX.as_bytes(), pool.as_ref()]
+    }
+
+    pub fn find_pda(pool: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&Self::seeds(pool), &crate::ID)
+    }
+
+    /// Get the length of the account data holding the current assets.
+    pub fn size(&self) -> usize {
+        Self::LEN + self.assets.len() * 32
+    }
+
+    /// Get the number of assets held by the pool.
+    pub fn num_assets(&self) -> Result<u16, ProgramError> {
+        u16::try_from(self.assets.len()).map_err(|_| FloorSwapError::NumericalOverflow.into())
+    }
+
+    /// Whether the pool holds the given asset.
+    pub fn contains(&self, asset: &Pubkey) -> bool {
+        self.assets.contains(asset)
+    }
+
+    /// Add the given asset unless it is already tracked, returning whether it was added.
+    pub fn insert(&mut self, asset: Pubkey) -> bool {
+        if self.contains(&asset) {
+            false
+        } else {
+            self.assets.push(asset);
+            true
+        }
+    }
+
+    /// Remove the given asset, returning whether the pool held it.
+    pub fn remove(&mut self, asset: &Pubkey) -> bool {
+        match self.assets.iter().position(|held| held == asset) {
+            Some(index) => {
+                self.assets.swap_remove(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Replace an asset leaving the pool with the one taking its place, returning whether
+    /// the pool held it.
+    pub fn replace(&mut self, asset: &Pubkey, new_asset: Pubkey) -> bool {
+        match self.assets.iter_mut().find(|held| *held == asset) {
+            Some(held) => {
+                *held = new_asset;
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl<'b> Checked<'_, 'b, Inventory> {
+    /// Resize the account to fit its assets then save it, the payer topping up the rent
+    /// when it grows.
+    pub fn resize_and_save(&self, payer: &AccountInfo<'b>) -> ProgramResult {
+        resize_account(self.info(), payer, self.size())?;
+        self.save()
+    }
+}
+
+impl SolanaAccount for Inventory {
+    fn key() -> Key {
+        Key::Inventory
+    }
+}
+
+impl PdaAccount for Inventory {
+    const LEN: usize = Inventory::LEN;
+    const RESIZABLE: bool = true;
+
+    fn pda_seeds(&self) -> Vec<&[u8]> {
+        Self::seeds(&self.pool)
+    }
+
+    fn pda_bump(&self) -> u8 {
+        self.bump
+    }
+}
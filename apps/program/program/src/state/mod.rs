@@ -5,7 +5,11 @@ mod traits;
 use strum::EnumIter;
 pub use traits::*;
 
+pub mod fee_vault;
+pub mod global_config;
+pub mod inventory;
 pub mod pool;
+pub mod swap_commit;
 
 /// An enum representing account discriminators.
 #[derive(
@@ -25,6 +29,14 @@ pub enum Key {
     Uninitialized,
     /// An account holding master settings.
     Pool,
+    /// A pending mystery swap waiting to be revealed.
+    SwapCommit,
+    /// The vault holding the swap fees of a pool until they are claimed.
+    FeeVault,
+    /// The program-wide settings.
+    GlobalConfig,
+    /// The registry of the assets held by a pool.
+    Inventory,
 }
 
 impl Key {
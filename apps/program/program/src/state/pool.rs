@@ -1,54 +1,349 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use shank::ShankAccount;
-use solana_program::account_info::AccountInfo;
-use solana_program::entrypoint::ProgramResult;
-use solana_program::msg;
-use solana_program::program_error::ProgramError;
-use solana_program::pubkey::Pubkey;
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
 
 use crate::{
     error::FloorSwapError,
-    state::{Key, SolanaAccount},
+    state::{Key, PdaAccount, SolanaAccount},
+    utils::apply_bps,
 };
 
 pub(crate) const PREFIX: &str = "floor_swap";
 
+/// The maximum number of recipients in the fee split of a pool.
+pub const MAX_FEE_RECIPIENTS: usize = 5;
+
+/// The highest referral share a pool can be created with, in basis points of the swap fee.
+pub const MAX_REFERRAL_BPS: u16 = 5_000;
+
+/// The maximum number of signers in the multisig of a pool.
+pub const MAX_MULTISIG_SIGNERS: usize = 5;
+
+/// Where a pool is in its lifecycle, which decides the instructions it accepts.
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, Debug, PartialEq, Eq)]
+pub enum PoolStatus {
+    /// Created but not launched yet, only taking deposits.
+    Pending,
+    /// Open for swaps and deposits.
+    Active,
+    /// Temporarily closed to swaps, still taking deposits.
+    Paused,
+    /// Winding down, assets can only be withdrawn.
+    WindDown,
+    /// Being closed, assets can only be withdrawn before `Close`.
+    Closing,
+}
+
+impl PoolStatus {
+    /// Whether `SetStatus` can move a pool from this status to the given one.
+    pub fn can_transition_to(&self, status: PoolStatus) -> bool {
+        use PoolStatus::*;
+
+        matches!(
+            (*self, status),
+            (Pending, Active | Closing)
+                | (Active, Paused | WindDown | Closing)
+                | (Paused, Active | WindDown | Closing)
+                | (WindDown, Active | Closing)
+        )
+    }
+}
+
+/// A subset of the permissions of the pool authority that can be delegated to another key.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PoolRole {
+    /// Can pause and resume the pool.
+    Operator,
+    /// Can lower and propose swap fees, within the fee ceiling.
+    FeeManager,
+}
+
+/// How the destination asset of a swap is picked.
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, Debug, PartialEq, Eq)]
+pub enum PoolMode {
+    /// The user picks the destination asset with `Swap` or `SwapBatch`.
+    Standard,
+    /// The destination asset is picked at random with `CommitSwap` and `RevealSwap`.
+    Mystery,
+}
+
+/// The amount creator royalties are charged on.
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, Debug, PartialEq, Eq)]
+pub enum RoyaltyBase {
+    /// The swap fee.
+    Fee,
+    /// The pool reference price.
+    ReferencePrice,
+}
+
+/// A recipient of the fee split and its weight.
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, Debug, PartialEq, Eq)]
+pub struct FeeRecipient {
+    pub address: Pubkey,
+    pub bps: u16,
+}
+
+impl FeeRecipient {
+    pub const LEN: usize = 32 + 2;
+}
+
+/// A set of keys, `threshold` of which must sign the admin instructions of a pool.
+#[derive(Clone, BorshSerialize, BorshDeserialize, Debug, PartialEq, Eq)]
+pub struct Multisig {
+    pub threshold: u8,
+    pub signers: Vec<Pubkey>,
+}
+
+impl Multisig {
+    pub const LEN: usize = 1 + 4 + MAX_MULTISIG_SIGNERS * 32;
+}
+
+/// How the fee charged on every swap is computed.
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, Debug, PartialEq, Eq)]
+pub enum FeeModel {
+    /// A flat amount of lamports.
+    Flat { lamports: u64 },
+    /// Basis points of the pool reference price.
+    Bps { bps: u16 },
+    /// A flat amount of lamports plus basis points of the pool reference price.
+    FlatPlusBps { lamports: u64, bps: u16 },
+}
+
+impl FeeModel {
+    /// The serialized length of the largest variant.
+    pub const LEN: usize = 1 + 8 + 2;
+
+    /// Get the basis points of the reference price charged by the model.
+    pub fn bps(&self) -> u16 {
+        match *self {
+            FeeModel::Flat { .. } => 0,
+            FeeModel::Bps { bps } | FeeModel::FlatPlusBps { bps, .. } => bps,
+        }
+    }
+
+    /// Compute the fee in lamports for the given reference price.
+    pub fn compute(&self, reference_price: u64) -> Result<u64, ProgramError> {
+        match *self {
+            FeeModel::Flat { lamports } => Ok(lamports),
+            FeeModel::Bps { bps } => apply_bps(reference_price, bps),
+            FeeModel::FlatPlusBps { lamports, bps } => lamports
+                .checked_add(apply_bps(reference_price, bps)?)
+                .ok_or(FloorSwapError::NumericalOverflow.into()),
+        }
+    }
+}
+
+/// A fee change waiting for the fee delay of the pool to elapse.
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, Debug, PartialEq, Eq)]
+pub struct PendingFee {
+    pub fee_model: FeeModel,
+    pub fee_mint: Option<Pubkey>,
+    /// The unix timestamp from which the fee can be applied.
+    pub effective_at: i64,
+    /// The reference price applied with the fee, or `None` to keep the current one.
+    pub reference_price: Option<u64>,
+    /// The royalty base applied with the fee, or `None` to keep the current one.
+    pub royalty_base: Option<RoyaltyBase>,
+}
+
+impl PendingFee {
+    pub const LEN: usize = FeeModel::LEN + 33 + 8 + 9 + 2;
+}
+
 #[repr(C)]
 #[derive(Clone, BorshSerialize, BorshDeserialize, Debug, ShankAccount)]
 pub struct Pool {
     pub key: Key,
     pub collection: Pubkey,
+    /// The current authority of the pool, which can be transferred.
     pub authority: Pubkey,
     pub treasury: Pubkey,
-    pub fee_amount: u64,
-    pub enabled: bool,
+    pub status: PoolStatus,
     pub num_assets: u16,
+    pub bump: u8,
+    pub mode: PoolMode,
+    pub reference_price: u64,
+    pub royalty_base: RoyaltyBase,
+    /// The share of the swap fee paid to the referrer of a swap, in basis points.
+    pub referral_bps: u16,
+    /// Incremented every time the terms of the pool change, so swaps can expect a version.
+    pub sequence: u64,
+    /// The highest swap fee the pool can ever charge, set at creation.
+    pub max_fee: u64,
+    /// The number of seconds a proposed fee increase waits before it can be applied.
+    pub fee_delay: u32,
+    /// The highest referral share the pool can ever pay, in basis points, set at creation.
+    pub max_referral_bps: u16,
+    /// The authority that created the pool. It stays in the PDA seeds when the authority
+    /// is transferred, so the pool address never changes.
+    pub creator: Pubkey,
+    /// Tells apart the pools of the same creator and collection. The first pool has index 0.
+    pub index: u8,
+    /// The number of mystery swaps committed but not yet revealed or cancelled. The
+    /// inventory only changes through them and the pool cannot close while any is open.
+    pub open_commits: u16,
+    /// The number of mystery swaps ever committed, numbering them so they are revealed or
+    /// cancelled in the order they were committed.
+    pub commit_count: u64,
+    // Variable-length fields are kept last.
+    pub fee_model: FeeModel,
+    /// The mint fees are paid in, or `None` for lamports.
+    pub fee_mint: Option<Pubkey>,
+    /// The fee proposed with `ProposeFee`, if any.
+    pub pending_fee: Option<PendingFee>,
+    /// The authority proposed with `ProposeAuthority`, if any.
+    pub pending_authority: Option<Pubkey>,
+    /// The key holding the operator role, if any.
+    pub operator: Option<Pubkey>,
+    /// The key holding the fee manager role, if any.
+    pub fee_manager: Option<Pubkey>,
+    /// The signers that must also approve `SetFee`, `ProposeFee`, withdrawals and `Close`,
+    /// if any.
+    pub multisig: Option<Multisig>,
+    /// How the fee is split between recipients, or empty when the treasury receives all of
+    /// it. The treasury also receives the rounding dust of the split. Fees paid in lamports
+    /// are split when claimed from the fee vault.
+    pub fee_split: Vec<FeeRecipient>,
 }
 
 impl Pool {
-    pub const LEN: usize = 1 + 32 + 32 + 32 + 8 + 1 + 2;
+    pub const LEN: usize = 1
+        + 32
+        + 32
+        + 32
+        + 1
+        + 2
+        + 1
+        + 1
+        + 8
+        + 1
+        + 2
+        + 8
+        + 8
+        + 4
+        + 2
+        + 32
+        + 1
+        + 2
+        + 8
+        + FeeModel::LEN
+        + 33
+        + 1
+        + PendingFee::LEN
+        + 33
+        + 33
+        + 33
+        + 1
+        + Multisig::LEN
+        + 4
+        + MAX_FEE_RECIPIENTS * FeeRecipient::LEN;
+
+    /// Get the seeds of the pool with the given index. The first pool leaves the index out of
+    /// its seeds, so pools created before indexes existed keep their address.
+    pub fn seeds<'a>(creator: &'a Pubkey, collection: &'a Pubkey, index: &'a u8) -> Vec<&'a [u8]> {
+        let mut seeds = vec![PREFIX.as_bytes(), creator.as_ref(), collection.as_ref()];
+        if *index > 0 {
+            seeds.push(std::slice::from_ref(index));
+        }
+        seeds
+    }
+
+    pub fn find_pda(creator: &Pubkey, collection: &Pubkey, index: u8) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&Self::seeds(creator, collection, &index), &crate::ID)
+    }
+
+    /// Get the keys allowed to act with the given role, the authority holds every role.
+    pub fn signers_for(&self, role: PoolRole) -> Vec<Pubkey> {
+        let holder = match role {
+            PoolRole::Operator => self.operator,
+            PoolRole::FeeManager => self.fee_manager,
+        };
+        std::iter::once(self.authority).chain(holder).collect()
+    }
+
+    /// Record a change to the terms of the pool.
+    pub fn increment_sequence(&mut self) {
+        self.sequence = self.sequence.wrapping_add(1);
+    }
 
-    pub fn seeds<'a>(authority: &'a Pubkey, collection: &'a Pubkey) -> Vec<&'a [u8]> {
-        vec![PREFIX.as_bytes(), authority.as_ref(), collection.as_ref()]
+    /// Get the number of the oldest open mystery swap, the only one that can be revealed
+    /// or cancelled.
+    pub fn next_commit(&self) -> u64 {
+        self.commit_count.saturating_sub(self.open_commits as u64)
     }
 
-    pub fn find_pda(authority: &Pubkey, collection: &Pubkey) -> (Pubkey, u8) {
-        Pubkey::find_program_address(&Self::seeds(authority, collection), &crate::ID)
+    /// Compute the fee charged for a single swap, in lamports or base units of the fee mint.
+    pub fn swap_fee(&self) -> Result<u64, ProgramError> {
+        self.fee_model.compute(self.reference_price)
     }
 
-    pub fn load(account: &AccountInfo) -> Result<Self, ProgramError> {
-        let mut bytes: &[u8] = &(*account.data).borrow();
-        Pool::deserialize(&mut bytes).map_err(|error| {
-            msg!("Error: {}", error);
-            FloorSwapError::DeserializationError.into()
-        })
+    /// Whether switching to the given fee, reference price and royalty base would charge
+    /// swaps more, fee or royalties, or in another mint.
+    pub fn is_fee_increase(
+        &self,
+        fee_model: &FeeModel,
+        fee_mint: &Option<Pubkey>,
+        reference_price: u64,
+        royalty_base: RoyaltyBase,
+    ) -> Result<bool, ProgramError> {
+        let fee = fee_model.compute(reference_price)?;
+        let royalty_base_amount = match royalty_base {
+            RoyaltyBase::Fee => fee,
+            RoyaltyBase::ReferencePrice => reference_price,
+        };
+        Ok(*fee_mint != self.fee_mint
+            || fee > self.swap_fee()?
+            || royalty_base_amount > self.royalty_base_amount()?)
     }
 
-    pub fn save(&self, account: &AccountInfo) -> ProgramResult {
-        borsh::to_writer(&mut account.data.borrow_mut()[..], self).map_err(|error| {
-            msg!("Error: {}", error);
-            FloorSwapError::SerializationError.into()
-        })
+    /// Split the given fee between the fee recipients, returning their shares in the order
+    /// of the fee split and the rounding dust left for the treasury.
+    pub fn split_fee(&self, amount: u64) -> Result<(Vec<u64>, u64), ProgramError> {
+        let mut dust = amount;
+        let shares = self
+            .fee_split
+            .iter()
+            .map(|recipient| {
+                let share = apply_bps(amount, recipient.bps)?;
+                dust = dust
+                    .checked_sub(share)
+                    .ok_or(FloorSwapError::NumericalOverflow)?;
+                Ok(share)
+            })
+            .collect::<Result<Vec<_>, ProgramError>>()?;
+
+        Ok((shares, dust))
+    }
+
+    /// Get the amount creator royalties are charged on for a single swap.
+    pub fn royalty_base_amount(&self) -> Result<u64, ProgramError> {
+        match self.royalty_base {
+            RoyaltyBase::Fee => self.swap_fee(),
+            RoyaltyBase::ReferencePrice => Ok(self.reference_price),
+        }
+    }
+}
+
+/// The layout of the pools created before the pool settings were added. `Migrate` rewrites
+/// them with the current layout.
+#[derive(Clone, BorshSerialize, BorshDeserialize, Debug)]
+pub struct LegacyPool {
+    pub key: Key,
+    pub collection: Pubkey,
+    pub authority: Pubkey,
+    pub treasury: Pubkey,
+    pub fee_amount: u64,
+    pub enabled: bool,
+    pub num_assets: u16,
+}
+
+impl LegacyPool {
+    pub const LEN: usize = 1 + 32 + 32 + 32 + 8 + 1 + 2;
+}
+
+impl SolanaAccount for LegacyPool {
+    fn key() -> Key {
+        Key::Pool
     }
 }
 
@@ -57,3 +352,15 @@ impl SolanaAccount for Pool {
         Key::Pool
     }
 }
+
+impl PdaAccount for Pool {
+    const LEN: usize = Pool::LEN;
+
+    fn pda_seeds(&self) -> Vec<&[u8]> {
+        Self::seeds(&self.creator, &self.collection, &self.index)
+    }
+
+    fn pda_bump(&self) -> u8 {
+        self.bump
+    }
+}
@@ -0,0 +1,52 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use shank::ShankAccount;
+use solana_program::pubkey::Pubkey;
+
+use crate::state::{Key, PdaAccount, SolanaAccount};
+
+pub(crate) const PREFIX: &str = "swap_commit";
+
+/// A committed mystery swap. It holds the source asset in escrow until the
+/// destination is picked with the hash of a slot after `slot`.
+#[repr(C)]
+#[derive(Clone, BorshSerialize, BorshDeserialize, Debug, ShankAccount)]
+pub struct SwapCommit {
+    pub key: Key,
+    pub pool: Pubkey,
+    pub owner: Pubkey,
+    pub source_asset: Pubkey,
+    pub slot: u64,
+    /// The number of the swap among the commits of the pool.
+    pub ticket: u64,
+    pub bump: u8,
+}
+
+impl SwapCommit {
+    pub const LEN: usize = 1 + 32 + 32 + 32 + 8 + 8 + 1;
+
+    pub fn seeds<'a>(pool: &'a Pubkey, source_asset: &'a Pubkey) -> Vec<&'a [u8]> {
+        vec![PREFIX.as_bytes(), pool.as_ref(), source_asset.as_ref()]
+    }
+
+    pub fn find_pda(pool: &Pubkey, source_asset: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&Self::seeds(pool, source_asset), &crate::ID)
+    }
+}
+
+impl SolanaAccount for SwapCommit {
+    fn key() -> Key {
+        Key::SwapCommit
+    }
+}
+
+impl PdaAccount for SwapCommit {
+    const LEN: usize = SwapCommit::LEN;
+
+    fn pda_seeds(&self) -> Vec<&[u8]> {
+        Self::seeds(&self.pool, &self.source_asset)
+    }
+
+    fn pda_bump(&self) -> u8 {
+        self.bump
+    }
+}
@@ -1,9 +1,19 @@
+use std::ops::{Deref, DerefMut};
+
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
 };
 
-use crate::{error::FloorSwapError, state::Key, utils::load_key};
+use crate::{
+    assertions::{
+        assert_account_key, assert_account_len, assert_account_min_len, assert_pda_with_bump,
+        assert_program_owner, assert_writable,
+    },
+    error::FloorSwapError,
+    state::Key,
+    utils::load_key,
+};
 
 /// A trait for generic blobs of data that have size.
 #[allow(clippy::len_without_is_empty)]
@@ -40,3 +50,97 @@ pub trait SolanaAccount: BorshSerialize + BorshDeserialize {
         })
     }
 }
+
+/// A trait for accounts owned by this program at a PDA derived from their own data.
+pub trait PdaAccount: SolanaAccount {
+    /// The expected length of the account data.
+    const LEN: usize;
+
+    /// Whether the account is resized as its data grows, `LEN` being its minimum length.
+    const RESIZABLE: bool = false;
+
+    /// Get the seeds of the account PDA, without the bump.
+    fn pda_seeds(&self) -> Vec<&[u8]>;
+
+    /// Get the bump of the account PDA, as stored when the account was created.
+    fn pda_bump(&self) -> u8;
+}
+
+/// A program account that passed every ownership, discriminator, length and PDA check.
+///
+/// The only way to obtain one is through [`Checked::load`] or [`Checked::load_mut`],
+/// so holding a `Checked<T>` proves the account was validated.
+pub struct Checked<'a, 'b, T: PdaAccount> {
+    info: &'a AccountInfo<'b>,
+    data: T,
+    bump: [u8; 1],
+}
+
+impl<'a, 'b, T: PdaAccount> Checked<'a, 'b, T> {
+    /// Load and validate a read-only program account.
+    pub fn load(account_name: &str, account: &'a AccountInfo<'b>) -> Result<Self, ProgramError> {
+        assert_program_owner(account_name, account, &crate::ID)?;
+        assert_account_key(account_name, account, T::key())?;
+        if T::RESIZABLE {
+            assert_account_min_len(account_name, account, T::LEN)?;
+        } else {
+            assert_account_len(account_name, account, T::LEN)?;
+        }
+        let data = T::load(account, 0)?;
+        let bump = [data.pda_bump()];
+        let mut seeds = data.pda_seeds();
+        seeds.push(&bump);
+        assert_pda_with_bump(account_name, account, &crate::ID, &seeds)?;
+
+        Ok(Self {
+            info: account,
+            data,
+            bump,
+        })
+    }
+
+    /// Load and validate a program account that will be written to.
+    pub fn load_mut(
+        account_name: &str,
+        account: &'a AccountInfo<'b>,
+    ) -> Result<Self, ProgramError> {
+        assert_writable(account_name, account)?;
+        Self::load(account_name, account)
+    }
+
+    /// Get the underlying account info.
+    pub fn info(&self) -> &'a AccountInfo<'b> {
+        self.info
+    }
+
+    /// Get the bump of the account PDA.
+    pub fn bump(&self) -> u8 {
+        self.bump[0]
+    }
+
+    /// Get the seeds of the account PDA including the bump, for signing CPIs.
+    pub fn signer_seeds(&self) -> Vec<&[u8]> {
+        let mut seeds = self.data.pda_seeds();
+        seeds.push(&self.bump);
+        seeds
+    }
+
+    /// Save the account data back to the account it was loaded from.
+    pub fn save(&self) -> ProgramResult {
+        self.data.save(self.info, 0)
+    }
+}
+
+impl<T: PdaAccount> Deref for Checked<'_, '_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.data
+    }
+}
+
+impl<T: PdaAccount> DerefMut for Checked<'_, '_, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.data
+    }
+}
@@ -1,9 +1,17 @@
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, program::invoke_signed, pubkey::Pubkey,
-    rent::Rent, system_instruction, system_program, sysvar::Sysvar,
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    program::{invoke, invoke_signed},
+    pubkey::Pubkey,
+    rent::Rent,
+    system_instruction, system_program,
+    sysvar::Sysvar,
 };
 
 /// Create a new account from the given size.
+///
+/// Accounts already holding lamports cannot be created by the system program, so the
+/// missing rent is transferred to them before they are allocated and assigned instead.
 #[inline(always)]
 pub(crate) fn create_account<'a>(
     target_account: &AccountInfo<'a>,
@@ -15,24 +23,80 @@ pub(crate) fn create_account<'a>(
 ) -> ProgramResult {
     let rent = Rent::get()?;
     let lamports: u64 = rent.minimum_balance(size);
+    let signer_seeds = signer_seeds.unwrap_or(&[]);
+
+    if target_account.lamports() == 0 {
+        return invoke_signed(
+            &system_instruction::create_account(
+                funding_account.key,
+                target_account.key,
+                lamports,
+                size as u64,
+                owner,
+            ),
+            &[
+                funding_account.clone(),
+                target_account.clone(),
+                system_program.clone(),
+            ],
+            signer_seeds,
+        );
+    }
+
+    if lamports > target_account.lamports() {
+        invoke(
+            &system_instruction::transfer(
+                funding_account.key,
+                target_account.key,
+                lamports - target_account.lamports(),
+            ),
+            &[
+                funding_account.clone(),
+                target_account.clone(),
+                system_program.clone(),
+            ],
+        )?;
+    }
+
+    invoke_signed(
+        &system_instruction::allocate(target_account.key, size as u64),
+        &[target_account.clone(), system_program.clone()],
+        signer_seeds,
+    )?;
 
     invoke_signed(
-        &system_instruction::create_account(
-            funding_account.key,
-            target_account.key,
-            lamports,
-            size as u64,
-            owner,
-        ),
-        &[
-            funding_account.clone(),
-            target_account.clone(),
-            system_program.clone(),
-        ],
-        signer_seeds.unwrap_or(&[]),
+        &system_instruction::assign(target_account.key, owner),
+        &[target_account.clone(), system_program.clone()],
+        signer_seeds,
     )
 }
 
+/// Resize an account to the given size, topping up its rent from the funding account.
+///
+/// Rent freed by shrinking the account stays in it until it is closed.
+#[inline(always)]
+pub(crate) fn resize_account<'a>(
+    target_account: &AccountInfo<'a>,
+    funding_account: &AccountInfo<'a>,
+    size: usize,
+) -> ProgramResult {
+    let rent = Rent::get()?;
+    let lamports = rent.minimum_balance(size);
+
+    if lamports > target_account.lamports() {
+        invoke(
+            &system_instruction::transfer(
+                funding_account.key,
+                target_account.key,
+                lamports - target_account.lamports(),
+            ),
+            &[funding_account.clone(), target_account.clone()],
+        )?;
+    }
+
+    target_account.realloc(size, false)
+}
+
 /// Close an account.
 #[inline(always)]
 pub(crate) fn close_account<'a>(
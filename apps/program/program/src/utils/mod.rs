@@ -1,6 +1,12 @@
 mod account;
 pub(crate) use account::*;
 
+mod slot_hashes;
+pub(crate) use slot_hashes::*;
+
+mod token;
+pub(crate) use token::*;
+
 use solana_program::{
     account_info::AccountInfo,
     entrypoint::ProgramResult,
@@ -13,6 +19,19 @@ use num_traits::FromPrimitive;
 
 use crate::{error::FloorSwapError, state::Key};
 
+/// Basis points making up 100%.
+pub const MAX_BPS: u16 = 10_000;
+
+/// Compute the given basis points of an amount, rounding down.
+pub fn apply_bps(amount: u64, bps: u16) -> Result<u64, ProgramError> {
+    let value = (amount as u128)
+        .checked_mul(bps as u128)
+        .ok_or(FloorSwapError::NumericalOverflow)?
+        / MAX_BPS as u128;
+
+    u64::try_from(value).map_err(|_| FloorSwapError::NumericalOverflow.into())
+}
+
 /// Transfer lamports.
 #[inline(always)]
 pub fn transfer_lamports<'a>(
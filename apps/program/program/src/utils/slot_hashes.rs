@@ -0,0 +1,67 @@
+use solana_program::{account_info::AccountInfo, clock::Slot, program_error::ProgramError};
+
+use crate::error::FloorSwapError;
+
+/// Length of the entry count prefix of the SlotHashes sysvar data.
+const SLOT_HASHES_PREFIX_LEN: usize = 8;
+
+/// Length of a single (slot, hash) entry of the SlotHashes sysvar data.
+const SLOT_HASH_ENTRY_LEN: usize = 8 + 32;
+
+/// The outcome of looking up the first slot hash after a given slot.
+pub(crate) enum SlotHashLookup {
+    /// No slot after the given slot has been hashed yet.
+    Pending,
+    /// The hash of the earliest slot after the given slot.
+    Found([u8; 32]),
+    /// The given slot is older than every entry in the sysvar, so the
+    /// earliest hash after it can no longer be looked up.
+    Expired,
+}
+
+/// Find the hash of the earliest slot after `slot` in the SlotHashes sysvar.
+///
+/// The sysvar is too large to deserialize on chain, so entries are read in
+/// place. They are stored newest first.
+pub(crate) fn find_slot_hash_after(
+    slot_hashes: &AccountInfo,
+    slot: Slot,
+) -> Result<SlotHashLookup, ProgramError> {
+    let data = slot_hashes.try_borrow_data()?;
+
+    let count = data
+        .get(..SLOT_HASHES_PREFIX_LEN)
+        .and_then(|bytes| bytes.try_into().ok())
+        .map(u64::from_le_bytes)
+        .ok_or(FloorSwapError::DeserializationError)?;
+
+    let mut found = None;
+
+    for index in 0..count as usize {
+        let offset = SLOT_HASHES_PREFIX_LEN + index * SLOT_HASH_ENTRY_LEN;
+        let entry = data
+            .get(offset..offset + SLOT_HASH_ENTRY_LEN)
+            .ok_or(FloorSwapError::DeserializationError)?;
+
+        let mut entry_slot = [0u8; 8];
+        entry_slot.copy_from_slice(&entry[..8]);
+
+        if u64::from_le_bytes(entry_slot) <= slot {
+            // Every later entry is older still, the last one seen is the earliest after `slot`.
+            return Ok(match found {
+                Some(hash) => SlotHashLookup::Found(hash),
+                None => SlotHashLookup::Pending,
+            });
+        }
+
+        let mut hash = [0u8; 32];
+        hash.copy_from_slice(&entry[8..]);
+        found = Some(hash);
+    }
+
+    Ok(if found.is_some() {
+        SlotHashLookup::Expired
+    } else {
+        SlotHashLookup::Pending
+    })
+}
@@ -0,0 +1,122 @@
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    msg,
+    program::invoke,
+    program_error::ProgramError,
+    program_pack::{IsInitialized, Pack},
+    pubkey,
+    pubkey::Pubkey,
+};
+use spl_token::state::{Account as TokenAccount, Mint};
+
+use crate::error::FloorSwapError;
+
+/// The Token-2022 program, which shares its base instructions and account layouts with SPL Token.
+pub const TOKEN_2022_PROGRAM_ID: Pubkey = pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+
+/// Programs that fee mints can belong to.
+pub const TOKEN_PROGRAM_IDS: [Pubkey; 2] = [spl_token::ID, TOKEN_2022_PROGRAM_ID];
+
+/// Token-2022 stores the account type right after the base account length
+/// when an account has extensions.
+const ACCOUNT_TYPE_MINT: u8 = 1;
+const ACCOUNT_TYPE_ACCOUNT: u8 = 2;
+
+/// Check the account data holds the given account type, with or without Token-2022 extensions.
+fn has_account_type(data: &[u8], base_len: usize, account_type: u8) -> bool {
+    data.len() == base_len
+        || (data.len() > TokenAccount::LEN && data[TokenAccount::LEN] == account_type)
+}
+
+/// Unpack the base state of a mint, ignoring any Token-2022 extensions.
+pub fn unpack_mint(account_name: &str, account: &AccountInfo) -> Result<Mint, ProgramError> {
+    let data = account.data.borrow();
+
+    if !has_account_type(&data, Mint::LEN, ACCOUNT_TYPE_MINT) {
+        msg!(
+            "Account \"{}\" [{}] is not a mint",
+            account_name,
+            account.key
+        );
+        return Err(FloorSwapError::InvalidTokenAccount.into());
+    }
+
+    let mint = Mint::unpack_from_slice(&data[..Mint::LEN])?;
+    if !mint.is_initialized() {
+        msg!(
+            "Account \"{}\" [{}] is not initialized",
+            account_name,
+            account.key
+        );
+        return Err(FloorSwapError::InvalidTokenAccount.into());
+    }
+
+    Ok(mint)
+}
+
+/// Unpack the base state of a token account, ignoring any Token-2022 extensions.
+pub fn unpack_token_account(
+    account_name: &str,
+    account: &AccountInfo,
+) -> Result<TokenAccount, ProgramError> {
+    let data = account.data.borrow();
+
+    if !has_account_type(&data, TokenAccount::LEN, ACCOUNT_TYPE_ACCOUNT) {
+        msg!(
+            "Account \"{}\" [{}] is not a token account",
+            account_name,
+            account.key
+        );
+        return Err(FloorSwapError::InvalidTokenAccount.into());
+    }
+
+    let token_account = TokenAccount::unpack_from_slice(&data[..TokenAccount::LEN])?;
+    if !token_account.is_initialized() {
+        msg!(
+            "Account \"{}\" [{}] is not initialized",
+            account_name,
+            account.key
+        );
+        return Err(FloorSwapError::InvalidTokenAccount.into());
+    }
+
+    Ok(token_account)
+}
+
+/// Transfer tokens with `transfer_checked`, which both token programs support.
+///
+/// Mints with the Token-2022 transfer fee extension withhold their fee from
+/// the amount received by the destination.
+#[inline(always)]
+pub fn transfer_tokens_checked<'a>(
+    token_program: &AccountInfo<'a>,
+    source: &AccountInfo<'a>,
+    mint: &AccountInfo<'a>,
+    destination: &AccountInfo<'a>,
+    authority: &AccountInfo<'a>,
+    amount: u64,
+    decimals: u8,
+) -> ProgramResult {
+    let mut instruction = spl_token::instruction::transfer_checked(
+        &spl_token::ID,
+        source.key,
+        mint.key,
+        destination.key,
+        authority.key,
+        &[],
+        amount,
+        decimals,
+    )?;
+    instruction.program_id = *token_program.key;
+
+    invoke(
+        &instruction,
+        &[
+            source.clone(),
+            mint.clone(),
+            destination.clone(),
+            authority.clone(),
+        ],
+    )
+}
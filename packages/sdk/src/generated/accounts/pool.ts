@@ -15,28 +15,68 @@ import {
   fetchEncodedAccounts,
   getAddressDecoder,
   getAddressEncoder,
-  getBooleanDecoder,
-  getBooleanEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
   getU16Decoder,
   getU16Encoder,
+  getU32Decoder,
+  getU32Encoder,
   getU64Decoder,
   getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
   transformEncoder,
   type Account,
   type Address,
+  type Codec,
+  type Decoder,
   type EncodedAccount,
+  type Encoder,
   type FetchAccountConfig,
   type FetchAccountsConfig,
-  type FixedSizeCodec,
-  type FixedSizeDecoder,
-  type FixedSizeEncoder,
   type MaybeAccount,
   type MaybeEncodedAccount,
+  type Option,
+  type OptionOrNullable,
 } from '@solana/kit';
 import { findPoolPda, PoolSeeds } from '../pdas';
-import { getKeyDecoder, getKeyEncoder, Key } from '../types';
+import {
+  getFeeModelDecoder,
+  getFeeModelEncoder,
+  getFeeRecipientDecoder,
+  getFeeRecipientEncoder,
+  getKeyDecoder,
+  getKeyEncoder,
+  getMultisigDecoder,
+  getMultisigEncoder,
+  getPendingFeeDecoder,
+  getPendingFeeEncoder,
+  getPoolModeDecoder,
+  getPoolModeEncoder,
+  getPoolStatusDecoder,
+  getPoolStatusEncoder,
+  getRoyaltyBaseDecoder,
+  getRoyaltyBaseEncoder,
+  Key,
+  type FeeModel,
+  type FeeModelArgs,
+  type FeeRecipient,
+  type FeeRecipientArgs,
+  type Multisig,
+  type MultisigArgs,
+  type PendingFee,
+  type PendingFeeArgs,
+  type PoolMode,
+  type PoolModeArgs,
+  type PoolStatus,
+  type PoolStatusArgs,
+  type RoyaltyBase,
+  type RoyaltyBaseArgs,
+} from '../types';
 
 export const POOL_KEY = Key.Pool;
 
@@ -49,48 +89,128 @@ export type Pool = {
   collection: Address;
   authority: Address;
   treasury: Address;
-  feeAmount: bigint;
-  enabled: boolean;
+  status: PoolStatus;
   numAssets: number;
+  bump: number;
+  mode: PoolMode;
+  referencePrice: bigint;
+  royaltyBase: RoyaltyBase;
+  referralBps: number;
+  sequence: bigint;
+  maxFee: bigint;
+  feeDelay: number;
+  maxReferralBps: number;
+  creator: Address;
+  index: number;
+  openCommits: number;
+  commitCount: bigint;
+  feeModel: FeeModel;
+  feeMint: Option<Address>;
+  pendingFee: Option<PendingFee>;
+  pendingAuthority: Option<Address>;
+  operator: Option<Address>;
+  feeManager: Option<Address>;
+  multisig: Option<Multisig>;
+  feeSplit: Array<FeeRecipient>;
 };
 
 export type PoolArgs = {
   collection: Address;
   authority: Address;
   treasury: Address;
-  feeAmount: number | bigint;
-  enabled: boolean;
+  status: PoolStatusArgs;
   numAssets: number;
+  bump: number;
+  mode: PoolModeArgs;
+  referencePrice: number | bigint;
+  royaltyBase: RoyaltyBaseArgs;
+  referralBps: number;
+  sequence: number | bigint;
+  maxFee: number | bigint;
+  feeDelay: number;
+  maxReferralBps: number;
+  creator: Address;
+  index: number;
+  openCommits: number;
+  commitCount: number | bigint;
+  feeModel: FeeModelArgs;
+  feeMint: OptionOrNullable<Address>;
+  pendingFee: OptionOrNullable<PendingFeeArgs>;
+  pendingAuthority: OptionOrNullable<Address>;
+  operator: OptionOrNullable<Address>;
+  feeManager: OptionOrNullable<Address>;
+  multisig: OptionOrNullable<MultisigArgs>;
+  feeSplit: Array<FeeRecipientArgs>;
 };
 
-export function getPoolEncoder(): FixedSizeEncoder<PoolArgs> {
+export function getPoolEncoder(): Encoder<PoolArgs> {
   return transformEncoder(
     getStructEncoder([
       ['key', getKeyEncoder()],
       ['collection', getAddressEncoder()],
       ['authority', getAddressEncoder()],
       ['treasury', getAddressEncoder()],
-      ['feeAmount', getU64Encoder()],
-      ['enabled', getBooleanEncoder()],
+      ['status', getPoolStatusEncoder()],
       ['numAssets', getU16Encoder()],
+      ['bump', getU8Encoder()],
+      ['mode', getPoolModeEncoder()],
+      ['referencePrice', getU64Encoder()],
+      ['royaltyBase', getRoyaltyBaseEncoder()],
+      ['referralBps', getU16Encoder()],
+      ['sequence', getU64Encoder()],
+      ['maxFee', getU64Encoder()],
+      ['feeDelay', getU32Encoder()],
+      ['maxReferralBps', getU16Encoder()],
+      ['creator', getAddressEncoder()],
+      ['index', getU8Encoder()],
+      ['openCommits', getU16Encoder()],
+      ['commitCount', getU64Encoder()],
+      ['feeModel', getFeeModelEncoder()],
+      ['feeMint', getOptionEncoder(getAddressEncoder())],
+      ['pendingFee', getOptionEncoder(getPendingFeeEncoder())],
+      ['pendingAuthority', getOptionEncoder(getAddressEncoder())],
+      ['operator', getOptionEncoder(getAddressEncoder())],
+      ['feeManager', getOptionEncoder(getAddressEncoder())],
+      ['multisig', getOptionEncoder(getMultisigEncoder())],
+      ['feeSplit', getArrayEncoder(getFeeRecipientEncoder())],
     ]),
     (value) => ({ ...value, key: POOL_KEY })
   );
 }
 
-export function getPoolDecoder(): FixedSizeDecoder<Pool> {
+export function getPoolDecoder(): Decoder<Pool> {
   return getStructDecoder([
     ['key', getKeyDecoder()],
     ['collection', getAddressDecoder()],
     ['authority', getAddressDecoder()],
     ['treasury', getAddressDecoder()],
-    ['feeAmount', getU64Decoder()],
-    ['enabled', getBooleanDecoder()],
+    ['status', getPoolStatusDecoder()],
     ['numAssets', getU16Decoder()],
+    ['bump', getU8Decoder()],
+    ['mode', getPoolModeDecoder()],
+    ['referencePrice', getU64Decoder()],
+    ['royaltyBase', getRoyaltyBaseDecoder()],
+    ['referralBps', getU16Decoder()],
+    ['sequence', getU64Decoder()],
+    ['maxFee', getU64Decoder()],
+    ['feeDelay', getU32Decoder()],
+    ['maxReferralBps', getU16Decoder()],
+    ['creator', getAddressDecoder()],
+    ['index', getU8Decoder()],
+    ['openCommits', getU16Decoder()],
+    ['commitCount', getU64Decoder()],
+    ['feeModel', getFeeModelDecoder()],
+    ['feeMint', getOptionDecoder(getAddressDecoder())],
+    ['pendingFee', getOptionDecoder(getPendingFeeDecoder())],
+    ['pendingAuthority', getOptionDecoder(getAddressDecoder())],
+    ['operator', getOptionDecoder(getAddressDecoder())],
+    ['feeManager', getOptionDecoder(getAddressDecoder())],
+    ['multisig', getOptionDecoder(getMultisigDecoder())],
+    ['feeSplit', getArrayDecoder(getFeeRecipientDecoder())],
   ]);
 }
 
-export function getPoolCodec(): FixedSizeCodec<PoolArgs, Pool> {
+export function getPoolCodec(): Codec<PoolArgs, Pool> {
   return combineCodec(getPoolEncoder(), getPoolDecoder());
 }
 
@@ -148,7 +268,7 @@ export async function fetchAllMaybePool(
 }
 
 export function getPoolSize(): number {
-  return 108;
+  return 725;
 }
 
 export async function fetchPoolFromSeeds(
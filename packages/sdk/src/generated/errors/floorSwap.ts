@@ -44,50 +44,186 @@ export const FLOOR_SWAP_ERROR__EXPECTED_MPL_CORE_ASSET = 0xc; // 12
 export const FLOOR_SWAP_ERROR__INVALID_COLLECTION_FOR_ASSET = 0xd; // 13
 /** InvalidAssetOwner: Invalid owner for asset */
 export const FLOOR_SWAP_ERROR__INVALID_ASSET_OWNER = 0xe; // 14
-/** PoolInactive: Pool inactive */
+/** PoolInactive: The pool status does not allow this instruction */
 export const FLOOR_SWAP_ERROR__POOL_INACTIVE = 0xf; // 15
 /** PoolNotEmpty: Pool not empty */
 export const FLOOR_SWAP_ERROR__POOL_NOT_EMPTY = 0x10; // 16
+/** DuplicateAccount: Duplicate account */
+export const FLOOR_SWAP_ERROR__DUPLICATE_ACCOUNT = 0x11; // 17
+/** InvalidAccountLength: Invalid account length */
+export const FLOOR_SWAP_ERROR__INVALID_ACCOUNT_LENGTH = 0x12; // 18
+/** InvalidRemainingAccounts: Invalid remaining accounts */
+export const FLOOR_SWAP_ERROR__INVALID_REMAINING_ACCOUNTS = 0x13; // 19
+/** InvalidPoolMode: Invalid pool mode */
+export const FLOOR_SWAP_ERROR__INVALID_POOL_MODE = 0x14; // 20
+/** SwapNotRevealable: Swap not revealable yet */
+export const FLOOR_SWAP_ERROR__SWAP_NOT_REVEALABLE = 0x15; // 21
+/** SwapCommitExpired: Swap commit expired */
+export const FLOOR_SWAP_ERROR__SWAP_COMMIT_EXPIRED = 0x16; // 22
+/** SwapCommitNotExpired: Swap commit not expired */
+export const FLOOR_SWAP_ERROR__SWAP_COMMIT_NOT_EXPIRED = 0x17; // 23
+/** InvalidBasisPoints: Invalid basis points */
+export const FLOOR_SWAP_ERROR__INVALID_BASIS_POINTS = 0x18; // 24
+/** InvalidTokenAccount: Invalid token account */
+export const FLOOR_SWAP_ERROR__INVALID_TOKEN_ACCOUNT = 0x19; // 25
+/** MissingFeeTokenAccounts: Missing accounts to pay the fee in the pool fee mint */
+export const FLOOR_SWAP_ERROR__MISSING_FEE_TOKEN_ACCOUNTS = 0x1a; // 26
+/** InvalidFeeSplit: Fee split weights must sum to 10000 basis points */
+export const FLOOR_SWAP_ERROR__INVALID_FEE_SPLIT = 0x1b; // 27
+/** UnclaimedFees: The fee vault holds unclaimed fees */
+export const FLOOR_SWAP_ERROR__UNCLAIMED_FEES = 0x1c; // 28
+/** InvalidReferralFee: Referral fee exceeds the maximum share of the swap fee */
+export const FLOOR_SWAP_ERROR__INVALID_REFERRAL_FEE = 0x1d; // 29
+/** SlippageExceeded: The swap terms of the pool are worse than expected */
+export const FLOOR_SWAP_ERROR__SLIPPAGE_EXCEEDED = 0x1e; // 30
+/** FeeAboveMaximum: The swap fee exceeds the fee ceiling of the pool */
+export const FLOOR_SWAP_ERROR__FEE_ABOVE_MAXIMUM = 0x1f; // 31
+/** FeeIncreaseNotProposed: Fee increases must be proposed with ProposeFee */
+export const FLOOR_SWAP_ERROR__FEE_INCREASE_NOT_PROPOSED = 0x20; // 32
+/** PendingFeeNotEffective: The pending fee is not effective yet */
+export const FLOOR_SWAP_ERROR__PENDING_FEE_NOT_EFFECTIVE = 0x21; // 33
+/** NoPendingFee: The pool has no pending fee */
+export const FLOOR_SWAP_ERROR__NO_PENDING_FEE = 0x22; // 34
+/** InvalidStatusTransition: The pool cannot move to the requested status */
+export const FLOOR_SWAP_ERROR__INVALID_STATUS_TRANSITION = 0x23; // 35
+/** NoPendingAuthority: The pool has no pending authority */
+export const FLOOR_SWAP_ERROR__NO_PENDING_AUTHORITY = 0x24; // 36
+/** OperatorStatusNotAllowed: Operators can only pause and resume the pool */
+export const FLOOR_SWAP_ERROR__OPERATOR_STATUS_NOT_ALLOWED = 0x25; // 37
+/** InvalidMultisig: The multisig signers or threshold are invalid */
+export const FLOOR_SWAP_ERROR__INVALID_MULTISIG = 0x26; // 38
+/** MultisigThresholdNotMet: Not enough multisig signers approved the instruction */
+export const FLOOR_SWAP_ERROR__MULTISIG_THRESHOLD_NOT_MET = 0x27; // 39
+/** ProgramPaused: Swaps, deposits and withdrawals are paused program-wide */
+export const FLOOR_SWAP_ERROR__PROGRAM_PAUSED = 0x28; // 40
+/** InvalidUpgradeAuthority: The signer is not the upgrade authority of the program */
+export const FLOOR_SWAP_ERROR__INVALID_UPGRADE_AUTHORITY = 0x29; // 41
+/** TooManyGuardians: Too many guardians for the global config */
+export const FLOOR_SWAP_ERROR__TOO_MANY_GUARDIANS = 0x2a; // 42
+/** ProtocolFeeAboveMaximum: Protocol fee is above the hard-coded maximum */
+export const FLOOR_SWAP_ERROR__PROTOCOL_FEE_ABOVE_MAXIMUM = 0x2b; // 43
+/** MissingProtocolTreasury: Missing the protocol treasury accounts to pay the protocol fee */
+export const FLOOR_SWAP_ERROR__MISSING_PROTOCOL_TREASURY = 0x2c; // 44
+/** PoolAlreadyExists: A pool already exists for this creator, collection and index */
+export const FLOOR_SWAP_ERROR__POOL_ALREADY_EXISTS = 0x2d; // 45
+/** AssetNotInInventory: The asset is not in the inventory of the pool */
+export const FLOOR_SWAP_ERROR__ASSET_NOT_IN_INVENTORY = 0x2e; // 46
+/** OpenSwapCommits: The pool has mystery swaps waiting to be revealed or cancelled */
+export const FLOOR_SWAP_ERROR__OPEN_SWAP_COMMITS = 0x2f; // 47
+/** PoolHasNoAssets: The pool has no assets to swap for */
+export const FLOOR_SWAP_ERROR__POOL_HAS_NO_ASSETS = 0x30; // 48
+/** SwapCommitNotNext: Mystery swaps must be revealed or cancelled in the order they were committed */
+export const FLOOR_SWAP_ERROR__SWAP_COMMIT_NOT_NEXT = 0x31; // 49
+/** InvalidReferrer: The referrer cannot be the payer or an account of the pool */
+export const FLOOR_SWAP_ERROR__INVALID_REFERRER = 0x32; // 50
 
 export type FloorSwapError =
   | typeof FLOOR_SWAP_ERROR__ACCOUNT_MISMATCH
+  | typeof FLOOR_SWAP_ERROR__ASSET_NOT_IN_INVENTORY
   | typeof FLOOR_SWAP_ERROR__DESERIALIZATION_ERROR
+  | typeof FLOOR_SWAP_ERROR__DUPLICATE_ACCOUNT
   | typeof FLOOR_SWAP_ERROR__EXPECTED_EMPTY_ACCOUNT
   | typeof FLOOR_SWAP_ERROR__EXPECTED_MPL_CORE_ASSET
   | typeof FLOOR_SWAP_ERROR__EXPECTED_MPL_CORE_COLLECTION
   | typeof FLOOR_SWAP_ERROR__EXPECTED_NON_EMPTY_ACCOUNT
   | typeof FLOOR_SWAP_ERROR__EXPECTED_SIGNER_ACCOUNT
   | typeof FLOOR_SWAP_ERROR__EXPECTED_WRITABLE_ACCOUNT
+  | typeof FLOOR_SWAP_ERROR__FEE_ABOVE_MAXIMUM
+  | typeof FLOOR_SWAP_ERROR__FEE_INCREASE_NOT_PROPOSED
   | typeof FLOOR_SWAP_ERROR__INVALID_ACCOUNT_KEY
+  | typeof FLOOR_SWAP_ERROR__INVALID_ACCOUNT_LENGTH
   | typeof FLOOR_SWAP_ERROR__INVALID_ASSET_OWNER
+  | typeof FLOOR_SWAP_ERROR__INVALID_BASIS_POINTS
   | typeof FLOOR_SWAP_ERROR__INVALID_COLLECTION_FOR_ASSET
+  | typeof FLOOR_SWAP_ERROR__INVALID_FEE_SPLIT
+  | typeof FLOOR_SWAP_ERROR__INVALID_MULTISIG
   | typeof FLOOR_SWAP_ERROR__INVALID_PDA
+  | typeof FLOOR_SWAP_ERROR__INVALID_POOL_MODE
   | typeof FLOOR_SWAP_ERROR__INVALID_PROGRAM_OWNER
+  | typeof FLOOR_SWAP_ERROR__INVALID_REFERRAL_FEE
+  | typeof FLOOR_SWAP_ERROR__INVALID_REFERRER
+  | typeof FLOOR_SWAP_ERROR__INVALID_REMAINING_ACCOUNTS
+  | typeof FLOOR_SWAP_ERROR__INVALID_STATUS_TRANSITION
+  | typeof FLOOR_SWAP_ERROR__INVALID_TOKEN_ACCOUNT
+  | typeof FLOOR_SWAP_ERROR__INVALID_UPGRADE_AUTHORITY
+  | typeof FLOOR_SWAP_ERROR__MISSING_FEE_TOKEN_ACCOUNTS
+  | typeof FLOOR_SWAP_ERROR__MISSING_PROTOCOL_TREASURY
+  | typeof FLOOR_SWAP_ERROR__MULTISIG_THRESHOLD_NOT_MET
+  | typeof FLOOR_SWAP_ERROR__NO_PENDING_AUTHORITY
+  | typeof FLOOR_SWAP_ERROR__NO_PENDING_FEE
   | typeof FLOOR_SWAP_ERROR__NUMERICAL_OVERFLOW
+  | typeof FLOOR_SWAP_ERROR__OPEN_SWAP_COMMITS
+  | typeof FLOOR_SWAP_ERROR__OPERATOR_STATUS_NOT_ALLOWED
+  | typeof FLOOR_SWAP_ERROR__PENDING_FEE_NOT_EFFECTIVE
+  | typeof FLOOR_SWAP_ERROR__POOL_ALREADY_EXISTS
+  | typeof FLOOR_SWAP_ERROR__POOL_HAS_NO_ASSETS
   | typeof FLOOR_SWAP_ERROR__POOL_INACTIVE
   | typeof FLOOR_SWAP_ERROR__POOL_NOT_EMPTY
-  | typeof FLOOR_SWAP_ERROR__SERIALIZATION_ERROR;
+  | typeof FLOOR_SWAP_ERROR__PROGRAM_PAUSED
+  | typeof FLOOR_SWAP_ERROR__PROTOCOL_FEE_ABOVE_MAXIMUM
+  | typeof FLOOR_SWAP_ERROR__SERIALIZATION_ERROR
+  | typeof FLOOR_SWAP_ERROR__SLIPPAGE_EXCEEDED
+  | typeof FLOOR_SWAP_ERROR__SWAP_COMMIT_EXPIRED
+  | typeof FLOOR_SWAP_ERROR__SWAP_COMMIT_NOT_EXPIRED
+  | typeof FLOOR_SWAP_ERROR__SWAP_COMMIT_NOT_NEXT
+  | typeof FLOOR_SWAP_ERROR__SWAP_NOT_REVEALABLE
+  | typeof FLOOR_SWAP_ERROR__TOO_MANY_GUARDIANS
+  | typeof FLOOR_SWAP_ERROR__UNCLAIMED_FEES;
 
 let floorSwapErrorMessages: Record<FloorSwapError, string> | undefined;
 if (process.env.NODE_ENV !== 'production') {
   floorSwapErrorMessages = {
     [FLOOR_SWAP_ERROR__ACCOUNT_MISMATCH]: `Account mismatch`,
+    [FLOOR_SWAP_ERROR__ASSET_NOT_IN_INVENTORY]: `The asset is not in the inventory of the pool`,
     [FLOOR_SWAP_ERROR__DESERIALIZATION_ERROR]: `Error deserializing an account`,
+    [FLOOR_SWAP_ERROR__DUPLICATE_ACCOUNT]: `Duplicate account`,
     [FLOOR_SWAP_ERROR__EXPECTED_EMPTY_ACCOUNT]: `Expected empty account`,
     [FLOOR_SWAP_ERROR__EXPECTED_MPL_CORE_ASSET]: `Expected MPL Core Asset`,
     [FLOOR_SWAP_ERROR__EXPECTED_MPL_CORE_COLLECTION]: `Expected MPL Core Collection`,
     [FLOOR_SWAP_ERROR__EXPECTED_NON_EMPTY_ACCOUNT]: `Expected non empty account`,
     [FLOOR_SWAP_ERROR__EXPECTED_SIGNER_ACCOUNT]: `Expected signer account`,
     [FLOOR_SWAP_ERROR__EXPECTED_WRITABLE_ACCOUNT]: `Expected writable account`,
+    [FLOOR_SWAP_ERROR__FEE_ABOVE_MAXIMUM]: `The swap fee exceeds the fee ceiling of the pool`,
+    [FLOOR_SWAP_ERROR__FEE_INCREASE_NOT_PROPOSED]: `Fee increases must be proposed with ProposeFee`,
     [FLOOR_SWAP_ERROR__INVALID_ACCOUNT_KEY]: `Invalid account key`,
+    [FLOOR_SWAP_ERROR__INVALID_ACCOUNT_LENGTH]: `Invalid account length`,
     [FLOOR_SWAP_ERROR__INVALID_ASSET_OWNER]: `Invalid owner for asset`,
+    [FLOOR_SWAP_ERROR__INVALID_BASIS_POINTS]: `Invalid basis points`,
     [FLOOR_SWAP_ERROR__INVALID_COLLECTION_FOR_ASSET]: `Invalid collection for asset`,
+    [FLOOR_SWAP_ERROR__INVALID_FEE_SPLIT]: `Fee split weights must sum to 10000 basis points`,
+    [FLOOR_SWAP_ERROR__INVALID_MULTISIG]: `The multisig signers or threshold are invalid`,
     [FLOOR_SWAP_ERROR__INVALID_PDA]: `Invalid PDA derivation`,
+    [FLOOR_SWAP_ERROR__INVALID_POOL_MODE]: `Invalid pool mode`,
     [FLOOR_SWAP_ERROR__INVALID_PROGRAM_OWNER]: `Invalid program owner. This likely mean the provided account does not exist`,
+    [FLOOR_SWAP_ERROR__INVALID_REFERRAL_FEE]: `Referral fee exceeds the maximum share of the swap fee`,
+    [FLOOR_SWAP_ERROR__INVALID_REFERRER]: `The referrer cannot be the payer or an account of the pool`,
+    [FLOOR_SWAP_ERROR__INVALID_REMAINING_ACCOUNTS]: `Invalid remaining accounts`,
+    [FLOOR_SWAP_ERROR__INVALID_STATUS_TRANSITION]: `The pool cannot move to the requested status`,
+    [FLOOR_SWAP_ERROR__INVALID_TOKEN_ACCOUNT]: `Invalid token account`,
+    [FLOOR_SWAP_ERROR__INVALID_UPGRADE_AUTHORITY]: `The signer is not the upgrade authority of the program`,
+    [FLOOR_SWAP_ERROR__MISSING_FEE_TOKEN_ACCOUNTS]: `Missing accounts to pay the fee in the pool fee mint`,
+    [FLOOR_SWAP_ERROR__MISSING_PROTOCOL_TREASURY]: `Missing the protocol treasury accounts to pay the protocol fee`,
+    [FLOOR_SWAP_ERROR__MULTISIG_THRESHOLD_NOT_MET]: `Not enough multisig signers approved the instruction`,
+    [FLOOR_SWAP_ERROR__NO_PENDING_AUTHORITY]: `The pool has no pending authority`,
+    [FLOOR_SWAP_ERROR__NO_PENDING_FEE]: `The pool has no pending fee`,
     [FLOOR_SWAP_ERROR__NUMERICAL_OVERFLOW]: `Numerical overflow`,
-    [FLOOR_SWAP_ERROR__POOL_INACTIVE]: `Pool inactive`,
+    [FLOOR_SWAP_ERROR__OPEN_SWAP_COMMITS]: `The pool has mystery swaps waiting to be revealed or cancelled`,
+    [FLOOR_SWAP_ERROR__OPERATOR_STATUS_NOT_ALLOWED]: `Operators can only pause and resume the pool`,
+    [FLOOR_SWAP_ERROR__PENDING_FEE_NOT_EFFECTIVE]: `The pending fee is not effective yet`,
+    [FLOOR_SWAP_ERROR__POOL_ALREADY_EXISTS]: `A pool already exists for this creator, collection and index`,
+    [FLOOR_SWAP_ERROR__POOL_HAS_NO_ASSETS]: `The pool has no assets to swap for`,
+    [FLOOR_SWAP_ERROR__POOL_INACTIVE]: `The pool status does not allow this instruction`,
     [FLOOR_SWAP_ERROR__POOL_NOT_EMPTY]: `Pool not empty`,
+    [FLOOR_SWAP_ERROR__PROGRAM_PAUSED]: `Swaps, deposits and withdrawals are paused program-wide`,
+    [FLOOR_SWAP_ERROR__PROTOCOL_FEE_ABOVE_MAXIMUM]: `Protocol fee is above the hard-coded maximum`,
     [FLOOR_SWAP_ERROR__SERIALIZATION_ERROR]: `Error serializing an account`,
+    [FLOOR_SWAP_ERROR__SLIPPAGE_EXCEEDED]: `The swap terms of the pool are worse than expected`,
+    [FLOOR_SWAP_ERROR__SWAP_COMMIT_EXPIRED]: `Swap commit expired`,
+    [FLOOR_SWAP_ERROR__SWAP_COMMIT_NOT_EXPIRED]: `Swap commit not expired`,
+    [FLOOR_SWAP_ERROR__SWAP_COMMIT_NOT_NEXT]: `Mystery swaps must be revealed or cancelled in the order they were committed`,
+    [FLOOR_SWAP_ERROR__SWAP_NOT_REVEALABLE]: `Swap not revealable yet`,
+    [FLOOR_SWAP_ERROR__TOO_MANY_GUARDIANS]: `Too many guardians for the global config`,
+    [FLOOR_SWAP_ERROR__UNCLAIMED_FEES]: `The fee vault holds unclaimed fees`,
   };
 }
 
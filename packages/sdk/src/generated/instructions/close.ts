@@ -28,8 +28,13 @@ import {
   type TransactionSigner,
   type WritableAccount,
 } from '@solana/kit';
+import { findFeeVaultPda, findInventoryPda } from '../pdas';
 import { FLOOR_SWAP_PROGRAM_ADDRESS } from '../programs';
-import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+import {
+  expectAddress,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from '../shared';
 
 export const CLOSE_DISCRIMINATOR = 6;
 
@@ -40,10 +45,12 @@ export function getCloseDiscriminatorBytes() {
 export type CloseInstruction<
   TProgram extends string = typeof FLOOR_SWAP_PROGRAM_ADDRESS,
   TAccountPool extends string | AccountMeta<string> = string,
+  TAccountFeeVault extends string | AccountMeta<string> = string,
   TAccountAuthority extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
+  TAccountInventory extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -52,6 +59,9 @@ export type CloseInstruction<
       TAccountPool extends string
         ? WritableAccount<TAccountPool>
         : TAccountPool,
+      TAccountFeeVault extends string
+        ? WritableAccount<TAccountFeeVault>
+        : TAccountFeeVault,
       TAccountAuthority extends string
         ? ReadonlySignerAccount<TAccountAuthority> &
             AccountSignerMeta<TAccountAuthority>
@@ -59,6 +69,9 @@ export type CloseInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountInventory extends string
+        ? WritableAccount<TAccountInventory>
+        : TAccountInventory,
       ...TRemainingAccounts,
     ]
   >;
@@ -88,32 +101,146 @@ export function getCloseInstructionDataCodec(): FixedSizeCodec<
   );
 }
 
+export type CloseAsyncInput<
+  TAccountPool extends string = string,
+  TAccountFeeVault extends string = string,
+  TAccountAuthority extends string = string,
+  TAccountSystemProgram extends string = string,
+  TAccountInventory extends string = string,
+> = {
+  /** The PDA of the Pool account (seeds: ['floor_swap', creator, collection]) */
+  pool: Address<TAccountPool>;
+  /** The program derived address of the FeeVault account of the pool (seeds: ['fee_vault', pool]) */
+  feeVault?: Address<TAccountFeeVault>;
+  /** The authority of the pool */
+  authority: TransactionSigner<TAccountAuthority>;
+  /** The system program */
+  systemProgram?: Address<TAccountSystemProgram>;
+  /** The program derived address of the Inventory account of the pool (seeds: ['inventory', pool]) */
+  inventory?: Address<TAccountInventory>;
+};
+
+export async function getCloseInstructionAsync<
+  TAccountPool extends string,
+  TAccountFeeVault extends string,
+  TAccountAuthority extends string,
+  TAccountSystemProgram extends string,
+  TAccountInventory extends string,
+  TProgramAddress extends Address = typeof FLOOR_SWAP_PROGRAM_ADDRESS,
+>(
+  input: CloseAsyncInput<
+    TAccountPool,
+    TAccountFeeVault,
+    TAccountAuthority,
+    TAccountSystemProgram,
+    TAccountInventory
+  >,
+  config?: { programAddress?: TProgramAddress }
+): Promise<
+  CloseInstruction<
+    TProgramAddress,
+    TAccountPool,
+    TAccountFeeVault,
+    TAccountAuthority,
+    TAccountSystemProgram,
+    TAccountInventory
+  >
+> {
+  // Program address.
+  const programAddress = config?.programAddress ?? FLOOR_SWAP_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    pool: { value: input.pool ?? null, isWritable: true },
+    feeVault: { value: input.feeVault ?? null, isWritable: true },
+    authority: { value: input.authority ?? null, isWritable: false },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    inventory: { value: input.inventory ?? null, isWritable: true },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.feeVault.value) {
+    accounts.feeVault.value = await findFeeVaultPda({
+      pool: expectAddress(accounts.pool.value),
+    });
+  }
+  if (!accounts.inventory.value) {
+    accounts.inventory.value = await findInventoryPda({
+      pool: expectAddress(accounts.pool.value),
+    });
+  }
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  return Object.freeze({
+    accounts: [
+      getAccountMeta(accounts.pool),
+      getAccountMeta(accounts.feeVault),
+      getAccountMeta(accounts.authority),
+      getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.inventory),
+    ],
+    data: getCloseInstructionDataEncoder().encode({}),
+    programAddress,
+  } as CloseInstruction<
+    TProgramAddress,
+    TAccountPool,
+    TAccountFeeVault,
+    TAccountAuthority,
+    TAccountSystemProgram,
+    TAccountInventory
+  >);
+}
+
 export type CloseInput<
   TAccountPool extends string = string,
+  TAccountFeeVault extends string = string,
   TAccountAuthority extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountInventory extends string = string,
 > = {
-  /** The PDA of the Pool account (seeds: ['floor_swap', authority, collection]) */
+  /** The PDA of the Pool account (seeds: ['floor_swap', creator, collection]) */
   pool: Address<TAccountPool>;
+  /** The program derived address of the FeeVault account of the pool (seeds: ['fee_vault', pool]) */
+  feeVault: Address<TAccountFeeVault>;
   /** The authority of the pool */
   authority: TransactionSigner<TAccountAuthority>;
   /** The system program */
   systemProgram?: Address<TAccountSystemProgram>;
+  /** The program derived address of the Inventory account of the pool (seeds: ['inventory', pool]) */
+  inventory: Address<TAccountInventory>;
 };
 
 export function getCloseInstruction<
   TAccountPool extends string,
+  TAccountFeeVault extends string,
   TAccountAuthority extends string,
   TAccountSystemProgram extends string,
+  TAccountInventory extends string,
   TProgramAddress extends Address = typeof FLOOR_SWAP_PROGRAM_ADDRESS,
 >(
-  input: CloseInput<TAccountPool, TAccountAuthority, TAccountSystemProgram>,
+  input: CloseInput<
+    TAccountPool,
+    TAccountFeeVault,
+    TAccountAuthority,
+    TAccountSystemProgram,
+    TAccountInventory
+  >,
   config?: { programAddress?: TProgramAddress }
 ): CloseInstruction<
   TProgramAddress,
   TAccountPool,
+  TAccountFeeVault,
   TAccountAuthority,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountInventory
 > {
   // Program address.
   const programAddress = config?.programAddress ?? FLOOR_SWAP_PROGRAM_ADDRESS;
@@ -121,8 +248,10 @@ export function getCloseInstruction<
   // Original accounts.
   const originalAccounts = {
     pool: { value: input.pool ?? null, isWritable: true },
+    feeVault: { value: input.feeVault ?? null, isWritable: true },
     authority: { value: input.authority ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    inventory: { value: input.inventory ?? null, isWritable: true },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -139,16 +268,20 @@ export function getCloseInstruction<
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.pool),
+      getAccountMeta(accounts.feeVault),
       getAccountMeta(accounts.authority),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.inventory),
     ],
     data: getCloseInstructionDataEncoder().encode({}),
     programAddress,
   } as CloseInstruction<
     TProgramAddress,
     TAccountPool,
+    TAccountFeeVault,
     TAccountAuthority,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountInventory
   >);
 }
 
@@ -158,12 +291,16 @@ export type ParsedCloseInstruction<
 > = {
   programAddress: Address<TProgram>;
   accounts: {
-    /** The PDA of the Pool account (seeds: ['floor_swap', authority, collection]) */
+    /** The PDA of the Pool account (seeds: ['floor_swap', creator, collection]) */
     pool: TAccountMetas[0];
+    /** The program derived address of the FeeVault account of the pool (seeds: ['fee_vault', pool]) */
+    feeVault: TAccountMetas[1];
     /** The authority of the pool */
-    authority: TAccountMetas[1];
+    authority: TAccountMetas[2];
     /** The system program */
-    systemProgram: TAccountMetas[2];
+    systemProgram: TAccountMetas[3];
+    /** The program derived address of the Inventory account of the pool (seeds: ['inventory', pool]) */
+    inventory: TAccountMetas[4];
   };
   data: CloseInstructionData;
 };
@@ -176,7 +313,7 @@ export function parseCloseInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedCloseInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 3) {
+  if (instruction.accounts.length < 5) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -190,8 +327,10 @@ export function parseCloseInstruction<
     programAddress: instruction.programAddress,
     accounts: {
       pool: getNextAccount(),
+      feeVault: getNextAccount(),
       authority: getNextAccount(),
       systemProgram: getNextAccount(),
+      inventory: getNextAccount(),
     },
     data: getCloseInstructionDataDecoder().decode(instruction.data),
   };
@@ -9,8 +9,16 @@
 import {
   BASE_ACCOUNT_SIZE,
   combineCodec,
+  getAddressDecoder,
+  getAddressEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
+  getU32Decoder,
+  getU32Encoder,
   getU64Decoder,
   getU64Encoder,
   getU8Decoder,
@@ -19,12 +27,14 @@ import {
   type AccountMeta,
   type AccountSignerMeta,
   type Address,
-  type FixedSizeCodec,
-  type FixedSizeDecoder,
-  type FixedSizeEncoder,
+  type Codec,
+  type Decoder,
+  type Encoder,
   type Instruction,
   type InstructionWithAccounts,
   type InstructionWithData,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyAccount,
   type ReadonlySignerAccount,
   type ReadonlyUint8Array,
@@ -33,7 +43,7 @@ import {
   type WritableSignerAccount,
 } from '@solana/kit';
 import { getPoolSize } from '../accounts';
-import { findPoolPda } from '../pdas';
+import { findFeeVaultPda, findInventoryPda, findPoolPda } from '../pdas';
 import { FLOOR_SWAP_PROGRAM_ADDRESS } from '../programs';
 import {
   expectAddress,
@@ -42,6 +52,12 @@ import {
   type InstructionWithByteDelta,
   type ResolvedAccount,
 } from '../shared';
+import {
+  getFeeModelDecoder,
+  getFeeModelEncoder,
+  type FeeModel,
+  type FeeModelArgs,
+} from '../types';
 
 export const CREATE_DISCRIMINATOR = 0;
 
@@ -52,6 +68,7 @@ export function getCreateDiscriminatorBytes() {
 export type CreateInstruction<
   TProgram extends string = typeof FLOOR_SWAP_PROGRAM_ADDRESS,
   TAccountPool extends string | AccountMeta<string> = string,
+  TAccountFeeVault extends string | AccountMeta<string> = string,
   TAccountCollection extends string | AccountMeta<string> = string,
   TAccountAuthority extends string | AccountMeta<string> = string,
   TAccountTreasury extends string | AccountMeta<string> = string,
@@ -59,6 +76,7 @@ export type CreateInstruction<
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
+  TAccountInventory extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -67,6 +85,9 @@ export type CreateInstruction<
       TAccountPool extends string
         ? WritableAccount<TAccountPool>
         : TAccountPool,
+      TAccountFeeVault extends string
+        ? WritableAccount<TAccountFeeVault>
+        : TAccountFeeVault,
       TAccountCollection extends string
         ? ReadonlyAccount<TAccountCollection>
         : TAccountCollection,
@@ -84,35 +105,65 @@ export type CreateInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountInventory extends string
+        ? WritableAccount<TAccountInventory>
+        : TAccountInventory,
       ...TRemainingAccounts,
     ]
   >;
 
 export type CreateInstructionData = {
   discriminator: number;
-  feeAmount: bigint;
+  feeModel: FeeModel;
+  feeMint: Option<Address>;
+  maxFee: bigint;
+  feeDelay: number;
+  maxReferralBps: number;
+  index: number;
 };
 
-export type CreateInstructionDataArgs = { feeAmount: number | bigint };
+export type CreateInstructionDataArgs = {
+  feeModel: FeeModelArgs;
+  feeMint: OptionOrNullable<Address>;
+  maxFee: number | bigint;
+  feeDelay: number;
+  maxReferralBps?: number;
+  index?: number;
+};
 
-export function getCreateInstructionDataEncoder(): FixedSizeEncoder<CreateInstructionDataArgs> {
+export function getCreateInstructionDataEncoder(): Encoder<CreateInstructionDataArgs> {
   return transformEncoder(
     getStructEncoder([
       ['discriminator', getU8Encoder()],
-      ['feeAmount', getU64Encoder()],
+      ['feeModel', getFeeModelEncoder()],
+      ['feeMint', getOptionEncoder(getAddressEncoder())],
+      ['maxFee', getU64Encoder()],
+      ['feeDelay', getU32Encoder()],
+      ['maxReferralBps', getU16Encoder()],
+      ['index', getU8Encoder()],
     ]),
-    (value) => ({ ...value, discriminator: CREATE_DISCRIMINATOR })
+    (value) => ({
+      ...value,
+      discriminator: CREATE_DISCRIMINATOR,
+      index: value.index ?? 0,
+      maxReferralBps: value.maxReferralBps ?? 0,
+    })
   );
 }
 
-export function getCreateInstructionDataDecoder(): FixedSizeDecoder<CreateInstructionData> {
+export function getCreateInstructionDataDecoder(): Decoder<CreateInstructionData> {
   return getStructDecoder([
     ['discriminator', getU8Decoder()],
-    ['feeAmount', getU64Decoder()],
+    ['feeModel', getFeeModelDecoder()],
+    ['feeMint', getOptionDecoder(getAddressDecoder())],
+    ['maxFee', getU64Decoder()],
+    ['feeDelay', getU32Decoder()],
+    ['maxReferralBps', getU16Decoder()],
+    ['index', getU8Decoder()],
   ]);
 }
 
-export function getCreateInstructionDataCodec(): FixedSizeCodec<
+export function getCreateInstructionDataCodec(): Codec<
   CreateInstructionDataArgs,
   CreateInstructionData
 > {
@@ -124,54 +175,71 @@ export function getCreateInstructionDataCodec(): FixedSizeCodec<
 
 export type CreateAsyncInput<
   TAccountPool extends string = string,
+  TAccountFeeVault extends string = string,
   TAccountCollection extends string = string,
   TAccountAuthority extends string = string,
   TAccountTreasury extends string = string,
   TAccountPayer extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountInventory extends string = string,
 > = {
-  /** The program derived address of the Pool account to create (seeds: ['floor_swap', authority, collection]) */
+  /** The program derived address of the Pool account to create (seeds: ['floor_swap', creator, collection], plus the index when above 0) */
   pool?: Address<TAccountPool>;
+  /** The program derived address of the FeeVault account to create (seeds: ['fee_vault', pool]) */
+  feeVault?: Address<TAccountFeeVault>;
   /** The mpl-core collection */
   collection: Address<TAccountCollection>;
   /** The authority of the pool */
   authority: TransactionSigner<TAccountAuthority>;
-  /** The treasury where fees are sent */
+  /** The treasury where claimed fees are sent */
   treasury: Address<TAccountTreasury>;
   /** The account paying for the storage fees */
   payer?: TransactionSigner<TAccountPayer>;
   /** The system program */
   systemProgram?: Address<TAccountSystemProgram>;
-  feeAmount: CreateInstructionDataArgs['feeAmount'];
+  /** The program derived address of the Inventory account of the pool (seeds: ['inventory', pool]) */
+  inventory?: Address<TAccountInventory>;
+  feeModel: CreateInstructionDataArgs['feeModel'];
+  feeMint: CreateInstructionDataArgs['feeMint'];
+  maxFee: CreateInstructionDataArgs['maxFee'];
+  feeDelay: CreateInstructionDataArgs['feeDelay'];
+  maxReferralBps?: CreateInstructionDataArgs['maxReferralBps'];
+  index?: CreateInstructionDataArgs['index'];
 };
 
 export async function getCreateInstructionAsync<
   TAccountPool extends string,
+  TAccountFeeVault extends string,
   TAccountCollection extends string,
   TAccountAuthority extends string,
   TAccountTreasury extends string,
   TAccountPayer extends string,
   TAccountSystemProgram extends string,
+  TAccountInventory extends string,
   TProgramAddress extends Address = typeof FLOOR_SWAP_PROGRAM_ADDRESS,
 >(
   input: CreateAsyncInput<
     TAccountPool,
+    TAccountFeeVault,
     TAccountCollection,
     TAccountAuthority,
     TAccountTreasury,
     TAccountPayer,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountInventory
   >,
   config?: { programAddress?: TProgramAddress }
 ): Promise<
   CreateInstruction<
     TProgramAddress,
     TAccountPool,
+    TAccountFeeVault,
     TAccountCollection,
     TAccountAuthority,
     TAccountTreasury,
     TAccountPayer,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountInventory
   > &
     InstructionWithByteDelta
 > {
@@ -181,11 +249,13 @@ export async function getCreateInstructionAsync<
   // Original accounts.
   const originalAccounts = {
     pool: { value: input.pool ?? null, isWritable: true },
+    feeVault: { value: input.feeVault ?? null, isWritable: true },
     collection: { value: input.collection ?? null, isWritable: false },
     authority: { value: input.authority ?? null, isWritable: false },
     treasury: { value: input.treasury ?? null, isWritable: false },
     payer: { value: input.payer ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    inventory: { value: input.inventory ?? null, isWritable: true },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -198,10 +268,20 @@ export async function getCreateInstructionAsync<
   // Resolve default values.
   if (!accounts.pool.value) {
     accounts.pool.value = await findPoolPda({
-      authority: expectAddress(accounts.authority.value),
+      creator: expectAddress(accounts.authority.value),
       collection: expectAddress(accounts.collection.value),
     });
   }
+  if (!accounts.feeVault.value) {
+    accounts.feeVault.value = await findFeeVaultPda({
+      pool: expectAddress(accounts.pool.value),
+    });
+  }
+  if (!accounts.inventory.value) {
+    accounts.inventory.value = await findInventoryPda({
+      pool: expectAddress(accounts.pool.value),
+    });
+  }
   if (!accounts.payer.value) {
     accounts.payer.value = expectSome(accounts.authority.value);
   }
@@ -220,11 +300,13 @@ export async function getCreateInstructionAsync<
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.pool),
+      getAccountMeta(accounts.feeVault),
       getAccountMeta(accounts.collection),
       getAccountMeta(accounts.authority),
       getAccountMeta(accounts.treasury),
       getAccountMeta(accounts.payer),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.inventory),
     ],
     byteDelta,
     data: getCreateInstructionDataEncoder().encode(
@@ -234,64 +316,83 @@ export async function getCreateInstructionAsync<
   } as CreateInstruction<
     TProgramAddress,
     TAccountPool,
+    TAccountFeeVault,
     TAccountCollection,
     TAccountAuthority,
     TAccountTreasury,
     TAccountPayer,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountInventory
   > &
     InstructionWithByteDelta);
 }
 
 export type CreateInput<
   TAccountPool extends string = string,
+  TAccountFeeVault extends string = string,
   TAccountCollection extends string = string,
   TAccountAuthority extends string = string,
   TAccountTreasury extends string = string,
   TAccountPayer extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountInventory extends string = string,
 > = {
-  /** The program derived address of the Pool account to create (seeds: ['floor_swap', authority, collection]) */
+  /** The program derived address of the Pool account to create (seeds: ['floor_swap', creator, collection], plus the index when above 0) */
   pool: Address<TAccountPool>;
+  /** The program derived address of the FeeVault account to create (seeds: ['fee_vault', pool]) */
+  feeVault: Address<TAccountFeeVault>;
   /** The mpl-core collection */
   collection: Address<TAccountCollection>;
   /** The authority of the pool */
   authority: TransactionSigner<TAccountAuthority>;
-  /** The treasury where fees are sent */
+  /** The treasury where claimed fees are sent */
   treasury: Address<TAccountTreasury>;
   /** The account paying for the storage fees */
   payer?: TransactionSigner<TAccountPayer>;
   /** The system program */
   systemProgram?: Address<TAccountSystemProgram>;
-  feeAmount: CreateInstructionDataArgs['feeAmount'];
+  /** The program derived address of the Inventory account of the pool (seeds: ['inventory', pool]) */
+  inventory: Address<TAccountInventory>;
+  feeModel: CreateInstructionDataArgs['feeModel'];
+  feeMint: CreateInstructionDataArgs['feeMint'];
+  maxFee: CreateInstructionDataArgs['maxFee'];
+  feeDelay: CreateInstructionDataArgs['feeDelay'];
+  maxReferralBps?: CreateInstructionDataArgs['maxReferralBps'];
+  index?: CreateInstructionDataArgs['index'];
 };
 
 export function getCreateInstruction<
   TAccountPool extends string,
+  TAccountFeeVault extends string,
   TAccountCollection extends string,
   TAccountAuthority extends string,
   TAccountTreasury extends string,
   TAccountPayer extends string,
   TAccountSystemProgram extends string,
+  TAccountInventory extends string,
   TProgramAddress extends Address = typeof FLOOR_SWAP_PROGRAM_ADDRESS,
 >(
   input: CreateInput<
     TAccountPool,
+    TAccountFeeVault,
     TAccountCollection,
     TAccountAuthority,
     TAccountTreasury,
     TAccountPayer,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountInventory
   >,
   config?: { programAddress?: TProgramAddress }
 ): CreateInstruction<
   TProgramAddress,
   TAccountPool,
+  TAccountFeeVault,
   TAccountCollection,
   TAccountAuthority,
   TAccountTreasury,
   TAccountPayer,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountInventory
 > &
   InstructionWithByteDelta {
   // Program address.
@@ -300,11 +401,13 @@ export function getCreateInstruction<
   // Original accounts.
   const originalAccounts = {
     pool: { value: input.pool ?? null, isWritable: true },
+    feeVault: { value: input.feeVault ?? null, isWritable: true },
     collection: { value: input.collection ?? null, isWritable: false },
     authority: { value: input.authority ?? null, isWritable: false },
     treasury: { value: input.treasury ?? null, isWritable: false },
     payer: { value: input.payer ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    inventory: { value: input.inventory ?? null, isWritable: true },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -333,11 +436,13 @@ export function getCreateInstruction<
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.pool),
+      getAccountMeta(accounts.feeVault),
       getAccountMeta(accounts.collection),
       getAccountMeta(accounts.authority),
       getAccountMeta(accounts.treasury),
       getAccountMeta(accounts.payer),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.inventory),
     ],
     byteDelta,
     data: getCreateInstructionDataEncoder().encode(
@@ -347,11 +452,13 @@ export function getCreateInstruction<
   } as CreateInstruction<
     TProgramAddress,
     TAccountPool,
+    TAccountFeeVault,
     TAccountCollection,
     TAccountAuthority,
     TAccountTreasury,
     TAccountPayer,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountInventory
   > &
     InstructionWithByteDelta);
 }
@@ -362,18 +469,22 @@ export type ParsedCreateInstruction<
 > = {
   programAddress: Address<TProgram>;
   accounts: {
-    /** The program derived address of the Pool account to create (seeds: ['floor_swap', authority, collection]) */
+    /** The program derived address of the Pool account to create (seeds: ['floor_swap', creator, collection], plus the index when above 0) */
     pool: TAccountMetas[0];
+    /** The program derived address of the FeeVault account to create (seeds: ['fee_vault', pool]) */
+    feeVault: TAccountMetas[1];
     /** The mpl-core collection */
-    collection: TAccountMetas[1];
+    collection: TAccountMetas[2];
     /** The authority of the pool */
-    authority: TAccountMetas[2];
-    /** The treasury where fees are sent */
-    treasury: TAccountMetas[3];
+    authority: TAccountMetas[3];
+    /** The treasury where claimed fees are sent */
+    treasury: TAccountMetas[4];
     /** The account paying for the storage fees */
-    payer: TAccountMetas[4];
+    payer: TAccountMetas[5];
     /** The system program */
-    systemProgram: TAccountMetas[5];
+    systemProgram: TAccountMetas[6];
+    /** The program derived address of the Inventory account of the pool (seeds: ['inventory', pool]) */
+    inventory: TAccountMetas[7];
   };
   data: CreateInstructionData;
 };
@@ -386,7 +497,7 @@ export function parseCreateInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedCreateInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 6) {
+  if (instruction.accounts.length < 8) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -400,11 +511,13 @@ export function parseCreateInstruction<
     programAddress: instruction.programAddress,
     accounts: {
       pool: getNextAccount(),
+      feeVault: getNextAccount(),
       collection: getNextAccount(),
       authority: getNextAccount(),
       treasury: getNextAccount(),
       payer: getNextAccount(),
       systemProgram: getNextAccount(),
+      inventory: getNextAccount(),
     },
     data: getCreateInstructionDataDecoder().decode(instruction.data),
   };
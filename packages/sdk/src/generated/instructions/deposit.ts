@@ -28,8 +28,13 @@ import {
   type TransactionSigner,
   type WritableAccount,
 } from '@solana/kit';
+import { findInventoryPda } from '../pdas';
 import { FLOOR_SWAP_PROGRAM_ADDRESS } from '../programs';
-import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+import {
+  expectAddress,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from '../shared';
 
 export const DEPOSIT_DISCRIMINATOR = 4;
 
@@ -44,6 +49,13 @@ export type DepositInstruction<
   TAccountCollection extends string | AccountMeta<string> = string,
   TAccountPayer extends string | AccountMeta<string> = string,
   TAccountCoreProgram extends string | AccountMeta<string> = string,
+  TAccountGlobalConfig extends
+    | string
+    | AccountMeta<string> = 'Eh9HhTbDPkxqTSsJqMhygBny7GjsrVyVmEAZvWYzBsy1',
+  TAccountInventory extends string | AccountMeta<string> = string,
+  TAccountSystemProgram extends
+    | string
+    | AccountMeta<string> = '11111111111111111111111111111111',
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -65,6 +77,15 @@ export type DepositInstruction<
       TAccountCoreProgram extends string
         ? ReadonlyAccount<TAccountCoreProgram>
         : TAccountCoreProgram,
+      TAccountGlobalConfig extends string
+        ? ReadonlyAccount<TAccountGlobalConfig>
+        : TAccountGlobalConfig,
+      TAccountInventory extends string
+        ? WritableAccount<TAccountInventory>
+        : TAccountInventory,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -94,14 +115,141 @@ export function getDepositInstructionDataCodec(): FixedSizeCodec<
   );
 }
 
+export type DepositAsyncInput<
+  TAccountPool extends string = string,
+  TAccountAsset extends string = string,
+  TAccountCollection extends string = string,
+  TAccountPayer extends string = string,
+  TAccountCoreProgram extends string = string,
+  TAccountGlobalConfig extends string = string,
+  TAccountInventory extends string = string,
+  TAccountSystemProgram extends string = string,
+> = {
+  /** The PDA of the Pool account (seeds: ['floor_swap', creator, collection]) */
+  pool: Address<TAccountPool>;
+  /** The mpl-core asset to deposit */
+  asset: Address<TAccountAsset>;
+  /** The collection of the asset */
+  collection: Address<TAccountCollection>;
+  /** The user depositing the asset */
+  payer: TransactionSigner<TAccountPayer>;
+  /** The MPL Core program */
+  coreProgram: Address<TAccountCoreProgram>;
+  /** The program derived address of the GlobalConfig account (seeds: ['global_config']) */
+  globalConfig?: Address<TAccountGlobalConfig>;
+  /** The program derived address of the Inventory account of the pool (seeds: ['inventory', pool]) */
+  inventory?: Address<TAccountInventory>;
+  /** The system program */
+  systemProgram?: Address<TAccountSystemProgram>;
+};
+
+export async function getDepositInstructionAsync<
+  TAccountPool extends string,
+  TAccountAsset extends string,
+  TAccountCollection extends string,
+  TAccountPayer extends string,
+  TAccountCoreProgram extends string,
+  TAccountGlobalConfig extends string,
+  TAccountInventory extends string,
+  TAccountSystemProgram extends string,
+  TProgramAddress extends Address = typeof FLOOR_SWAP_PROGRAM_ADDRESS,
+>(
+  input: DepositAsyncInput<
+    TAccountPool,
+    TAccountAsset,
+    TAccountCollection,
+    TAccountPayer,
+    TAccountCoreProgram,
+    TAccountGlobalConfig,
+    TAccountInventory,
+    TAccountSystemProgram
+  >,
+  config?: { programAddress?: TProgramAddress }
+): Promise<
+  DepositInstruction<
+    TProgramAddress,
+    TAccountPool,
+    TAccountAsset,
+    TAccountCollection,
+    TAccountPayer,
+    TAccountCoreProgram,
+    TAccountGlobalConfig,
+    TAccountInventory,
+    TAccountSystemProgram
+  >
+> {
+  // Program address.
+  const programAddress = config?.programAddress ?? FLOOR_SWAP_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    pool: { value: input.pool ?? null, isWritable: true },
+    asset: { value: input.asset ?? null, isWritable: true },
+    collection: { value: input.collection ?? null, isWritable: false },
+    payer: { value: input.payer ?? null, isWritable: false },
+    coreProgram: { value: input.coreProgram ?? null, isWritable: false },
+    globalConfig: { value: input.globalConfig ?? null, isWritable: false },
+    inventory: { value: input.inventory ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.inventory.value) {
+    accounts.inventory.value = await findInventoryPda({
+      pool: expectAddress(accounts.pool.value),
+    });
+  }
+  if (!accounts.globalConfig.value) {
+    accounts.globalConfig.value =
+      'Eh9HhTbDPkxqTSsJqMhygBny7GjsrVyVmEAZvWYzBsy1' as Address<'Eh9HhTbDPkxqTSsJqMhygBny7GjsrVyVmEAZvWYzBsy1'>;
+  }
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  return Object.freeze({
+    accounts: [
+      getAccountMeta(accounts.pool),
+      getAccountMeta(accounts.asset),
+      getAccountMeta(accounts.collection),
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.coreProgram),
+      getAccountMeta(accounts.globalConfig),
+      getAccountMeta(accounts.inventory),
+      getAccountMeta(accounts.systemProgram),
+    ],
+    data: getDepositInstructionDataEncoder().encode({}),
+    programAddress,
+  } as DepositInstruction<
+    TProgramAddress,
+    TAccountPool,
+    TAccountAsset,
+    TAccountCollection,
+    TAccountPayer,
+    TAccountCoreProgram,
+    TAccountGlobalConfig,
+    TAccountInventory,
+    TAccountSystemProgram
+  >);
+}
+
 export type DepositInput<
   TAccountPool extends string = string,
   TAccountAsset extends string = string,
   TAccountCollection extends string = string,
   TAccountPayer extends string = string,
   TAccountCoreProgram extends string = string,
+  TAccountGlobalConfig extends string = string,
+  TAccountInventory extends string = string,
+  TAccountSystemProgram extends string = string,
 > = {
-  /** The PDA of the Pool account (seeds: ['floor_swap', authority, collection]) */
+  /** The PDA of the Pool account (seeds: ['floor_swap', creator, collection]) */
   pool: Address<TAccountPool>;
   /** The mpl-core asset to deposit */
   asset: Address<TAccountAsset>;
@@ -111,6 +259,12 @@ export type DepositInput<
   payer: TransactionSigner<TAccountPayer>;
   /** The MPL Core program */
   coreProgram: Address<TAccountCoreProgram>;
+  /** The program derived address of the GlobalConfig account (seeds: ['global_config']) */
+  globalConfig?: Address<TAccountGlobalConfig>;
+  /** The program derived address of the Inventory account of the pool (seeds: ['inventory', pool]) */
+  inventory: Address<TAccountInventory>;
+  /** The system program */
+  systemProgram?: Address<TAccountSystemProgram>;
 };
 
 export function getDepositInstruction<
@@ -119,6 +273,9 @@ export function getDepositInstruction<
   TAccountCollection extends string,
   TAccountPayer extends string,
   TAccountCoreProgram extends string,
+  TAccountGlobalConfig extends string,
+  TAccountInventory extends string,
+  TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof FLOOR_SWAP_PROGRAM_ADDRESS,
 >(
   input: DepositInput<
@@ -126,7 +283,10 @@ export function getDepositInstruction<
     TAccountAsset,
     TAccountCollection,
     TAccountPayer,
-    TAccountCoreProgram
+    TAccountCoreProgram,
+    TAccountGlobalConfig,
+    TAccountInventory,
+    TAccountSystemProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): DepositInstruction<
@@ -135,7 +295,10 @@ export function getDepositInstruction<
   TAccountAsset,
   TAccountCollection,
   TAccountPayer,
-  TAccountCoreProgram
+  TAccountCoreProgram,
+  TAccountGlobalConfig,
+  TAccountInventory,
+  TAccountSystemProgram
 > {
   // Program address.
   const programAddress = config?.programAddress ?? FLOOR_SWAP_PROGRAM_ADDRESS;
@@ -147,12 +310,25 @@ export function getDepositInstruction<
     collection: { value: input.collection ?? null, isWritable: false },
     payer: { value: input.payer ?? null, isWritable: false },
     coreProgram: { value: input.coreProgram ?? null, isWritable: false },
+    globalConfig: { value: input.globalConfig ?? null, isWritable: false },
+    inventory: { value: input.inventory ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
     ResolvedAccount
   >;
 
+  // Resolve default values.
+  if (!accounts.globalConfig.value) {
+    accounts.globalConfig.value =
+      'Eh9HhTbDPkxqTSsJqMhygBny7GjsrVyVmEAZvWYzBsy1' as Address<'Eh9HhTbDPkxqTSsJqMhygBny7GjsrVyVmEAZvWYzBsy1'>;
+  }
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
     accounts: [
@@ -161,6 +337,9 @@ export function getDepositInstruction<
       getAccountMeta(accounts.collection),
       getAccountMeta(accounts.payer),
       getAccountMeta(accounts.coreProgram),
+      getAccountMeta(accounts.globalConfig),
+      getAccountMeta(accounts.inventory),
+      getAccountMeta(accounts.systemProgram),
     ],
     data: getDepositInstructionDataEncoder().encode({}),
     programAddress,
@@ -170,7 +349,10 @@ export function getDepositInstruction<
     TAccountAsset,
     TAccountCollection,
     TAccountPayer,
-    TAccountCoreProgram
+    TAccountCoreProgram,
+    TAccountGlobalConfig,
+    TAccountInventory,
+    TAccountSystemProgram
   >);
 }
 
@@ -180,7 +362,7 @@ export type ParsedDepositInstruction<
 > = {
   programAddress: Address<TProgram>;
   accounts: {
-    /** The PDA of the Pool account (seeds: ['floor_swap', authority, collection]) */
+    /** The PDA of the Pool account (seeds: ['floor_swap', creator, collection]) */
     pool: TAccountMetas[0];
     /** The mpl-core asset to deposit */
     asset: TAccountMetas[1];
@@ -190,6 +372,12 @@ export type ParsedDepositInstruction<
     payer: TAccountMetas[3];
     /** The MPL Core program */
     coreProgram: TAccountMetas[4];
+    /** The program derived address of the GlobalConfig account (seeds: ['global_config']) */
+    globalConfig: TAccountMetas[5];
+    /** The program derived address of the Inventory account of the pool (seeds: ['inventory', pool]) */
+    inventory: TAccountMetas[6];
+    /** The system program */
+    systemProgram: TAccountMetas[7];
   };
   data: DepositInstructionData;
 };
@@ -202,7 +390,7 @@ export function parseDepositInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedDepositInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 5) {
+  if (instruction.accounts.length < 8) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -220,6 +408,9 @@ export function parseDepositInstruction<
       collection: getNextAccount(),
       payer: getNextAccount(),
       coreProgram: getNextAccount(),
+      globalConfig: getNextAccount(),
+      inventory: getNextAccount(),
+      systemProgram: getNextAccount(),
     },
     data: getDepositInstructionDataDecoder().decode(instruction.data),
   };
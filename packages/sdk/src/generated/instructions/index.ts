@@ -6,10 +6,35 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './acceptAuthority';
+export * from './applyFee';
+export * from './cancelSwap';
+export * from './claimFees';
 export * from './close';
+export * from './closeAndDrain';
+export * from './commitSwap';
 export * from './create';
 export * from './deposit';
-export * from './setActive';
+export * from './depositMany';
+export * from './initGlobalConfig';
+export * from './migrate';
+export * from './pauseProgram';
+export * from './proposeAuthority';
+export * from './proposeFee';
+export * from './reconcile';
+export * from './revealSwap';
 export * from './setFee';
+export * from './setFeeSplit';
+export * from './setMode';
+export * from './setMultisig';
+export * from './setReferencePrice';
+export * from './setReferralFee';
+export * from './setRoles';
+export * from './setRoyaltyBase';
+export * from './setStatus';
 export * from './swap';
+export * from './swapBatch';
+export * from './updateGlobalConfig';
+export * from './updatePool';
 export * from './withdraw';
+export * from './withdrawMany';
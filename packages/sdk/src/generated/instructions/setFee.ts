@@ -8,22 +8,26 @@
 
 import {
   combineCodec,
+  getAddressDecoder,
+  getAddressEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
-  getU64Decoder,
-  getU64Encoder,
   getU8Decoder,
   getU8Encoder,
   transformEncoder,
   type AccountMeta,
   type AccountSignerMeta,
   type Address,
-  type FixedSizeCodec,
-  type FixedSizeDecoder,
-  type FixedSizeEncoder,
+  type Codec,
+  type Decoder,
+  type Encoder,
   type Instruction,
   type InstructionWithAccounts,
   type InstructionWithData,
+  type Option,
+  type OptionOrNullable,
   type ReadonlySignerAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
@@ -31,6 +35,12 @@ import {
 } from '@solana/kit';
 import { FLOOR_SWAP_PROGRAM_ADDRESS } from '../programs';
 import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+import {
+  getFeeModelDecoder,
+  getFeeModelEncoder,
+  type FeeModel,
+  type FeeModelArgs,
+} from '../types';
 
 export const SET_FEE_DISCRIMINATOR = 2;
 
@@ -60,29 +70,35 @@ export type SetFeeInstruction<
 
 export type SetFeeInstructionData = {
   discriminator: number;
-  feeAmount: bigint;
+  feeModel: FeeModel;
+  feeMint: Option<Address>;
 };
 
-export type SetFeeInstructionDataArgs = { feeAmount: number | bigint };
+export type SetFeeInstructionDataArgs = {
+  feeModel: FeeModelArgs;
+  feeMint: OptionOrNullable<Address>;
+};
 
-export function getSetFeeInstructionDataEncoder(): FixedSizeEncoder<SetFeeInstructionDataArgs> {
+export function getSetFeeInstructionDataEncoder(): Encoder<SetFeeInstructionDataArgs> {
   return transformEncoder(
     getStructEncoder([
       ['discriminator', getU8Encoder()],
-      ['feeAmount', getU64Encoder()],
+      ['feeModel', getFeeModelEncoder()],
+      ['feeMint', getOptionEncoder(getAddressEncoder())],
     ]),
     (value) => ({ ...value, discriminator: SET_FEE_DISCRIMINATOR })
   );
 }
 
-export function getSetFeeInstructionDataDecoder(): FixedSizeDecoder<SetFeeInstructionData> {
+export function getSetFeeInstructionDataDecoder(): Decoder<SetFeeInstructionData> {
   return getStructDecoder([
     ['discriminator', getU8Decoder()],
-    ['feeAmount', getU64Decoder()],
+    ['feeModel', getFeeModelDecoder()],
+    ['feeMint', getOptionDecoder(getAddressDecoder())],
   ]);
 }
 
-export function getSetFeeInstructionDataCodec(): FixedSizeCodec<
+export function getSetFeeInstructionDataCodec(): Codec<
   SetFeeInstructionDataArgs,
   SetFeeInstructionData
 > {
@@ -96,11 +112,12 @@ export type SetFeeInput<
   TAccountPool extends string = string,
   TAccountAuthority extends string = string,
 > = {
-  /** The program derived address of the Pool account (seeds: ['floor_swap', authority, collection]) */
+  /** The program derived address of the Pool account (seeds: ['floor_swap', creator, collection]) */
   pool: Address<TAccountPool>;
-  /** The authority of the app */
+  /** The authority or the fee manager of the pool */
   authority: TransactionSigner<TAccountAuthority>;
-  feeAmount: SetFeeInstructionDataArgs['feeAmount'];
+  feeModel: SetFeeInstructionDataArgs['feeModel'];
+  feeMint: SetFeeInstructionDataArgs['feeMint'];
 };
 
 export function getSetFeeInstruction<
@@ -146,9 +163,9 @@ export type ParsedSetFeeInstruction<
 > = {
   programAddress: Address<TProgram>;
   accounts: {
-    /** The program derived address of the Pool account (seeds: ['floor_swap', authority, collection]) */
+    /** The program derived address of the Pool account (seeds: ['floor_swap', creator, collection]) */
     pool: TAccountMetas[0];
-    /** The authority of the app */
+    /** The authority or the fee manager of the pool */
     authority: TAccountMetas[1];
   };
   data: SetFeeInstructionData;
@@ -8,8 +8,6 @@
 
 import {
   combineCodec,
-  getBooleanDecoder,
-  getBooleanEncoder,
   getStructDecoder,
   getStructEncoder,
   getU8Decoder,
@@ -31,14 +29,20 @@ import {
 } from '@solana/kit';
 import { FLOOR_SWAP_PROGRAM_ADDRESS } from '../programs';
 import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+import {
+  getPoolStatusDecoder,
+  getPoolStatusEncoder,
+  type PoolStatus,
+  type PoolStatusArgs,
+} from '../types';
 
-export const SET_ACTIVE_DISCRIMINATOR = 1;
+export const SET_STATUS_DISCRIMINATOR = 1;
 
-export function getSetActiveDiscriminatorBytes() {
-  return getU8Encoder().encode(SET_ACTIVE_DISCRIMINATOR);
+export function getSetStatusDiscriminatorBytes() {
+  return getU8Encoder().encode(SET_STATUS_DISCRIMINATOR);
 }
 
-export type SetActiveInstruction<
+export type SetStatusInstruction<
   TProgram extends string = typeof FLOOR_SWAP_PROGRAM_ADDRESS,
   TAccountPool extends string | AccountMeta<string> = string,
   TAccountAuthority extends string | AccountMeta<string> = string,
@@ -58,59 +62,59 @@ export type SetActiveInstruction<
     ]
   >;
 
-export type SetActiveInstructionData = {
+export type SetStatusInstructionData = {
   discriminator: number;
-  active: boolean;
+  status: PoolStatus;
 };
 
-export type SetActiveInstructionDataArgs = { active: boolean };
+export type SetStatusInstructionDataArgs = { status: PoolStatusArgs };
 
-export function getSetActiveInstructionDataEncoder(): FixedSizeEncoder<SetActiveInstructionDataArgs> {
+export function getSetStatusInstructionDataEncoder(): FixedSizeEncoder<SetStatusInstructionDataArgs> {
   return transformEncoder(
     getStructEncoder([
       ['discriminator', getU8Encoder()],
-      ['active', getBooleanEncoder()],
+      ['status', getPoolStatusEncoder()],
     ]),
-    (value) => ({ ...value, discriminator: SET_ACTIVE_DISCRIMINATOR })
+    (value) => ({ ...value, discriminator: SET_STATUS_DISCRIMINATOR })
   );
 }
 
-export function getSetActiveInstructionDataDecoder(): FixedSizeDecoder<SetActiveInstructionData> {
+export function getSetStatusInstructionDataDecoder(): FixedSizeDecoder<SetStatusInstructionData> {
   return getStructDecoder([
     ['discriminator', getU8Decoder()],
-    ['active', getBooleanDecoder()],
+    ['status', getPoolStatusDecoder()],
   ]);
 }
 
-export function getSetActiveInstructionDataCodec(): FixedSizeCodec<
-  SetActiveInstructionDataArgs,
-  SetActiveInstructionData
+export function getSetStatusInstructionDataCodec(): FixedSizeCodec<
+  SetStatusInstructionDataArgs,
+  SetStatusInstructionData
 > {
   return combineCodec(
-    getSetActiveInstructionDataEncoder(),
-    getSetActiveInstructionDataDecoder()
+    getSetStatusInstructionDataEncoder(),
+    getSetStatusInstructionDataDecoder()
   );
 }
 
-export type SetActiveInput<
+export type SetStatusInput<
   TAccountPool extends string = string,
   TAccountAuthority extends string = string,
 > = {
-  /** The program derived address of the Pool account to toggle (seeds: ['floor_swap', authority, collection]) */
+  /** The program derived address of the Pool account (seeds: ['floor_swap', creator, collection]) */
   pool: Address<TAccountPool>;
-  /** The authority of the app */
+  /** The authority or the operator of the pool */
   authority: TransactionSigner<TAccountAuthority>;
-  active: SetActiveInstructionDataArgs['active'];
+  status: SetStatusInstructionDataArgs['status'];
 };
 
-export function getSetActiveInstruction<
+export function getSetStatusInstruction<
   TAccountPool extends string,
   TAccountAuthority extends string,
   TProgramAddress extends Address = typeof FLOOR_SWAP_PROGRAM_ADDRESS,
 >(
-  input: SetActiveInput<TAccountPool, TAccountAuthority>,
+  input: SetStatusInput<TAccountPool, TAccountAuthority>,
   config?: { programAddress?: TProgramAddress }
-): SetActiveInstruction<TProgramAddress, TAccountPool, TAccountAuthority> {
+): SetStatusInstruction<TProgramAddress, TAccountPool, TAccountAuthority> {
   // Program address.
   const programAddress = config?.programAddress ?? FLOOR_SWAP_PROGRAM_ADDRESS;
 
@@ -133,35 +137,35 @@ export function getSetActiveInstruction<
       getAccountMeta(accounts.pool),
       getAccountMeta(accounts.authority),
     ],
-    data: getSetActiveInstructionDataEncoder().encode(
-      args as SetActiveInstructionDataArgs
+    data: getSetStatusInstructionDataEncoder().encode(
+      args as SetStatusInstructionDataArgs
     ),
     programAddress,
-  } as SetActiveInstruction<TProgramAddress, TAccountPool, TAccountAuthority>);
+  } as SetStatusInstruction<TProgramAddress, TAccountPool, TAccountAuthority>);
 }
 
-export type ParsedSetActiveInstruction<
+export type ParsedSetStatusInstruction<
   TProgram extends string = typeof FLOOR_SWAP_PROGRAM_ADDRESS,
   TAccountMetas extends readonly AccountMeta[] = readonly AccountMeta[],
 > = {
   programAddress: Address<TProgram>;
   accounts: {
-    /** The program derived address of the Pool account to toggle (seeds: ['floor_swap', authority, collection]) */
+    /** The program derived address of the Pool account (seeds: ['floor_swap', creator, collection]) */
     pool: TAccountMetas[0];
-    /** The authority of the app */
+    /** The authority or the operator of the pool */
     authority: TAccountMetas[1];
   };
-  data: SetActiveInstructionData;
+  data: SetStatusInstructionData;
 };
 
-export function parseSetActiveInstruction<
+export function parseSetStatusInstruction<
   TProgram extends string,
   TAccountMetas extends readonly AccountMeta[],
 >(
   instruction: Instruction<TProgram> &
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
-): ParsedSetActiveInstruction<TProgram, TAccountMetas> {
+): ParsedSetStatusInstruction<TProgram, TAccountMetas> {
   if (instruction.accounts.length < 2) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
@@ -175,6 +179,6 @@ export function parseSetActiveInstruction<
   return {
     programAddress: instruction.programAddress,
     accounts: { pool: getNextAccount(), authority: getNextAccount() },
-    data: getSetActiveInstructionDataDecoder().decode(instruction.data),
+    data: getSetStatusInstructionDataDecoder().decode(instruction.data),
   };
 }
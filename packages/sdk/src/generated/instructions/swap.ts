@@ -8,28 +8,40 @@
 
 import {
   combineCodec,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
   getU8Decoder,
   getU8Encoder,
+  none,
   transformEncoder,
   type AccountMeta,
   type AccountSignerMeta,
   type Address,
-  type FixedSizeCodec,
-  type FixedSizeDecoder,
-  type FixedSizeEncoder,
+  type Codec,
+  type Decoder,
+  type Encoder,
   type Instruction,
   type InstructionWithAccounts,
   type InstructionWithData,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
   type WritableAccount,
   type WritableSignerAccount,
 } from '@solana/kit';
+import { findFeeVaultPda, findInventoryPda } from '../pdas';
 import { FLOOR_SWAP_PROGRAM_ADDRESS } from '../programs';
-import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+import {
+  expectAddress,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from '../shared';
 
 export const SWAP_DISCRIMINATOR = 3;
 
@@ -43,12 +55,26 @@ export type SwapInstruction<
   TAccountSourceAsset extends string | AccountMeta<string> = string,
   TAccountDestAsset extends string | AccountMeta<string> = string,
   TAccountPayer extends string | AccountMeta<string> = string,
-  TAccountTreasury extends string | AccountMeta<string> = string,
+  TAccountFeeVault extends string | AccountMeta<string> = string,
   TAccountCollection extends string | AccountMeta<string> = string,
   TAccountCoreProgram extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
+  TAccountFeeMint extends string | AccountMeta<string> = string,
+  TAccountPayerTokenAccount extends string | AccountMeta<string> = string,
+  TAccountTreasuryTokenAccount extends string | AccountMeta<string> = string,
+  TAccountTokenProgram extends string | AccountMeta<string> = string,
+  TAccountReferrer extends string | AccountMeta<string> = string,
+  TAccountReferrerTokenAccount extends string | AccountMeta<string> = string,
+  TAccountGlobalConfig extends
+    | string
+    | AccountMeta<string> = 'Eh9HhTbDPkxqTSsJqMhygBny7GjsrVyVmEAZvWYzBsy1',
+  TAccountProtocolTreasury extends string | AccountMeta<string> = string,
+  TAccountProtocolTreasuryTokenAccount extends
+    | string
+    | AccountMeta<string> = string,
+  TAccountInventory extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -67,9 +93,9 @@ export type SwapInstruction<
         ? WritableSignerAccount<TAccountPayer> &
             AccountSignerMeta<TAccountPayer>
         : TAccountPayer,
-      TAccountTreasury extends string
-        ? WritableAccount<TAccountTreasury>
-        : TAccountTreasury,
+      TAccountFeeVault extends string
+        ? WritableAccount<TAccountFeeVault>
+        : TAccountFeeVault,
       TAccountCollection extends string
         ? ReadonlyAccount<TAccountCollection>
         : TAccountCollection,
@@ -79,26 +105,75 @@ export type SwapInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountFeeMint extends string
+        ? ReadonlyAccount<TAccountFeeMint>
+        : TAccountFeeMint,
+      TAccountPayerTokenAccount extends string
+        ? WritableAccount<TAccountPayerTokenAccount>
+        : TAccountPayerTokenAccount,
+      TAccountTreasuryTokenAccount extends string
+        ? WritableAccount<TAccountTreasuryTokenAccount>
+        : TAccountTreasuryTokenAccount,
+      TAccountTokenProgram extends string
+        ? ReadonlyAccount<TAccountTokenProgram>
+        : TAccountTokenProgram,
+      TAccountReferrer extends string
+        ? WritableAccount<TAccountReferrer>
+        : TAccountReferrer,
+      TAccountReferrerTokenAccount extends string
+        ? WritableAccount<TAccountReferrerTokenAccount>
+        : TAccountReferrerTokenAccount,
+      TAccountGlobalConfig extends string
+        ? ReadonlyAccount<TAccountGlobalConfig>
+        : TAccountGlobalConfig,
+      TAccountProtocolTreasury extends string
+        ? WritableAccount<TAccountProtocolTreasury>
+        : TAccountProtocolTreasury,
+      TAccountProtocolTreasuryTokenAccount extends string
+        ? WritableAccount<TAccountProtocolTreasuryTokenAccount>
+        : TAccountProtocolTreasuryTokenAccount,
+      TAccountInventory extends string
+        ? WritableAccount<TAccountInventory>
+        : TAccountInventory,
       ...TRemainingAccounts,
     ]
   >;
 
-export type SwapInstructionData = { discriminator: number };
+export type SwapInstructionData = {
+  discriminator: number;
+  maxFee: bigint;
+  expectedSequence: Option<bigint>;
+};
 
-export type SwapInstructionDataArgs = {};
+export type SwapInstructionDataArgs = {
+  maxFee: number | bigint;
+  expectedSequence?: OptionOrNullable<number | bigint>;
+};
 
-export function getSwapInstructionDataEncoder(): FixedSizeEncoder<SwapInstructionDataArgs> {
+export function getSwapInstructionDataEncoder(): Encoder<SwapInstructionDataArgs> {
   return transformEncoder(
-    getStructEncoder([['discriminator', getU8Encoder()]]),
-    (value) => ({ ...value, discriminator: SWAP_DISCRIMINATOR })
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['maxFee', getU64Encoder()],
+      ['expectedSequence', getOptionEncoder(getU64Encoder())],
+    ]),
+    (value) => ({
+      ...value,
+      discriminator: SWAP_DISCRIMINATOR,
+      expectedSequence: value.expectedSequence ?? none(),
+    })
   );
 }
 
-export function getSwapInstructionDataDecoder(): FixedSizeDecoder<SwapInstructionData> {
-  return getStructDecoder([['discriminator', getU8Decoder()]]);
+export function getSwapInstructionDataDecoder(): Decoder<SwapInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['maxFee', getU64Decoder()],
+    ['expectedSequence', getOptionDecoder(getU64Decoder())],
+  ]);
 }
 
-export function getSwapInstructionDataCodec(): FixedSizeCodec<
+export function getSwapInstructionDataCodec(): Codec<
   SwapInstructionDataArgs,
   SwapInstructionData
 > {
@@ -108,17 +183,268 @@ export function getSwapInstructionDataCodec(): FixedSizeCodec<
   );
 }
 
+export type SwapAsyncInput<
+  TAccountPool extends string = string,
+  TAccountSourceAsset extends string = string,
+  TAccountDestAsset extends string = string,
+  TAccountPayer extends string = string,
+  TAccountFeeVault extends string = string,
+  TAccountCollection extends string = string,
+  TAccountCoreProgram extends string = string,
+  TAccountSystemProgram extends string = string,
+  TAccountFeeMint extends string = string,
+  TAccountPayerTokenAccount extends string = string,
+  TAccountTreasuryTokenAccount extends string = string,
+  TAccountTokenProgram extends string = string,
+  TAccountReferrer extends string = string,
+  TAccountReferrerTokenAccount extends string = string,
+  TAccountGlobalConfig extends string = string,
+  TAccountProtocolTreasury extends string = string,
+  TAccountProtocolTreasuryTokenAccount extends string = string,
+  TAccountInventory extends string = string,
+> = {
+  /** The program derived address of the Pool account to toggle (seeds: ['floor_swap', creator, collection]) */
+  pool: Address<TAccountPool>;
+  /** The mpl asset to send to the protocol */
+  sourceAsset: Address<TAccountSourceAsset>;
+  /** The mpl asset to receive from the protocol */
+  destAsset: Address<TAccountDestAsset>;
+  /** The user performing the swap */
+  payer: TransactionSigner<TAccountPayer>;
+  /** The program derived address of the FeeVault account of the pool (seeds: ['fee_vault', pool]) */
+  feeVault?: Address<TAccountFeeVault>;
+  /** The collection of the pool */
+  collection: Address<TAccountCollection>;
+  /** The MPL Core program */
+  coreProgram: Address<TAccountCoreProgram>;
+  /** The system program */
+  systemProgram?: Address<TAccountSystemProgram>;
+  /** The mint fees are paid in, when the pool has one */
+  feeMint?: Address<TAccountFeeMint>;
+  /** The payer token account of the fee mint */
+  payerTokenAccount?: Address<TAccountPayerTokenAccount>;
+  /** The treasury token account of the fee mint */
+  treasuryTokenAccount?: Address<TAccountTreasuryTokenAccount>;
+  /** The token program of the fee mint */
+  tokenProgram?: Address<TAccountTokenProgram>;
+  /** The wallet of the front-end that referred the swap, receiving the referral share of the fee */
+  referrer?: Address<TAccountReferrer>;
+  /** The referrer token account of the fee mint */
+  referrerTokenAccount?: Address<TAccountReferrerTokenAccount>;
+  /** The program derived address of the GlobalConfig account (seeds: ['global_config']) */
+  globalConfig?: Address<TAccountGlobalConfig>;
+  /** The protocol treasury of the global config, required when a protocol fee is charged */
+  protocolTreasury?: Address<TAccountProtocolTreasury>;
+  /** The protocol treasury token account of the fee mint */
+  protocolTreasuryTokenAccount?: Address<TAccountProtocolTreasuryTokenAccount>;
+  /** The program derived address of the Inventory account of the pool (seeds: ['inventory', pool]) */
+  inventory?: Address<TAccountInventory>;
+  maxFee: SwapInstructionDataArgs['maxFee'];
+  expectedSequence?: SwapInstructionDataArgs['expectedSequence'];
+};
+
+export async function getSwapInstructionAsync<
+  TAccountPool extends string,
+  TAccountSourceAsset extends string,
+  TAccountDestAsset extends string,
+  TAccountPayer extends string,
+  TAccountFeeVault extends string,
+  TAccountCollection extends string,
+  TAccountCoreProgram extends string,
+  TAccountSystemProgram extends string,
+  TAccountFeeMint extends string,
+  TAccountPayerTokenAccount extends string,
+  TAccountTreasuryTokenAccount extends string,
+  TAccountTokenProgram extends string,
+  TAccountReferrer extends string,
+  TAccountReferrerTokenAccount extends string,
+  TAccountGlobalConfig extends string,
+  TAccountProtocolTreasury extends string,
+  TAccountProtocolTreasuryTokenAccount extends string,
+  TAccountInventory extends string,
+  TProgramAddress extends Address = typeof FLOOR_SWAP_PROGRAM_ADDRESS,
+>(
+  input: SwapAsyncInput<
+    TAccountPool,
+    TAccountSourceAsset,
+    TAccountDestAsset,
+    TAccountPayer,
+    TAccountFeeVault,
+    TAccountCollection,
+    TAccountCoreProgram,
+    TAccountSystemProgram,
+    TAccountFeeMint,
+    TAccountPayerTokenAccount,
+    TAccountTreasuryTokenAccount,
+    TAccountTokenProgram,
+    TAccountReferrer,
+    TAccountReferrerTokenAccount,
+    TAccountGlobalConfig,
+    TAccountProtocolTreasury,
+    TAccountProtocolTreasuryTokenAccount,
+    TAccountInventory
+  >,
+  config?: { programAddress?: TProgramAddress }
+): Promise<
+  SwapInstruction<
+    TProgramAddress,
+    TAccountPool,
+    TAccountSourceAsset,
+    TAccountDestAsset,
+    TAccountPayer,
+    TAccountFeeVault,
+    TAccountCollection,
+    TAccountCoreProgram,
+    TAccountSystemProgram,
+    TAccountFeeMint,
+    TAccountPayerTokenAccount,
+    TAccountTreasuryTokenAccount,
+    TAccountTokenProgram,
+    TAccountReferrer,
+    TAccountReferrerTokenAccount,
+    TAccountGlobalConfig,
+    TAccountProtocolTreasury,
+    TAccountProtocolTreasuryTokenAccount,
+    TAccountInventory
+  >
+> {
+  // Program address.
+  const programAddress = config?.programAddress ?? FLOOR_SWAP_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    pool: { value: input.pool ?? null, isWritable: false },
+    sourceAsset: { value: input.sourceAsset ?? null, isWritable: true },
+    destAsset: { value: input.destAsset ?? null, isWritable: true },
+    payer: { value: input.payer ?? null, isWritable: true },
+    feeVault: { value: input.feeVault ?? null, isWritable: true },
+    collection: { value: input.collection ?? null, isWritable: false },
+    coreProgram: { value: input.coreProgram ?? null, isWritable: false },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    feeMint: { value: input.feeMint ?? null, isWritable: false },
+    payerTokenAccount: {
+      value: input.payerTokenAccount ?? null,
+      isWritable: true,
+    },
+    treasuryTokenAccount: {
+      value: input.treasuryTokenAccount ?? null,
+      isWritable: true,
+    },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    referrer: { value: input.referrer ?? null, isWritable: true },
+    referrerTokenAccount: {
+      value: input.referrerTokenAccount ?? null,
+      isWritable: true,
+    },
+    globalConfig: { value: input.globalConfig ?? null, isWritable: false },
+    protocolTreasury: {
+      value: input.protocolTreasury ?? null,
+      isWritable: true,
+    },
+    protocolTreasuryTokenAccount: {
+      value: input.protocolTreasuryTokenAccount ?? null,
+      isWritable: true,
+    },
+    inventory: { value: input.inventory ?? null, isWritable: true },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.feeVault.value) {
+    accounts.feeVault.value = await findFeeVaultPda({
+      pool: expectAddress(accounts.pool.value),
+    });
+  }
+  if (!accounts.inventory.value) {
+    accounts.inventory.value = await findInventoryPda({
+      pool: expectAddress(accounts.pool.value),
+    });
+  }
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+  if (!accounts.globalConfig.value) {
+    accounts.globalConfig.value =
+      'Eh9HhTbDPkxqTSsJqMhygBny7GjsrVyVmEAZvWYzBsy1' as Address<'Eh9HhTbDPkxqTSsJqMhygBny7GjsrVyVmEAZvWYzBsy1'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  return Object.freeze({
+    accounts: [
+      getAccountMeta(accounts.pool),
+      getAccountMeta(accounts.sourceAsset),
+      getAccountMeta(accounts.destAsset),
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.feeVault),
+      getAccountMeta(accounts.collection),
+      getAccountMeta(accounts.coreProgram),
+      getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.feeMint),
+      getAccountMeta(accounts.payerTokenAccount),
+      getAccountMeta(accounts.treasuryTokenAccount),
+      getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.referrer),
+      getAccountMeta(accounts.referrerTokenAccount),
+      getAccountMeta(accounts.globalConfig),
+      getAccountMeta(accounts.protocolTreasury),
+      getAccountMeta(accounts.protocolTreasuryTokenAccount),
+      getAccountMeta(accounts.inventory),
+    ],
+    data: getSwapInstructionDataEncoder().encode(
+      args as SwapInstructionDataArgs
+    ),
+    programAddress,
+  } as SwapInstruction<
+    TProgramAddress,
+    TAccountPool,
+    TAccountSourceAsset,
+    TAccountDestAsset,
+    TAccountPayer,
+    TAccountFeeVault,
+    TAccountCollection,
+    TAccountCoreProgram,
+    TAccountSystemProgram,
+    TAccountFeeMint,
+    TAccountPayerTokenAccount,
+    TAccountTreasuryTokenAccount,
+    TAccountTokenProgram,
+    TAccountReferrer,
+    TAccountReferrerTokenAccount,
+    TAccountGlobalConfig,
+    TAccountProtocolTreasury,
+    TAccountProtocolTreasuryTokenAccount,
+    TAccountInventory
+  >);
+}
+
 export type SwapInput<
   TAccountPool extends string = string,
   TAccountSourceAsset extends string = string,
   TAccountDestAsset extends string = string,
   TAccountPayer extends string = string,
-  TAccountTreasury extends string = string,
+  TAccountFeeVault extends string = string,
   TAccountCollection extends string = string,
   TAccountCoreProgram extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountFeeMint extends string = string,
+  TAccountPayerTokenAccount extends string = string,
+  TAccountTreasuryTokenAccount extends string = string,
+  TAccountTokenProgram extends string = string,
+  TAccountReferrer extends string = string,
+  TAccountReferrerTokenAccount extends string = string,
+  TAccountGlobalConfig extends string = string,
+  TAccountProtocolTreasury extends string = string,
+  TAccountProtocolTreasuryTokenAccount extends string = string,
+  TAccountInventory extends string = string,
 > = {
-  /** The program derived address of the Pool account to toggle (seeds: ['floor_swap', authority, collection]) */
+  /** The program derived address of the Pool account to toggle (seeds: ['floor_swap', creator, collection]) */
   pool: Address<TAccountPool>;
   /** The mpl asset to send to the protocol */
   sourceAsset: Address<TAccountSourceAsset>;
@@ -126,14 +452,36 @@ export type SwapInput<
   destAsset: Address<TAccountDestAsset>;
   /** The user performing the swap */
   payer: TransactionSigner<TAccountPayer>;
-  /** The treasury where fees are sent */
-  treasury: Address<TAccountTreasury>;
+  /** The program derived address of the FeeVault account of the pool (seeds: ['fee_vault', pool]) */
+  feeVault: Address<TAccountFeeVault>;
   /** The collection of the pool */
   collection: Address<TAccountCollection>;
   /** The MPL Core program */
   coreProgram: Address<TAccountCoreProgram>;
   /** The system program */
   systemProgram?: Address<TAccountSystemProgram>;
+  /** The mint fees are paid in, when the pool has one */
+  feeMint?: Address<TAccountFeeMint>;
+  /** The payer token account of the fee mint */
+  payerTokenAccount?: Address<TAccountPayerTokenAccount>;
+  /** The treasury token account of the fee mint */
+  treasuryTokenAccount?: Address<TAccountTreasuryTokenAccount>;
+  /** The token program of the fee mint */
+  tokenProgram?: Address<TAccountTokenProgram>;
+  /** The wallet of the front-end that referred the swap, receiving the referral share of the fee */
+  referrer?: Address<TAccountReferrer>;
+  /** The referrer token account of the fee mint */
+  referrerTokenAccount?: Address<TAccountReferrerTokenAccount>;
+  /** The program derived address of the GlobalConfig account (seeds: ['global_config']) */
+  globalConfig?: Address<TAccountGlobalConfig>;
+  /** The protocol treasury of the global config, required when a protocol fee is charged */
+  protocolTreasury?: Address<TAccountProtocolTreasury>;
+  /** The protocol treasury token account of the fee mint */
+  protocolTreasuryTokenAccount?: Address<TAccountProtocolTreasuryTokenAccount>;
+  /** The program derived address of the Inventory account of the pool (seeds: ['inventory', pool]) */
+  inventory: Address<TAccountInventory>;
+  maxFee: SwapInstructionDataArgs['maxFee'];
+  expectedSequence?: SwapInstructionDataArgs['expectedSequence'];
 };
 
 export function getSwapInstruction<
@@ -141,10 +489,20 @@ export function getSwapInstruction<
   TAccountSourceAsset extends string,
   TAccountDestAsset extends string,
   TAccountPayer extends string,
-  TAccountTreasury extends string,
+  TAccountFeeVault extends string,
   TAccountCollection extends string,
   TAccountCoreProgram extends string,
   TAccountSystemProgram extends string,
+  TAccountFeeMint extends string,
+  TAccountPayerTokenAccount extends string,
+  TAccountTreasuryTokenAccount extends string,
+  TAccountTokenProgram extends string,
+  TAccountReferrer extends string,
+  TAccountReferrerTokenAccount extends string,
+  TAccountGlobalConfig extends string,
+  TAccountProtocolTreasury extends string,
+  TAccountProtocolTreasuryTokenAccount extends string,
+  TAccountInventory extends string,
   TProgramAddress extends Address = typeof FLOOR_SWAP_PROGRAM_ADDRESS,
 >(
   input: SwapInput<
@@ -152,10 +510,20 @@ export function getSwapInstruction<
     TAccountSourceAsset,
     TAccountDestAsset,
     TAccountPayer,
-    TAccountTreasury,
+    TAccountFeeVault,
     TAccountCollection,
     TAccountCoreProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountFeeMint,
+    TAccountPayerTokenAccount,
+    TAccountTreasuryTokenAccount,
+    TAccountTokenProgram,
+    TAccountReferrer,
+    TAccountReferrerTokenAccount,
+    TAccountGlobalConfig,
+    TAccountProtocolTreasury,
+    TAccountProtocolTreasuryTokenAccount,
+    TAccountInventory
   >,
   config?: { programAddress?: TProgramAddress }
 ): SwapInstruction<
@@ -164,10 +532,20 @@ export function getSwapInstruction<
   TAccountSourceAsset,
   TAccountDestAsset,
   TAccountPayer,
-  TAccountTreasury,
+  TAccountFeeVault,
   TAccountCollection,
   TAccountCoreProgram,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountFeeMint,
+  TAccountPayerTokenAccount,
+  TAccountTreasuryTokenAccount,
+  TAccountTokenProgram,
+  TAccountReferrer,
+  TAccountReferrerTokenAccount,
+  TAccountGlobalConfig,
+  TAccountProtocolTreasury,
+  TAccountProtocolTreasuryTokenAccount,
+  TAccountInventory
 > {
   // Program address.
   const programAddress = config?.programAddress ?? FLOOR_SWAP_PROGRAM_ADDRESS;
@@ -178,21 +556,53 @@ export function getSwapInstruction<
     sourceAsset: { value: input.sourceAsset ?? null, isWritable: true },
     destAsset: { value: input.destAsset ?? null, isWritable: true },
     payer: { value: input.payer ?? null, isWritable: true },
-    treasury: { value: input.treasury ?? null, isWritable: true },
+    feeVault: { value: input.feeVault ?? null, isWritable: true },
     collection: { value: input.collection ?? null, isWritable: false },
     coreProgram: { value: input.coreProgram ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    feeMint: { value: input.feeMint ?? null, isWritable: false },
+    payerTokenAccount: {
+      value: input.payerTokenAccount ?? null,
+      isWritable: true,
+    },
+    treasuryTokenAccount: {
+      value: input.treasuryTokenAccount ?? null,
+      isWritable: true,
+    },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    referrer: { value: input.referrer ?? null, isWritable: true },
+    referrerTokenAccount: {
+      value: input.referrerTokenAccount ?? null,
+      isWritable: true,
+    },
+    globalConfig: { value: input.globalConfig ?? null, isWritable: false },
+    protocolTreasury: {
+      value: input.protocolTreasury ?? null,
+      isWritable: true,
+    },
+    protocolTreasuryTokenAccount: {
+      value: input.protocolTreasuryTokenAccount ?? null,
+      isWritable: true,
+    },
+    inventory: { value: input.inventory ?? null, isWritable: true },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
     ResolvedAccount
   >;
 
+  // Original args.
+  const args = { ...input };
+
   // Resolve default values.
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
+  if (!accounts.globalConfig.value) {
+    accounts.globalConfig.value =
+      'Eh9HhTbDPkxqTSsJqMhygBny7GjsrVyVmEAZvWYzBsy1' as Address<'Eh9HhTbDPkxqTSsJqMhygBny7GjsrVyVmEAZvWYzBsy1'>;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
@@ -201,12 +611,24 @@ export function getSwapInstruction<
       getAccountMeta(accounts.sourceAsset),
       getAccountMeta(accounts.destAsset),
       getAccountMeta(accounts.payer),
-      getAccountMeta(accounts.treasury),
+      getAccountMeta(accounts.feeVault),
       getAccountMeta(accounts.collection),
       getAccountMeta(accounts.coreProgram),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.feeMint),
+      getAccountMeta(accounts.payerTokenAccount),
+      getAccountMeta(accounts.treasuryTokenAccount),
+      getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.referrer),
+      getAccountMeta(accounts.referrerTokenAccount),
+      getAccountMeta(accounts.globalConfig),
+      getAccountMeta(accounts.protocolTreasury),
+      getAccountMeta(accounts.protocolTreasuryTokenAccount),
+      getAccountMeta(accounts.inventory),
     ],
-    data: getSwapInstructionDataEncoder().encode({}),
+    data: getSwapInstructionDataEncoder().encode(
+      args as SwapInstructionDataArgs
+    ),
     programAddress,
   } as SwapInstruction<
     TProgramAddress,
@@ -214,10 +636,20 @@ export function getSwapInstruction<
     TAccountSourceAsset,
     TAccountDestAsset,
     TAccountPayer,
-    TAccountTreasury,
+    TAccountFeeVault,
     TAccountCollection,
     TAccountCoreProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountFeeMint,
+    TAccountPayerTokenAccount,
+    TAccountTreasuryTokenAccount,
+    TAccountTokenProgram,
+    TAccountReferrer,
+    TAccountReferrerTokenAccount,
+    TAccountGlobalConfig,
+    TAccountProtocolTreasury,
+    TAccountProtocolTreasuryTokenAccount,
+    TAccountInventory
   >);
 }
 
@@ -227,7 +659,7 @@ export type ParsedSwapInstruction<
 > = {
   programAddress: Address<TProgram>;
   accounts: {
-    /** The program derived address of the Pool account to toggle (seeds: ['floor_swap', authority, collection]) */
+    /** The program derived address of the Pool account to toggle (seeds: ['floor_swap', creator, collection]) */
     pool: TAccountMetas[0];
     /** The mpl asset to send to the protocol */
     sourceAsset: TAccountMetas[1];
@@ -235,14 +667,34 @@ export type ParsedSwapInstruction<
     destAsset: TAccountMetas[2];
     /** The user performing the swap */
     payer: TAccountMetas[3];
-    /** The treasury where fees are sent */
-    treasury: TAccountMetas[4];
+    /** The program derived address of the FeeVault account of the pool (seeds: ['fee_vault', pool]) */
+    feeVault: TAccountMetas[4];
     /** The collection of the pool */
     collection: TAccountMetas[5];
     /** The MPL Core program */
     coreProgram: TAccountMetas[6];
     /** The system program */
     systemProgram: TAccountMetas[7];
+    /** The mint fees are paid in, when the pool has one */
+    feeMint?: TAccountMetas[8] | undefined;
+    /** The payer token account of the fee mint */
+    payerTokenAccount?: TAccountMetas[9] | undefined;
+    /** The treasury token account of the fee mint */
+    treasuryTokenAccount?: TAccountMetas[10] | undefined;
+    /** The token program of the fee mint */
+    tokenProgram?: TAccountMetas[11] | undefined;
+    /** The wallet of the front-end that referred the swap, receiving the referral share of the fee */
+    referrer?: TAccountMetas[12] | undefined;
+    /** The referrer token account of the fee mint */
+    referrerTokenAccount?: TAccountMetas[13] | undefined;
+    /** The program derived address of the GlobalConfig account (seeds: ['global_config']) */
+    globalConfig: TAccountMetas[14];
+    /** The protocol treasury of the global config, required when a protocol fee is charged */
+    protocolTreasury?: TAccountMetas[15] | undefined;
+    /** The protocol treasury token account of the fee mint */
+    protocolTreasuryTokenAccount?: TAccountMetas[16] | undefined;
+    /** The program derived address of the Inventory account of the pool (seeds: ['inventory', pool]) */
+    inventory: TAccountMetas[17];
   };
   data: SwapInstructionData;
 };
@@ -255,7 +707,7 @@ export function parseSwapInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedSwapInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 8) {
+  if (instruction.accounts.length < 18) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -265,6 +717,12 @@ export function parseSwapInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === FLOOR_SWAP_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -272,10 +730,20 @@ export function parseSwapInstruction<
       sourceAsset: getNextAccount(),
       destAsset: getNextAccount(),
       payer: getNextAccount(),
-      treasury: getNextAccount(),
+      feeVault: getNextAccount(),
       collection: getNextAccount(),
       coreProgram: getNextAccount(),
       systemProgram: getNextAccount(),
+      feeMint: getNextOptionalAccount(),
+      payerTokenAccount: getNextOptionalAccount(),
+      treasuryTokenAccount: getNextOptionalAccount(),
+      tokenProgram: getNextOptionalAccount(),
+      referrer: getNextOptionalAccount(),
+      referrerTokenAccount: getNextOptionalAccount(),
+      globalConfig: getNextAccount(),
+      protocolTreasury: getNextOptionalAccount(),
+      protocolTreasuryTokenAccount: getNextOptionalAccount(),
+      inventory: getNextAccount(),
     },
     data: getSwapInstructionDataDecoder().decode(instruction.data),
   };
@@ -28,8 +28,13 @@ import {
   type TransactionSigner,
   type WritableAccount,
 } from '@solana/kit';
+import { findInventoryPda } from '../pdas';
 import { FLOOR_SWAP_PROGRAM_ADDRESS } from '../programs';
-import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+import {
+  expectAddress,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from '../shared';
 
 export const WITHDRAW_DISCRIMINATOR = 5;
 
@@ -45,6 +50,10 @@ export type WithdrawInstruction<
   TAccountCollection extends string | AccountMeta<string> = string,
   TAccountDestination extends string | AccountMeta<string> = string,
   TAccountCoreProgram extends string | AccountMeta<string> = string,
+  TAccountGlobalConfig extends
+    | string
+    | AccountMeta<string> = 'Eh9HhTbDPkxqTSsJqMhygBny7GjsrVyVmEAZvWYzBsy1',
+  TAccountInventory extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -69,6 +78,12 @@ export type WithdrawInstruction<
       TAccountCoreProgram extends string
         ? ReadonlyAccount<TAccountCoreProgram>
         : TAccountCoreProgram,
+      TAccountGlobalConfig extends string
+        ? ReadonlyAccount<TAccountGlobalConfig>
+        : TAccountGlobalConfig,
+      TAccountInventory extends string
+        ? WritableAccount<TAccountInventory>
+        : TAccountInventory,
       ...TRemainingAccounts,
     ]
   >;
@@ -98,6 +113,126 @@ export function getWithdrawInstructionDataCodec(): FixedSizeCodec<
   );
 }
 
+export type WithdrawAsyncInput<
+  TAccountPool extends string = string,
+  TAccountAuthority extends string = string,
+  TAccountAsset extends string = string,
+  TAccountCollection extends string = string,
+  TAccountDestination extends string = string,
+  TAccountCoreProgram extends string = string,
+  TAccountGlobalConfig extends string = string,
+  TAccountInventory extends string = string,
+> = {
+  /** The PDA of the Pool account (seeds: ['floor_swap', creator, collection]) */
+  pool: Address<TAccountPool>;
+  /** The authority of the pool */
+  authority: TransactionSigner<TAccountAuthority>;
+  /** The mpl-core asset to deposit */
+  asset: Address<TAccountAsset>;
+  /** The collection of the asset */
+  collection: Address<TAccountCollection>;
+  /** The wallet to receive the asset */
+  destination?: Address<TAccountDestination>;
+  /** The MPL Core program */
+  coreProgram: Address<TAccountCoreProgram>;
+  /** The program derived address of the GlobalConfig account (seeds: ['global_config']) */
+  globalConfig?: Address<TAccountGlobalConfig>;
+  /** The program derived address of the Inventory account of the pool (seeds: ['inventory', pool]) */
+  inventory?: Address<TAccountInventory>;
+};
+
+export async function getWithdrawInstructionAsync<
+  TAccountPool extends string,
+  TAccountAuthority extends string,
+  TAccountAsset extends string,
+  TAccountCollection extends string,
+  TAccountDestination extends string,
+  TAccountCoreProgram extends string,
+  TAccountGlobalConfig extends string,
+  TAccountInventory extends string,
+  TProgramAddress extends Address = typeof FLOOR_SWAP_PROGRAM_ADDRESS,
+>(
+  input: WithdrawAsyncInput<
+    TAccountPool,
+    TAccountAuthority,
+    TAccountAsset,
+    TAccountCollection,
+    TAccountDestination,
+    TAccountCoreProgram,
+    TAccountGlobalConfig,
+    TAccountInventory
+  >,
+  config?: { programAddress?: TProgramAddress }
+): Promise<
+  WithdrawInstruction<
+    TProgramAddress,
+    TAccountPool,
+    TAccountAuthority,
+    TAccountAsset,
+    TAccountCollection,
+    TAccountDestination,
+    TAccountCoreProgram,
+    TAccountGlobalConfig,
+    TAccountInventory
+  >
+> {
+  // Program address.
+  const programAddress = config?.programAddress ?? FLOOR_SWAP_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    pool: { value: input.pool ?? null, isWritable: true },
+    authority: { value: input.authority ?? null, isWritable: false },
+    asset: { value: input.asset ?? null, isWritable: true },
+    collection: { value: input.collection ?? null, isWritable: false },
+    destination: { value: input.destination ?? null, isWritable: false },
+    coreProgram: { value: input.coreProgram ?? null, isWritable: false },
+    globalConfig: { value: input.globalConfig ?? null, isWritable: false },
+    inventory: { value: input.inventory ?? null, isWritable: true },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.inventory.value) {
+    accounts.inventory.value = await findInventoryPda({
+      pool: expectAddress(accounts.pool.value),
+    });
+  }
+  if (!accounts.globalConfig.value) {
+    accounts.globalConfig.value =
+      'Eh9HhTbDPkxqTSsJqMhygBny7GjsrVyVmEAZvWYzBsy1' as Address<'Eh9HhTbDPkxqTSsJqMhygBny7GjsrVyVmEAZvWYzBsy1'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  return Object.freeze({
+    accounts: [
+      getAccountMeta(accounts.pool),
+      getAccountMeta(accounts.authority),
+      getAccountMeta(accounts.asset),
+      getAccountMeta(accounts.collection),
+      getAccountMeta(accounts.destination),
+      getAccountMeta(accounts.coreProgram),
+      getAccountMeta(accounts.globalConfig),
+      getAccountMeta(accounts.inventory),
+    ],
+    data: getWithdrawInstructionDataEncoder().encode({}),
+    programAddress,
+  } as WithdrawInstruction<
+    TProgramAddress,
+    TAccountPool,
+    TAccountAuthority,
+    TAccountAsset,
+    TAccountCollection,
+    TAccountDestination,
+    TAccountCoreProgram,
+    TAccountGlobalConfig,
+    TAccountInventory
+  >);
+}
+
 export type WithdrawInput<
   TAccountPool extends string = string,
   TAccountAuthority extends string = string,
@@ -105,8 +240,10 @@ export type WithdrawInput<
   TAccountCollection extends string = string,
   TAccountDestination extends string = string,
   TAccountCoreProgram extends string = string,
+  TAccountGlobalConfig extends string = string,
+  TAccountInventory extends string = string,
 > = {
-  /** The PDA of the Pool account (seeds: ['floor_swap', authority, collection]) */
+  /** The PDA of the Pool account (seeds: ['floor_swap', creator, collection]) */
   pool: Address<TAccountPool>;
   /** The authority of the pool */
   authority: TransactionSigner<TAccountAuthority>;
@@ -118,6 +255,10 @@ export type WithdrawInput<
   destination?: Address<TAccountDestination>;
   /** The MPL Core program */
   coreProgram: Address<TAccountCoreProgram>;
+  /** The program derived address of the GlobalConfig account (seeds: ['global_config']) */
+  globalConfig?: Address<TAccountGlobalConfig>;
+  /** The program derived address of the Inventory account of the pool (seeds: ['inventory', pool]) */
+  inventory: Address<TAccountInventory>;
 };
 
 export function getWithdrawInstruction<
@@ -127,6 +268,8 @@ export function getWithdrawInstruction<
   TAccountCollection extends string,
   TAccountDestination extends string,
   TAccountCoreProgram extends string,
+  TAccountGlobalConfig extends string,
+  TAccountInventory extends string,
   TProgramAddress extends Address = typeof FLOOR_SWAP_PROGRAM_ADDRESS,
 >(
   input: WithdrawInput<
@@ -135,7 +278,9 @@ export function getWithdrawInstruction<
     TAccountAsset,
     TAccountCollection,
     TAccountDestination,
-    TAccountCoreProgram
+    TAccountCoreProgram,
+    TAccountGlobalConfig,
+    TAccountInventory
   >,
   config?: { programAddress?: TProgramAddress }
 ): WithdrawInstruction<
@@ -145,7 +290,9 @@ export function getWithdrawInstruction<
   TAccountAsset,
   TAccountCollection,
   TAccountDestination,
-  TAccountCoreProgram
+  TAccountCoreProgram,
+  TAccountGlobalConfig,
+  TAccountInventory
 > {
   // Program address.
   const programAddress = config?.programAddress ?? FLOOR_SWAP_PROGRAM_ADDRESS;
@@ -158,12 +305,20 @@ export function getWithdrawInstruction<
     collection: { value: input.collection ?? null, isWritable: false },
     destination: { value: input.destination ?? null, isWritable: false },
     coreProgram: { value: input.coreProgram ?? null, isWritable: false },
+    globalConfig: { value: input.globalConfig ?? null, isWritable: false },
+    inventory: { value: input.inventory ?? null, isWritable: true },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
     ResolvedAccount
   >;
 
+  // Resolve default values.
+  if (!accounts.globalConfig.value) {
+    accounts.globalConfig.value =
+      'Eh9HhTbDPkxqTSsJqMhygBny7GjsrVyVmEAZvWYzBsy1' as Address<'Eh9HhTbDPkxqTSsJqMhygBny7GjsrVyVmEAZvWYzBsy1'>;
+  }
+
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
     accounts: [
@@ -173,6 +328,8 @@ export function getWithdrawInstruction<
       getAccountMeta(accounts.collection),
       getAccountMeta(accounts.destination),
       getAccountMeta(accounts.coreProgram),
+      getAccountMeta(accounts.globalConfig),
+      getAccountMeta(accounts.inventory),
     ],
     data: getWithdrawInstructionDataEncoder().encode({}),
     programAddress,
@@ -183,7 +340,9 @@ export function getWithdrawInstruction<
     TAccountAsset,
     TAccountCollection,
     TAccountDestination,
-    TAccountCoreProgram
+    TAccountCoreProgram,
+    TAccountGlobalConfig,
+    TAccountInventory
   >);
 }
 
@@ -193,7 +352,7 @@ export type ParsedWithdrawInstruction<
 > = {
   programAddress: Address<TProgram>;
   accounts: {
-    /** The PDA of the Pool account (seeds: ['floor_swap', authority, collection]) */
+    /** The PDA of the Pool account (seeds: ['floor_swap', creator, collection]) */
     pool: TAccountMetas[0];
     /** The authority of the pool */
     authority: TAccountMetas[1];
@@ -205,6 +364,10 @@ export type ParsedWithdrawInstruction<
     destination?: TAccountMetas[4] | undefined;
     /** The MPL Core program */
     coreProgram: TAccountMetas[5];
+    /** The program derived address of the GlobalConfig account (seeds: ['global_config']) */
+    globalConfig: TAccountMetas[6];
+    /** The program derived address of the Inventory account of the pool (seeds: ['inventory', pool]) */
+    inventory: TAccountMetas[7];
   };
   data: WithdrawInstructionData;
 };
@@ -217,7 +380,7 @@ export function parseWithdrawInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedWithdrawInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 6) {
+  if (instruction.accounts.length < 8) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -242,6 +405,8 @@ export function parseWithdrawInstruction<
       collection: getNextAccount(),
       destination: getNextOptionalAccount(),
       coreProgram: getNextAccount(),
+      globalConfig: getNextAccount(),
+      inventory: getNextAccount(),
     },
     data: getWithdrawInstructionDataDecoder().decode(instruction.data),
   };
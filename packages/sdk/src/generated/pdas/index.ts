@@ -6,4 +6,9 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './feeVault';
+export * from './globalConfig';
+export * from './indexedPool';
+export * from './inventory';
 export * from './pool';
+export * from './swapCommit';
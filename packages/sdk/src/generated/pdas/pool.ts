@@ -15,8 +15,8 @@ import {
 } from '@solana/kit';
 
 export type PoolSeeds = {
-  /** The authority of the pool */
-  authority: Address;
+  /** The authority that created the pool */
+  creator: Address;
   /** The collection of the pool */
   collection: Address;
 };
@@ -32,7 +32,7 @@ export async function findPoolPda(
     programAddress,
     seeds: [
       getUtf8Encoder().encode('floor_swap'),
-      getAddressEncoder().encode(seeds.authority),
+      getAddressEncoder().encode(seeds.creator),
       getAddressEncoder().encode(seeds.collection),
     ],
   });
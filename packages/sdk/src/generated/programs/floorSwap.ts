@@ -13,13 +13,38 @@ import {
   type ReadonlyUint8Array,
 } from '@solana/kit';
 import {
+  type ParsedAcceptAuthorityInstruction,
+  type ParsedApplyFeeInstruction,
+  type ParsedCancelSwapInstruction,
+  type ParsedClaimFeesInstruction,
+  type ParsedCloseAndDrainInstruction,
   type ParsedCloseInstruction,
+  type ParsedCommitSwapInstruction,
   type ParsedCreateInstruction,
   type ParsedDepositInstruction,
-  type ParsedSetActiveInstruction,
+  type ParsedDepositManyInstruction,
+  type ParsedInitGlobalConfigInstruction,
+  type ParsedMigrateInstruction,
+  type ParsedPauseProgramInstruction,
+  type ParsedProposeAuthorityInstruction,
+  type ParsedProposeFeeInstruction,
+  type ParsedReconcileInstruction,
+  type ParsedRevealSwapInstruction,
   type ParsedSetFeeInstruction,
+  type ParsedSetFeeSplitInstruction,
+  type ParsedSetModeInstruction,
+  type ParsedSetMultisigInstruction,
+  type ParsedSetReferencePriceInstruction,
+  type ParsedSetReferralFeeInstruction,
+  type ParsedSetRolesInstruction,
+  type ParsedSetRoyaltyBaseInstruction,
+  type ParsedSetStatusInstruction,
+  type ParsedSwapBatchInstruction,
   type ParsedSwapInstruction,
+  type ParsedUpdateGlobalConfigInstruction,
+  type ParsedUpdatePoolInstruction,
   type ParsedWithdrawInstruction,
+  type ParsedWithdrawManyInstruction,
 } from '../instructions';
 import { getKeyEncoder, Key } from '../types';
 
@@ -28,6 +53,10 @@ export const FLOOR_SWAP_PROGRAM_ADDRESS =
 
 export enum FloorSwapAccount {
   Pool,
+  SwapCommit,
+  FeeVault,
+  GlobalConfig,
+  Inventory,
 }
 
 export function identifyFloorSwapAccount(
@@ -37,6 +66,18 @@ export function identifyFloorSwapAccount(
   if (containsBytes(data, getKeyEncoder().encode(Key.Pool), 0)) {
     return FloorSwapAccount.Pool;
   }
+  if (containsBytes(data, getKeyEncoder().encode(Key.SwapCommit), 0)) {
+    return FloorSwapAccount.SwapCommit;
+  }
+  if (containsBytes(data, getKeyEncoder().encode(Key.FeeVault), 0)) {
+    return FloorSwapAccount.FeeVault;
+  }
+  if (containsBytes(data, getKeyEncoder().encode(Key.GlobalConfig), 0)) {
+    return FloorSwapAccount.GlobalConfig;
+  }
+  if (containsBytes(data, getKeyEncoder().encode(Key.Inventory), 0)) {
+    return FloorSwapAccount.Inventory;
+  }
   throw new Error(
     'The provided account could not be identified as a floorSwap account.'
   );
@@ -44,12 +85,37 @@ export function identifyFloorSwapAccount(
 
 export enum FloorSwapInstruction {
   Create,
-  SetActive,
+  SetStatus,
   SetFee,
   Swap,
   Deposit,
   Withdraw,
   Close,
+  SwapBatch,
+  DepositMany,
+  WithdrawMany,
+  SetMode,
+  CommitSwap,
+  RevealSwap,
+  CancelSwap,
+  SetReferencePrice,
+  SetRoyaltyBase,
+  SetFeeSplit,
+  ClaimFees,
+  SetReferralFee,
+  ProposeFee,
+  ApplyFee,
+  UpdatePool,
+  ProposeAuthority,
+  AcceptAuthority,
+  SetRoles,
+  SetMultisig,
+  InitGlobalConfig,
+  UpdateGlobalConfig,
+  PauseProgram,
+  Reconcile,
+  CloseAndDrain,
+  Migrate,
 }
 
 export function identifyFloorSwapInstruction(
@@ -60,7 +126,7 @@ export function identifyFloorSwapInstruction(
     return FloorSwapInstruction.Create;
   }
   if (containsBytes(data, getU8Encoder().encode(1), 0)) {
-    return FloorSwapInstruction.SetActive;
+    return FloorSwapInstruction.SetStatus;
   }
   if (containsBytes(data, getU8Encoder().encode(2), 0)) {
     return FloorSwapInstruction.SetFee;
@@ -77,6 +143,81 @@ export function identifyFloorSwapInstruction(
   if (containsBytes(data, getU8Encoder().encode(6), 0)) {
     return FloorSwapInstruction.Close;
   }
+  if (containsBytes(data, getU8Encoder().encode(7), 0)) {
+    return FloorSwapInstruction.SwapBatch;
+  }
+  if (containsBytes(data, getU8Encoder().encode(8), 0)) {
+    return FloorSwapInstruction.DepositMany;
+  }
+  if (containsBytes(data, getU8Encoder().encode(9), 0)) {
+    return FloorSwapInstruction.WithdrawMany;
+  }
+  if (containsBytes(data, getU8Encoder().encode(10), 0)) {
+    return FloorSwapInstruction.SetMode;
+  }
+  if (containsBytes(data, getU8Encoder().encode(11), 0)) {
+    return FloorSwapInstruction.CommitSwap;
+  }
+  if (containsBytes(data, getU8Encoder().encode(12), 0)) {
+    return FloorSwapInstruction.RevealSwap;
+  }
+  if (containsBytes(data, getU8Encoder().encode(13), 0)) {
+    return FloorSwapInstruction.CancelSwap;
+  }
+  if (containsBytes(data, getU8Encoder().encode(14), 0)) {
+    return FloorSwapInstruction.SetReferencePrice;
+  }
+  if (containsBytes(data, getU8Encoder().encode(15), 0)) {
+    return FloorSwapInstruction.SetRoyaltyBase;
+  }
+  if (containsBytes(data, getU8Encoder().encode(16), 0)) {
+    return FloorSwapInstruction.SetFeeSplit;
+  }
+  if (containsBytes(data, getU8Encoder().encode(17), 0)) {
+    return FloorSwapInstruction.ClaimFees;
+  }
+  if (containsBytes(data, getU8Encoder().encode(18), 0)) {
+    return FloorSwapInstruction.SetReferralFee;
+  }
+  if (containsBytes(data, getU8Encoder().encode(19), 0)) {
+    return FloorSwapInstruction.ProposeFee;
+  }
+  if (containsBytes(data, getU8Encoder().encode(20), 0)) {
+    return FloorSwapInstruction.ApplyFee;
+  }
+  if (containsBytes(data, getU8Encoder().encode(21), 0)) {
+    return FloorSwapInstruction.UpdatePool;
+  }
+  if (containsBytes(data, getU8Encoder().encode(22), 0)) {
+    return FloorSwapInstruction.ProposeAuthority;
+  }
+  if (containsBytes(data, getU8Encoder().encode(23), 0)) {
+    return FloorSwapInstruction.AcceptAuthority;
+  }
+  if (containsBytes(data, getU8Encoder().encode(24), 0)) {
+    return FloorSwapInstruction.SetRoles;
+  }
+  if (containsBytes(data, getU8Encoder().encode(25), 0)) {
+    return FloorSwapInstruction.SetMultisig;
+  }
+  if (containsBytes(data, getU8Encoder().encode(26), 0)) {
+    return FloorSwapInstruction.InitGlobalConfig;
+  }
+  if (containsBytes(data, getU8Encoder().encode(27), 0)) {
+    return FloorSwapInstruction.UpdateGlobalConfig;
+  }
+  if (containsBytes(data, getU8Encoder().encode(28), 0)) {
+    return FloorSwapInstruction.PauseProgram;
+  }
+  if (containsBytes(data, getU8Encoder().encode(29), 0)) {
+    return FloorSwapInstruction.Reconcile;
+  }
+  if (containsBytes(data, getU8Encoder().encode(30), 0)) {
+    return FloorSwapInstruction.CloseAndDrain;
+  }
+  if (containsBytes(data, getU8Encoder().encode(31), 0)) {
+    return FloorSwapInstruction.Migrate;
+  }
   throw new Error(
     'The provided instruction could not be identified as a floorSwap instruction.'
   );
@@ -89,8 +230,8 @@ export type ParsedFloorSwapInstruction<
       instructionType: FloorSwapInstruction.Create;
     } & ParsedCreateInstruction<TProgram>)
   | ({
-      instructionType: FloorSwapInstruction.SetActive;
-    } & ParsedSetActiveInstruction<TProgram>)
+      instructionType: FloorSwapInstruction.SetStatus;
+    } & ParsedSetStatusInstruction<TProgram>)
   | ({
       instructionType: FloorSwapInstruction.SetFee;
     } & ParsedSetFeeInstruction<TProgram>)
@@ -105,4 +246,79 @@ export type ParsedFloorSwapInstruction<
     } & ParsedWithdrawInstruction<TProgram>)
   | ({
       instructionType: FloorSwapInstruction.Close;
-    } & ParsedCloseInstruction<TProgram>);
+    } & ParsedCloseInstruction<TProgram>)
+  | ({
+      instructionType: FloorSwapInstruction.SwapBatch;
+    } & ParsedSwapBatchInstruction<TProgram>)
+  | ({
+      instructionType: FloorSwapInstruction.DepositMany;
+    } & ParsedDepositManyInstruction<TProgram>)
+  | ({
+      instructionType: FloorSwapInstruction.WithdrawMany;
+    } & ParsedWithdrawManyInstruction<TProgram>)
+  | ({
+      instructionType: FloorSwapInstruction.SetMode;
+    } & ParsedSetModeInstruction<TProgram>)
+  | ({
+      instructionType: FloorSwapInstruction.CommitSwap;
+    } & ParsedCommitSwapInstruction<TProgram>)
+  | ({
+      instructionType: FloorSwapInstruction.RevealSwap;
+    } & ParsedRevealSwapInstruction<TProgram>)
+  | ({
+      instructionType: FloorSwapInstruction.CancelSwap;
+    } & ParsedCancelSwapInstruction<TProgram>)
+  | ({
+      instructionType: FloorSwapInstruction.SetReferencePrice;
+    } & ParsedSetReferencePriceInstruction<TProgram>)
+  | ({
+      instructionType: FloorSwapInstruction.SetRoyaltyBase;
+    } & ParsedSetRoyaltyBaseInstruction<TProgram>)
+  | ({
+      instructionType: FloorSwapInstruction.SetFeeSplit;
+    } & ParsedSetFeeSplitInstruction<TProgram>)
+  | ({
+      instructionType: FloorSwapInstruction.ClaimFees;
+    } & ParsedClaimFeesInstruction<TProgram>)
+  | ({
+      instructionType: FloorSwapInstruction.SetReferralFee;
+    } & ParsedSetReferralFeeInstruction<TProgram>)
+  | ({
+      instructionType: FloorSwapInstruction.ProposeFee;
+    } & ParsedProposeFeeInstruction<TProgram>)
+  | ({
+      instructionType: FloorSwapInstruction.ApplyFee;
+    } & ParsedApplyFeeInstruction<TProgram>)
+  | ({
+      instructionType: FloorSwapInstruction.UpdatePool;
+    } & ParsedUpdatePoolInstruction<TProgram>)
+  | ({
+      instructionType: FloorSwapInstruction.ProposeAuthority;
+    } & ParsedProposeAuthorityInstruction<TProgram>)
+  | ({
+      instructionType: FloorSwapInstruction.AcceptAuthority;
+    } & ParsedAcceptAuthorityInstruction<TProgram>)
+  | ({
+      instructionType: FloorSwapInstruction.SetRoles;
+    } & ParsedSetRolesInstruction<TProgram>)
+  | ({
+      instructionType: FloorSwapInstruction.SetMultisig;
+    } & ParsedSetMultisigInstruction<TProgram>)
+  | ({
+      instructionType: FloorSwapInstruction.InitGlobalConfig;
+    } & ParsedInitGlobalConfigInstruction<TProgram>)
+  | ({
+      instructionType: FloorSwapInstruction.UpdateGlobalConfig;
+    } & ParsedUpdateGlobalConfigInstruction<TProgram>)
+  | ({
+      instructionType: FloorSwapInstruction.PauseProgram;
+    } & ParsedPauseProgramInstruction<TProgram>)
+  | ({
+      instructionType: FloorSwapInstruction.Reconcile;
+    } & ParsedReconcileInstruction<TProgram>)
+  | ({
+      instructionType: FloorSwapInstruction.CloseAndDrain;
+    } & ParsedCloseAndDrainInstruction<TProgram>)
+  | ({
+      instructionType: FloorSwapInstruction.Migrate;
+    } & ParsedMigrateInstruction<TProgram>);
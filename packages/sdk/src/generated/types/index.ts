@@ -6,4 +6,11 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './feeModel';
+export * from './feeRecipient';
 export * from './key';
+export * from './multisig';
+export * from './pendingFee';
+export * from './poolMode';
+export * from './poolStatus';
+export * from './royaltyBase';
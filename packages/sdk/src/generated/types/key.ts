@@ -18,6 +18,10 @@ import {
 export enum Key {
   Uninitialized,
   Pool,
+  SwapCommit,
+  FeeVault,
+  GlobalConfig,
+  Inventory,
 }
 
 export type KeyArgs = Key;
@@ -0,0 +1,72 @@
+import {
+  Address,
+  address,
+  fetchEncodedAccount,
+  getAddressEncoder,
+} from '@solana/kit';
+import {
+  fetchInventoryFromSeeds,
+  fetchPool,
+  fetchSwapCommit,
+} from './generated';
+
+const SLOT_HASHES_SYSVAR_ADDRESS = address(
+  'SysvarS1otHashes111111111111111111111111111'
+);
+
+/**
+ * Finds the hash of the earliest slot after the given slot in the
+ * SlotHashes sysvar, mirroring the lookup done on chain.
+ */
+async function findSlotHashAfter(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  slot: bigint
+): Promise<Uint8Array | null> {
+  const account = await fetchEncodedAccount(rpc, SLOT_HASHES_SYSVAR_ADDRESS);
+  if (!account.exists) return null;
+  const { data } = account;
+  const view = new DataView(data.buffer, data.byteOffset, data.byteLength);
+  const count = Number(view.getBigUint64(0, true));
+
+  // Entries are stored newest first.
+  let found: Uint8Array | null = null;
+  for (let index = 0; index < count; index += 1) {
+    const offset = 8 + index * 40;
+    if (view.getBigUint64(offset, true) <= slot) return found;
+    found = data.slice(offset + 8, offset + 40);
+  }
+  return null;
+}
+
+/**
+ * Finds the asset a mystery swap reveals, so it can be passed as the
+ * `destAsset` account of `RevealSwap`. Returns `null` while older swaps of
+ * the pool are still open or the slot following the commit has not been
+ * hashed yet, and once it has expired.
+ */
+export async function findMysteryDestination(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  swapCommit: Address
+): Promise<Address | null> {
+  const { data } = await fetchSwapCommit(rpc, swapCommit);
+  // Swaps are revealed in the order they were committed.
+  const pool = await fetchPool(rpc, data.pool);
+  const nextCommit = pool.data.commitCount - BigInt(pool.data.openCommits);
+  if (data.ticket !== nextCommit) return null;
+
+  const slotHash = await findSlotHashAfter(rpc, data.slot);
+  if (!slotHash) return null;
+
+  const inventory = await fetchInventoryFromSeeds(rpc, { pool: data.pool });
+  const { assets } = inventory.data;
+  if (assets.length === 0) return null;
+
+  const seed = new Uint8Array(
+    await crypto.subtle.digest(
+      'SHA-256',
+      new Uint8Array([...slotHash, ...getAddressEncoder().encode(swapCommit)])
+    )
+  );
+  const index = new DataView(seed.buffer).getBigUint64(0, true);
+  return assets[Number(index % BigInt(assets.length))];
+}
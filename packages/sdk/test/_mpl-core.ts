@@ -15,13 +15,15 @@ import { DataState } from '@metaplex-foundation/mpl-core';
 import {
   getCreateCollectionV2Instruction,
   getCreateV2Instruction,
+  PluginAuthorityPairArgs,
 } from '../../mpl-core/generated';
 
 export async function createCoreCollection(
   client: Client,
   authority: KeyPairSigner,
   name = 'Test collection',
-  uri = 'https://bafybeih5rnavjmj4u4aslm6k5qtqznxzla2mthsf7p2c3lrk2wc33m2h3i.ipfs.w3s.link/collection.json'
+  uri = 'https://bafybeih5rnavjmj4u4aslm6k5qtqznxzla2mthsf7p2c3lrk2wc33m2h3i.ipfs.w3s.link/collection.json',
+  plugins: PluginAuthorityPairArgs[] = []
 ): Promise<Address> {
   const collection = await generateKeyPairSigner();
 
@@ -31,7 +33,7 @@ export async function createCoreCollection(
     payer: authority,
     name,
     uri,
-    plugins: [],
+    plugins,
     externalPluginAdapters: [],
   });
 
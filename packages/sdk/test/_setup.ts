@@ -1,6 +1,8 @@
 import {
+  AccountRole,
   Address,
   Commitment,
+  Instruction,
   TransactionSigner,
   TransactionMessageWithBlockhashLifetime,
   ProgramDerivedAddress,
@@ -10,6 +12,7 @@ import {
   SolanaRpcSubscriptionsApi,
   airdropFactory,
   appendTransactionMessageInstruction,
+  appendTransactionMessageInstructions,
   createSolanaRpc,
   createSolanaRpcSubscriptions,
   createTransactionMessage,
@@ -27,13 +30,26 @@ import {
   TransactionBlockhashLifetime,
   generateKeyPair,
   createSignerFromKeyPair,
+  createKeyPairSignerFromPrivateKeyBytes,
+  getAddressEncoder,
+  getProgramDerivedAddress,
+  Signature,
 } from '@solana/kit';
 import { createCoreAsset } from './_mpl-core';
 import {
+  FeeModelArgs,
+  feeModel,
+  findInventoryPda,
   findPoolPda,
+  FLOOR_SWAP_PROGRAM_ADDRESS,
+  getApplyFeeInstruction,
   getCreateInstructionAsync,
   getDepositInstruction,
-  getSetActiveInstruction,
+  getProposeFeeInstruction,
+  getSetModeInstruction,
+  getSetStatusInstruction,
+  PoolModeArgs,
+  PoolStatus,
 } from '../src';
 import { MPL_CORE_PROGRAM_PROGRAM_ADDRESS } from '../sdks/mpl-core/generated';
 
@@ -96,25 +112,85 @@ export const signAndSendTransaction = async (
   return signature;
 };
 
+export const withRemainingAccounts = <T extends Instruction>(
+  instruction: T,
+  addresses: Address[],
+  role: AccountRole = AccountRole.WRITABLE
+): T => ({
+  ...instruction,
+  accounts: [
+    ...(instruction.accounts ?? []),
+    ...addresses.map((address) => ({ address, role })),
+  ],
+});
+
+// The upgrade authority the local validator loads the program with.
+export const getLocalUpgradeAuthority = () =>
+  createKeyPairSignerFromPrivateKeyBytes(new Uint8Array(32).fill(1));
+
+export const findProgramDataAddress = async () =>
+  getProgramDerivedAddress({
+    programAddress:
+      'BPFLoaderUpgradeab1e11111111111111111111111' as Address<'BPFLoaderUpgradeab1e11111111111111111111111'>,
+    seeds: [getAddressEncoder().encode(FLOOR_SWAP_PROGRAM_ADDRESS)],
+  });
+
+export const withSigners = <T extends Instruction>(
+  instruction: T,
+  signers: TransactionSigner[]
+): T => ({
+  ...instruction,
+  accounts: [
+    ...(instruction.accounts ?? []),
+    ...signers.map((signer) => ({
+      address: signer.address,
+      role: AccountRole.READONLY_SIGNER,
+      signer,
+    })),
+  ],
+});
+
 export const getBalance = async (client: Client, address: Address) =>
   (await client.rpc.getBalance(address, { commitment: 'confirmed' }).send())
     .value;
 
+export const getComputeUnitsConsumed = async (
+  client: Client,
+  signature: Signature
+) => {
+  const transaction = await client.rpc
+    .getTransaction(signature, {
+      commitment: 'confirmed',
+      maxSupportedTransactionVersion: 0,
+    })
+    .send();
+  return transaction?.meta?.computeUnitsConsumed ?? 0n;
+};
+
 export const createPoolForAuthority = async (
   client: Client,
   authority: TransactionSigner,
   collection: Address,
   treasury: Address,
-  feeAmount = 10000000n // 0.01 sol
+  fee: FeeModelArgs = feeModel('Flat', { lamports: 10000000n }), // 0.01 sol
+  {
+    maxFee = 1_000_000_000n, // 1 sol
+    feeDelay = 0,
+    maxReferralBps = 0,
+  }: { maxFee?: bigint; feeDelay?: number; maxReferralBps?: number } = {}
 ): Promise<ProgramDerivedAddress> => {
   const [transaction, counterPda, createIx] = await Promise.all([
     createDefaultTransaction(client, authority),
-    findPoolPda({ authority: authority.address, collection }),
+    findPoolPda({ creator: authority.address, collection }),
     getCreateInstructionAsync({
       authority,
       collection,
       treasury,
-      feeAmount,
+      feeModel: fee,
+      feeMint: null,
+      maxFee,
+      feeDelay,
+      maxReferralBps,
     }),
   ]);
   await pipe(
@@ -133,12 +209,14 @@ export const createAndDepositAsset = async (
 ) => {
   const assetPk = await createCoreAsset(client, authority, collection);
 
+  const [inventory] = await findInventoryPda({ pool });
   await pipe(
     await createDefaultTransaction(client, authority),
     (tx) =>
       appendTransactionMessageInstruction(
         getDepositInstruction({
           pool,
+          inventory,
           collection,
           asset: assetPk,
           payer: authority,
@@ -152,23 +230,79 @@ export const createAndDepositAsset = async (
   return assetPk;
 };
 
-export const setPoolActive = async (
+export const setPoolStatus = async (
+  client: Client,
+  authority: TransactionSigner,
+  pool: Address,
+  status: PoolStatus
+) => {
+  await pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getSetStatusInstruction({
+          pool,
+          authority,
+          status,
+        }),
+        tx
+      ),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+};
+
+export const setPoolMode = async (
   client: Client,
   authority: TransactionSigner,
   pool: Address,
-  active: boolean
+  mode: PoolModeArgs
 ) => {
   await pipe(
     await createDefaultTransaction(client, authority),
     (tx) =>
       appendTransactionMessageInstruction(
-        getSetActiveInstruction({
+        getSetModeInstruction({
           pool,
           authority,
-          active,
+          mode,
         }),
         tx
       ),
     (tx) => signAndSendTransaction(client, tx)
   );
 };
+
+// Proposes and applies a fee at once, for pools created without a fee delay.
+export const setPoolFee = async (
+  client: Client,
+  authority: TransactionSigner,
+  pool: Address,
+  fee: FeeModelArgs,
+  feeMint: Address | null = null
+) => {
+  await pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstructions(
+        [
+          getProposeFeeInstruction({
+            pool,
+            authority,
+            feeModel: fee,
+            feeMint,
+          }),
+          getApplyFeeInstruction({ pool }),
+        ],
+        tx
+      ),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+};
+
+export const waitForSlot = async (client: Client, slot: bigint) => {
+  while (
+    (await client.rpc.getSlot({ commitment: 'confirmed' }).send()) < slot
+  ) {
+    await new Promise((resolve) => setTimeout(resolve, 200));
+  }
+};
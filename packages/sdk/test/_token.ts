@@ -0,0 +1,226 @@
+import {
+  AccountRole,
+  Address,
+  address,
+  appendTransactionMessageInstructions,
+  generateKeyPairSigner,
+  getAddressEncoder,
+  getOptionEncoder,
+  getStructEncoder,
+  getU16Encoder,
+  getU32Encoder,
+  getU64Encoder,
+  getU8Encoder,
+  Instruction,
+  KeyPairSigner,
+  none,
+  pipe,
+} from '@solana/kit';
+import {
+  Client,
+  createDefaultTransaction,
+  signAndSendTransaction,
+} from './_setup';
+
+// The SDK does not depend on the token programs, so the few instructions
+// tests need are encoded by hand.
+
+export const TOKEN_PROGRAM_ADDRESS = address(
+  'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'
+);
+export const TOKEN_2022_PROGRAM_ADDRESS = address(
+  'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb'
+);
+const SYSTEM_PROGRAM_ADDRESS = address('11111111111111111111111111111111');
+
+const MINT_SIZE = 82;
+const TOKEN_ACCOUNT_SIZE = 165;
+// Base account, account type, then a TLV entry for the transfer fee extension.
+const MINT_WITH_TRANSFER_FEE_SIZE = TOKEN_ACCOUNT_SIZE + 1 + 4 + 108;
+const TOKEN_ACCOUNT_WITH_TRANSFER_FEE_SIZE = TOKEN_ACCOUNT_SIZE + 1 + 4 + 8;
+
+const getCreateAccountInstruction = async (
+  client: Client,
+  payer: KeyPairSigner,
+  newAccount: KeyPairSigner,
+  space: number,
+  owner: Address
+): Promise<Instruction> => ({
+  programAddress: SYSTEM_PROGRAM_ADDRESS,
+  accounts: [
+    {
+      address: payer.address,
+      role: AccountRole.WRITABLE_SIGNER,
+      signer: payer,
+    },
+    {
+      address: newAccount.address,
+      role: AccountRole.WRITABLE_SIGNER,
+      signer: newAccount,
+    },
+  ] as Instruction['accounts'],
+  data: getStructEncoder([
+    ['discriminator', getU32Encoder()],
+    ['lamports', getU64Encoder()],
+    ['space', getU64Encoder()],
+    ['owner', getAddressEncoder()],
+  ]).encode({
+    discriminator: 0,
+    lamports: await client.rpc
+      .getMinimumBalanceForRentExemption(BigInt(space))
+      .send(),
+    space,
+    owner,
+  }),
+});
+
+export const createMint = async (
+  client: Client,
+  authority: KeyPairSigner,
+  {
+    tokenProgram = TOKEN_PROGRAM_ADDRESS,
+    decimals = 6,
+    transferFeeBps,
+  }: {
+    tokenProgram?: Address;
+    decimals?: number;
+    transferFeeBps?: number;
+  } = {}
+): Promise<Address> => {
+  const mint = await generateKeyPairSigner();
+  const instructions: Instruction[] = [
+    await getCreateAccountInstruction(
+      client,
+      authority,
+      mint,
+      transferFeeBps === undefined ? MINT_SIZE : MINT_WITH_TRANSFER_FEE_SIZE,
+      tokenProgram
+    ),
+  ];
+
+  if (transferFeeBps !== undefined) {
+    // Token-2022 InitializeTransferFeeConfig.
+    instructions.push({
+      programAddress: tokenProgram,
+      accounts: [{ address: mint.address, role: AccountRole.WRITABLE }],
+      data: getStructEncoder([
+        ['discriminator', getU8Encoder()],
+        ['extensionDiscriminator', getU8Encoder()],
+        ['configAuthority', getOptionEncoder(getAddressEncoder())],
+        ['withdrawAuthority', getOptionEncoder(getAddressEncoder())],
+        ['transferFeeBps', getU16Encoder()],
+        ['maximumFee', getU64Encoder()],
+      ]).encode({
+        discriminator: 26,
+        extensionDiscriminator: 0,
+        configAuthority: none(),
+        withdrawAuthority: none(),
+        transferFeeBps,
+        maximumFee: 2n ** 64n - 1n,
+      }),
+    });
+  }
+
+  // InitializeMint2.
+  instructions.push({
+    programAddress: tokenProgram,
+    accounts: [{ address: mint.address, role: AccountRole.WRITABLE }],
+    data: getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['decimals', getU8Encoder()],
+      ['mintAuthority', getAddressEncoder()],
+      ['freezeAuthority', getOptionEncoder(getAddressEncoder())],
+    ]).encode({
+      discriminator: 20,
+      decimals,
+      mintAuthority: authority.address,
+      freezeAuthority: none(),
+    }),
+  });
+
+  await pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) => appendTransactionMessageInstructions(instructions, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  return mint.address;
+};
+
+export const createTokenAccount = async (
+  client: Client,
+  mintAuthority: KeyPairSigner,
+  mint: Address,
+  owner: Address,
+  {
+    tokenProgram = TOKEN_PROGRAM_ADDRESS,
+    amount = 0n,
+    withTransferFee = false,
+  }: {
+    tokenProgram?: Address;
+    amount?: bigint;
+    withTransferFee?: boolean;
+  } = {}
+): Promise<Address> => {
+  const tokenAccount = await generateKeyPairSigner();
+  const instructions: Instruction[] = [
+    await getCreateAccountInstruction(
+      client,
+      mintAuthority,
+      tokenAccount,
+      withTransferFee
+        ? TOKEN_ACCOUNT_WITH_TRANSFER_FEE_SIZE
+        : TOKEN_ACCOUNT_SIZE,
+      tokenProgram
+    ),
+    // InitializeAccount3.
+    {
+      programAddress: tokenProgram,
+      accounts: [
+        { address: tokenAccount.address, role: AccountRole.WRITABLE },
+        { address: mint, role: AccountRole.READONLY },
+      ],
+      data: getStructEncoder([
+        ['discriminator', getU8Encoder()],
+        ['owner', getAddressEncoder()],
+      ]).encode({ discriminator: 18, owner }),
+    },
+  ];
+
+  if (amount > 0n) {
+    // MintTo.
+    instructions.push({
+      programAddress: tokenProgram,
+      accounts: [
+        { address: mint, role: AccountRole.WRITABLE },
+        { address: tokenAccount.address, role: AccountRole.WRITABLE },
+        {
+          address: mintAuthority.address,
+          role: AccountRole.READONLY_SIGNER,
+          signer: mintAuthority,
+        },
+      ] as Instruction['accounts'],
+      data: getStructEncoder([
+        ['discriminator', getU8Encoder()],
+        ['amount', getU64Encoder()],
+      ]).encode({ discriminator: 7, amount }),
+    });
+  }
+
+  await pipe(
+    await createDefaultTransaction(client, mintAuthority),
+    (tx) => appendTransactionMessageInstructions(instructions, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  return tokenAccount.address;
+};
+
+export const getTokenBalance = async (client: Client, tokenAccount: Address) =>
+  BigInt(
+    (
+      await client.rpc
+        .getTokenAccountBalance(tokenAccount, { commitment: 'confirmed' })
+        .send()
+    ).value.amount
+  );
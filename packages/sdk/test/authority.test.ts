@@ -0,0 +1,200 @@
+import test from 'ava';
+import {
+  Client,
+  createDefaultSolanaClient,
+  createDefaultTransaction,
+  createPoolForAuthority,
+  generateKeyPairSignerWithSol,
+  setPoolStatus,
+  signAndSendTransaction,
+} from './_setup';
+import { createCoreCollection } from './_mpl-core';
+import {
+  Account,
+  Address,
+  appendTransactionMessageInstruction,
+  generateKeyPairSigner,
+  isProgramError,
+  isSolanaError,
+  KeyPairSigner,
+  none,
+  pipe,
+  SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE,
+  some,
+} from '@solana/kit';
+import {
+  fetchPool,
+  fetchPoolFromSeeds,
+  FLOOR_SWAP_ERROR__ACCOUNT_MISMATCH,
+  FLOOR_SWAP_ERROR__NO_PENDING_AUTHORITY,
+  FLOOR_SWAP_PROGRAM_ADDRESS,
+  getAcceptAuthorityInstruction,
+  getProposeAuthorityInstruction,
+  Pool,
+  PoolStatus,
+} from '../src';
+
+const proposeAuthority = async (
+  client: Client,
+  authority: KeyPairSigner,
+  pool: Address,
+  newAuthority?: Address
+) => {
+  await pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getProposeAuthorityInstruction({ pool, authority, newAuthority }),
+        tx
+      ),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+};
+
+test('it can transfer the authority of a pool', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const authority = await generateKeyPairSignerWithSol(client);
+  const collection = await createCoreCollection(client, authority);
+  const treasury = (await generateKeyPairSigner()).address;
+  const [poolPda] = await createPoolForAuthority(
+    client,
+    authority,
+    collection,
+    treasury
+  );
+
+  const newAuthority = await generateKeyPairSignerWithSol(client);
+  await proposeAuthority(client, authority, poolPda, newAuthority.address);
+
+  t.like(await fetchPool(client.rpc, poolPda), <Account<Pool>>{
+    data: {
+      authority: authority.address,
+      pendingAuthority: some(newAuthority.address),
+    },
+  });
+
+  await pipe(
+    await createDefaultTransaction(client, newAuthority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getAcceptAuthorityInstruction({ pool: poolPda, newAuthority }),
+        tx
+      ),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  // The pool keeps its address, derived from the creator.
+  t.like(
+    await fetchPoolFromSeeds(client.rpc, {
+      creator: authority.address,
+      collection,
+    }),
+    <Account<Pool>>{
+      address: poolPda,
+      data: {
+        authority: newAuthority.address,
+        creator: authority.address,
+        pendingAuthority: none(),
+      },
+    }
+  );
+
+  // The new authority now manages the pool.
+  await setPoolStatus(client, newAuthority, poolPda, PoolStatus.Active);
+  t.like(await fetchPool(client.rpc, poolPda), <Account<Pool>>{
+    data: { status: PoolStatus.Active },
+  });
+});
+
+test('only the proposed authority can accept the pool', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const authority = await generateKeyPairSignerWithSol(client);
+  const collection = await createCoreCollection(client, authority);
+  const treasury = (await generateKeyPairSigner()).address;
+  const [poolPda] = await createPoolForAuthority(
+    client,
+    authority,
+    collection,
+    treasury
+  );
+
+  const newAuthority = (await generateKeyPairSigner()).address;
+  await proposeAuthority(client, authority, poolPda, newAuthority);
+
+  const attacker = await generateKeyPairSignerWithSol(client);
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, attacker),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getAcceptAuthorityInstruction({
+          pool: poolPda,
+          newAuthority: attacker,
+        }),
+        tx
+      )
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__ACCOUNT_MISMATCH
+    )
+  );
+});
+
+test('it cannot accept a cancelled authority transfer', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const authority = await generateKeyPairSignerWithSol(client);
+  const collection = await createCoreCollection(client, authority);
+  const treasury = (await generateKeyPairSigner()).address;
+  const [poolPda] = await createPoolForAuthority(
+    client,
+    authority,
+    collection,
+    treasury
+  );
+
+  const newAuthority = await generateKeyPairSignerWithSol(client);
+  await proposeAuthority(client, authority, poolPda, newAuthority.address);
+  // Proposing without a new authority cancels the transfer.
+  await proposeAuthority(client, authority, poolPda);
+
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, newAuthority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getAcceptAuthorityInstruction({ pool: poolPda, newAuthority }),
+        tx
+      )
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__NO_PENDING_AUTHORITY
+    )
+  );
+});
@@ -0,0 +1,195 @@
+import test from 'ava';
+import {
+  Client,
+  createAndDepositAsset,
+  createDefaultSolanaClient,
+  createDefaultTransaction,
+  createPoolForAuthority,
+  generateKeyPairSignerWithSol,
+  getBalance,
+  setPoolStatus,
+  signAndSendTransaction,
+} from './_setup';
+import { createCoreAsset, createCoreCollection } from './_mpl-core';
+import {
+  Account,
+  appendTransactionMessageInstruction,
+  isProgramError,
+  isSolanaError,
+  pipe,
+  SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE,
+} from '@solana/kit';
+import {
+  feeModel,
+  FeeVault,
+  fetchFeeVault,
+  findFeeVaultPda,
+  findInventoryPda,
+  FLOOR_SWAP_ERROR__ACCOUNT_MISMATCH,
+  FLOOR_SWAP_PROGRAM_ADDRESS,
+  getClaimFeesInstruction,
+  getSwapInstruction,
+  PoolStatus,
+} from '../src';
+import { MPL_CORE_PROGRAM_PROGRAM_ADDRESS } from '../sdks/mpl-core/generated';
+
+// Creates a pool and swaps once, leaving 0.1 sol of fees in its vault.
+const setup = async (client: Client) => {
+  const authority = await generateKeyPairSignerWithSol(client);
+  const collection = await createCoreCollection(client, authority);
+  const treasury = await generateKeyPairSignerWithSol(client);
+  const [poolPda] = await createPoolForAuthority(
+    client,
+    authority,
+    collection,
+    treasury.address,
+    feeModel('Flat', { lamports: 100_000_000n })
+  );
+
+  const destAssetPk = await createAndDepositAsset(
+    client,
+    authority,
+    collection,
+    poolPda
+  );
+
+  const payer = await generateKeyPairSignerWithSol(client);
+
+  const sourceAssetPk = await createCoreAsset(
+    client,
+    authority,
+    collection,
+    payer.address
+  );
+
+  await setPoolStatus(client, authority, poolPda, PoolStatus.Active);
+
+  const [feeVault] = await findFeeVaultPda({ pool: poolPda });
+
+  const [inventory] = await findInventoryPda({ pool: poolPda });
+  await pipe(
+    await createDefaultTransaction(client, payer),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getSwapInstruction({
+          pool: poolPda,
+          inventory,
+          collection,
+          sourceAsset: sourceAssetPk,
+          destAsset: destAssetPk,
+          payer,
+          feeVault,
+          coreProgram: MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+          maxFee: 100_000_000n,
+        }),
+        tx
+      ),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  return { authority, treasury, payer, poolPda, feeVault };
+};
+
+test('the authority can claim fees to the treasury', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const { authority, treasury, poolPda, feeVault } = await setup(client);
+
+  const treasuryBalance = await getBalance(client, treasury.address);
+  const feeVaultBalance = await getBalance(client, feeVault);
+
+  await pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getClaimFeesInstruction({
+          pool: poolPda,
+          feeVault,
+          claimer: authority,
+          treasury: treasury.address,
+        }),
+        tx
+      ),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  t.deepEqual(
+    await getBalance(client, treasury.address),
+    treasuryBalance + 100_000_000n
+  );
+  t.deepEqual(
+    await getBalance(client, feeVault),
+    feeVaultBalance - 100_000_000n
+  );
+  // Claiming does not reset the lifetime total.
+  t.like(await fetchFeeVault(client.rpc, feeVault), <Account<FeeVault>>{
+    data: {
+      lifetimeFees: 100_000_000n,
+    },
+  });
+});
+
+test('the treasury can claim its own fees', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const { treasury, poolPda, feeVault } = await setup(client);
+
+  const treasuryBalance = await getBalance(client, treasury.address);
+
+  await pipe(
+    await createDefaultTransaction(client, treasury),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getClaimFeesInstruction({
+          pool: poolPda,
+          feeVault,
+          claimer: treasury,
+          treasury: treasury.address,
+        }),
+        tx
+      ),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  t.deepEqual(
+    await getBalance(client, treasury.address),
+    treasuryBalance + 100_000_000n - 5000n
+  );
+});
+
+test('it cannot claim fees as another signer', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const { treasury, payer, poolPda, feeVault } = await setup(client);
+
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, payer),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getClaimFeesInstruction({
+          pool: poolPda,
+          feeVault,
+          claimer: payer,
+          treasury: treasury.address,
+        }),
+        tx
+      )
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__ACCOUNT_MISMATCH
+    )
+  );
+});
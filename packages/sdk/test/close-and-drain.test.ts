@@ -0,0 +1,167 @@
+import test from 'ava';
+import {
+  createAndDepositAsset,
+  createDefaultSolanaClient,
+  createDefaultTransaction,
+  createPoolForAuthority,
+  generateKeyPairSignerWithSol,
+  getBalance,
+  setPoolStatus,
+  signAndSendTransaction,
+  withRemainingAccounts,
+} from './_setup';
+import { createCoreCollection } from './_mpl-core';
+import {
+  Account,
+  appendTransactionMessageInstruction,
+  fetchEncodedAccount,
+  generateKeyPairSigner,
+  isProgramError,
+  isSolanaError,
+  pipe,
+  SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE,
+} from '@solana/kit';
+import {
+  findFeeVaultPda,
+  findInventoryPda,
+  FLOOR_SWAP_ERROR__POOL_NOT_EMPTY,
+  FLOOR_SWAP_PROGRAM_ADDRESS,
+  getCloseAndDrainInstruction,
+  PoolStatus,
+} from '../src';
+import {
+  AssetV1,
+  fetchAssetV1,
+  MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+} from '../sdks/mpl-core/generated';
+
+test('it can withdraw every asset and close a pool at once', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const authority = await generateKeyPairSignerWithSol(client);
+  const collection = await createCoreCollection(client, authority);
+  const treasury = (await generateKeyPairSigner()).address;
+  const [poolPda] = await createPoolForAuthority(
+    client,
+    authority,
+    collection,
+    treasury
+  );
+  const assetPks = [
+    await createAndDepositAsset(client, authority, collection, poolPda),
+    await createAndDepositAsset(client, authority, collection, poolPda),
+  ];
+
+  await setPoolStatus(client, authority, poolPda, PoolStatus.Closing);
+
+  const [destination, rentReceiver] = await Promise.all([
+    generateKeyPairSigner(),
+    generateKeyPairSigner(),
+  ]);
+  const [feeVault] = await findFeeVaultPda({ pool: poolPda });
+  const [inventory] = await findInventoryPda({ pool: poolPda });
+  const rent =
+    (await getBalance(client, poolPda)) +
+    (await getBalance(client, feeVault)) +
+    (await getBalance(client, inventory));
+
+  await pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        withRemainingAccounts(
+          getCloseAndDrainInstruction({
+            pool: poolPda,
+            feeVault,
+            inventory,
+            authority,
+            collection,
+            destination: destination.address,
+            rentReceiver: rentReceiver.address,
+            coreProgram: MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+          }),
+          assetPks
+        ),
+        tx
+      ),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  for (const assetPk of assetPks) {
+    t.like(await fetchAssetV1(client.rpc, assetPk), <Account<AssetV1>>{
+      data: { owner: destination.address },
+    });
+  }
+
+  const [poolAcc, feeVaultAcc, inventoryAcc] = await Promise.all([
+    fetchEncodedAccount(client.rpc, poolPda),
+    fetchEncodedAccount(client.rpc, feeVault),
+    fetchEncodedAccount(client.rpc, inventory),
+  ]);
+  t.false(poolAcc.exists);
+  t.false(feeVaultAcc.exists);
+  t.false(inventoryAcc.exists);
+
+  t.is(await getBalance(client, rentReceiver.address), rent);
+});
+
+test('it cannot close a pool leaving assets behind', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const authority = await generateKeyPairSignerWithSol(client);
+  const collection = await createCoreCollection(client, authority);
+  const treasury = (await generateKeyPairSigner()).address;
+  const [poolPda] = await createPoolForAuthority(
+    client,
+    authority,
+    collection,
+    treasury
+  );
+  const assetPk = await createAndDepositAsset(
+    client,
+    authority,
+    collection,
+    poolPda
+  );
+  await createAndDepositAsset(client, authority, collection, poolPda);
+
+  await setPoolStatus(client, authority, poolPda, PoolStatus.Closing);
+
+  const [feeVault] = await findFeeVaultPda({ pool: poolPda });
+  const [inventory] = await findInventoryPda({ pool: poolPda });
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        withRemainingAccounts(
+          getCloseAndDrainInstruction({
+            pool: poolPda,
+            feeVault,
+            inventory,
+            authority,
+            collection,
+            coreProgram: MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+          }),
+          [assetPk]
+        ),
+        tx
+      )
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__POOL_NOT_EMPTY
+    )
+  );
+});
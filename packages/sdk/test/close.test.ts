@@ -6,24 +6,37 @@ import {
   createPoolForAuthority,
   generateKeyPairSignerWithSol,
   getBalance,
+  setPoolStatus,
   signAndSendTransaction,
+  withRemainingAccounts,
 } from './_setup';
 import { createCoreAsset, createCoreCollection } from './_mpl-core';
 import {
+  AccountRole,
+  airdropFactory,
   appendTransactionMessageInstruction,
   appendTransactionMessageInstructions,
   fetchEncodedAccount,
   generateKeyPairSigner,
   isProgramError,
   isSolanaError,
+  lamports,
   pipe,
   SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE,
 } from '@solana/kit';
 import {
+  findFeeVaultPda,
+  findInventoryPda,
+  FLOOR_SWAP_ERROR__INVALID_PROGRAM_OWNER,
+  FLOOR_SWAP_ERROR__POOL_INACTIVE,
   FLOOR_SWAP_ERROR__POOL_NOT_EMPTY,
+  FLOOR_SWAP_ERROR__UNCLAIMED_FEES,
   FLOOR_SWAP_PROGRAM_ADDRESS,
   getCloseInstruction,
+  getReconcileInstruction,
+  getSetStatusInstruction,
   getWithdrawInstruction,
+  PoolStatus,
 } from '../src';
 import {
   fetchAssetV1,
@@ -44,9 +57,15 @@ test('it can close an empty pool', async (t) => {
     treasury
   );
 
+  await setPoolStatus(client, authority, poolPda, PoolStatus.Closing);
+
+  const [feeVault] = await findFeeVaultPda({ pool: poolPda });
+  const [inventory] = await findInventoryPda({ pool: poolPda });
   const balanceBefore = await getBalance(client, authority.address);
-  const pool = await fetchEncodedAccount(client.rpc, poolPda);
-  const rent = pool.exists ? pool.lamports : 0n;
+  const rent =
+    (await getBalance(client, poolPda)) +
+    (await getBalance(client, feeVault)) +
+    (await getBalance(client, inventory));
 
   await pipe(
     await createDefaultTransaction(client, authority),
@@ -54,6 +73,8 @@ test('it can close an empty pool', async (t) => {
       appendTransactionMessageInstruction(
         getCloseInstruction({
           pool: poolPda,
+          inventory,
+          feeVault,
           authority,
         }),
         tx
@@ -62,8 +83,12 @@ test('it can close an empty pool', async (t) => {
   );
 
   const acc = await fetchEncodedAccount(client.rpc, poolPda);
+  const feeVaultAcc = await fetchEncodedAccount(client.rpc, feeVault);
+  const inventoryAcc = await fetchEncodedAccount(client.rpc, inventory);
 
   t.deepEqual(acc.exists, false);
+  t.deepEqual(feeVaultAcc.exists, false);
+  t.deepEqual(inventoryAcc.exists, false);
 
   const balanceAfter = await getBalance(client, authority.address);
 
@@ -84,13 +109,18 @@ test('it cannot close a pool containing assets', async (t) => {
   );
 
   await createAndDepositAsset(client, authority, collection, poolPda);
+  await setPoolStatus(client, authority, poolPda, PoolStatus.Closing);
 
+  const [feeVault] = await findFeeVaultPda({ pool: poolPda });
+  const [inventory] = await findInventoryPda({ pool: poolPda });
   const transactionMessage = pipe(
     await createDefaultTransaction(client, authority),
     (tx) =>
       appendTransactionMessageInstruction(
         getCloseInstruction({
           pool: poolPda,
+          inventory,
+          feeVault,
           authority,
         }),
         tx
@@ -116,6 +146,110 @@ test('it cannot close a pool containing assets', async (t) => {
   );
 });
 
+test('it cannot close a pool that is not closing', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const authority = await generateKeyPairSignerWithSol(client);
+  const collection = await createCoreCollection(client, authority);
+  const treasury = (await generateKeyPairSigner()).address;
+  const [poolPda] = await createPoolForAuthority(
+    client,
+    authority,
+    collection,
+    treasury
+  );
+
+  await setPoolStatus(client, authority, poolPda, PoolStatus.Active);
+
+  const [feeVault] = await findFeeVaultPda({ pool: poolPda });
+  const [inventory] = await findInventoryPda({ pool: poolPda });
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getCloseInstruction({
+          pool: poolPda,
+          inventory,
+          feeVault,
+          authority,
+        }),
+        tx
+      )
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__POOL_INACTIVE
+    )
+  );
+});
+
+test('it cannot close a pool with unclaimed fees', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const authority = await generateKeyPairSignerWithSol(client);
+  const collection = await createCoreCollection(client, authority);
+  const treasury = (await generateKeyPairSigner()).address;
+  const [poolPda] = await createPoolForAuthority(
+    client,
+    authority,
+    collection,
+    treasury
+  );
+
+  await setPoolStatus(client, authority, poolPda, PoolStatus.Closing);
+
+  const [feeVault] = await findFeeVaultPda({ pool: poolPda });
+  await airdropFactory(client)({
+    recipientAddress: feeVault,
+    lamports: lamports(10_000_000n),
+    commitment: 'confirmed',
+  });
+
+  const [inventory] = await findInventoryPda({ pool: poolPda });
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getCloseInstruction({
+          pool: poolPda,
+          inventory,
+          feeVault,
+          authority,
+        }),
+        tx
+      )
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__UNCLAIMED_FEES
+    )
+  );
+});
+
 test('it can reopen a pool and claim manually sent assets if closed', async (t) => {
   t.timeout(30000);
   const client = createDefaultSolanaClient();
@@ -131,6 +265,8 @@ test('it can reopen a pool and claim manually sent assets if closed', async (t)
 
   const assetPk = await createCoreAsset(client, authority, collection);
 
+  const [feeVault] = await findFeeVaultPda({ pool: poolPda });
+  const [inventory] = await findInventoryPda({ pool: poolPda });
   await pipe(
     await createDefaultTransaction(client, authority),
     (tx) =>
@@ -144,8 +280,15 @@ test('it can reopen a pool and claim manually sent assets if closed', async (t)
             collection,
             compressionProof: null,
           }),
+          getSetStatusInstruction({
+            pool: poolPda,
+            authority,
+            status: PoolStatus.Closing,
+          }),
           getCloseInstruction({
             pool: poolPda,
+            inventory,
+            feeVault,
             authority,
           }),
         ],
@@ -167,14 +310,27 @@ test('it can reopen a pool and claim manually sent assets if closed', async (t)
   await pipe(
     await createDefaultTransaction(client, authority),
     (tx) =>
-      appendTransactionMessageInstruction(
-        getWithdrawInstruction({
-          pool: poolPda,
-          collection,
-          asset: assetPk,
-          authority,
-          coreProgram: MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
-        }),
+      appendTransactionMessageInstructions(
+        [
+          withRemainingAccounts(
+            getReconcileInstruction({
+              pool: poolPda,
+              inventory,
+              collection,
+              payer: authority,
+            }),
+            [assetPk],
+            AccountRole.READONLY
+          ),
+          getWithdrawInstruction({
+            pool: poolPda,
+            inventory,
+            collection,
+            asset: assetPk,
+            authority,
+            coreProgram: MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+          }),
+        ],
         tx
       ),
     (tx) => signAndSendTransaction(client, tx)
@@ -184,3 +340,43 @@ test('it can reopen a pool and claim manually sent assets if closed', async (t)
 
   t.deepEqual(asset.data.owner, authority.address);
 });
+
+test('it cannot close an account not owned by the program', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const authority = await generateKeyPairSignerWithSol(client);
+  const spoofedPool = (await generateKeyPairSigner()).address;
+
+  const [feeVault] = await findFeeVaultPda({ pool: spoofedPool });
+  const [spoofedInventory] = await findInventoryPda({ pool: spoofedPool });
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getCloseInstruction({
+          pool: spoofedPool,
+          inventory: spoofedInventory,
+          feeVault,
+          authority,
+        }),
+        tx
+      )
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__INVALID_PROGRAM_OWNER
+    )
+  );
+});
@@ -0,0 +1,282 @@
+import {
+  Address,
+  appendTransactionMessageInstruction,
+  generateKeyPairSigner,
+  KeyPairSigner,
+  pipe,
+} from '@solana/kit';
+import test from 'ava';
+import {
+  feeModel,
+  findFeeVaultPda,
+  findIndexedPoolPda,
+  findInventoryPda,
+  getClaimFeesInstruction,
+  getCloseInstruction,
+  getCreateInstructionAsync,
+  getSwapInstruction,
+  getWithdrawInstruction,
+  PoolStatus,
+} from '../src';
+import {
+  Client,
+  createAndDepositAsset,
+  createDefaultSolanaClient,
+  createDefaultTransaction,
+  createPoolForAuthority,
+  generateKeyPairSignerWithSol,
+  getComputeUnitsConsumed,
+  setPoolStatus,
+  signAndSendTransaction,
+} from './_setup';
+import { createCoreAsset, createCoreCollection } from './_mpl-core';
+import { MPL_CORE_PROGRAM_PROGRAM_ADDRESS } from '../sdks/mpl-core/generated';
+
+// Pool PDAs are verified against their stored bump, so these numbers
+// should not depend on how many bumps it takes to find the pool address.
+test('it reports compute units used by swap, withdraw and close', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const authority = await generateKeyPairSignerWithSol(client);
+  const collection = await createCoreCollection(client, authority);
+  const treasury = (await generateKeyPairSigner()).address;
+  const [poolPda] = await createPoolForAuthority(
+    client,
+    authority,
+    collection,
+    treasury
+  );
+
+  const destAssetPk = await createAndDepositAsset(
+    client,
+    authority,
+    collection,
+    poolPda
+  );
+
+  const payer = await generateKeyPairSignerWithSol(client);
+
+  const sourceAssetPk = await createCoreAsset(
+    client,
+    authority,
+    collection,
+    payer.address
+  );
+
+  await setPoolStatus(client, authority, poolPda, PoolStatus.Active);
+
+  const [feeVault] = await findFeeVaultPda({ pool: poolPda });
+  const [inventory] = await findInventoryPda({ pool: poolPda });
+  const swapSignature = await pipe(
+    await createDefaultTransaction(client, payer),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getSwapInstruction({
+          pool: poolPda,
+          inventory,
+          collection,
+          sourceAsset: sourceAssetPk,
+          destAsset: destAssetPk,
+          payer,
+          feeVault,
+          coreProgram: MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+          maxFee: 10000000n,
+        }),
+        tx
+      ),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  const withdrawSignature = await pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getWithdrawInstruction({
+          pool: poolPda,
+          inventory,
+          collection,
+          asset: sourceAssetPk,
+          authority,
+          coreProgram: MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+        }),
+        tx
+      ),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  // The fee vault must be emptied before the pool can be closed.
+  await pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getClaimFeesInstruction({
+          pool: poolPda,
+          feeVault,
+          claimer: authority,
+          treasury,
+        }),
+        tx
+      ),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  await setPoolStatus(client, authority, poolPda, PoolStatus.Closing);
+
+  const closeSignature = await pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getCloseInstruction({
+          pool: poolPda,
+          inventory,
+          feeVault,
+          authority,
+        }),
+        tx
+      ),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  const [swapUnits, withdrawUnits, closeUnits] = await Promise.all([
+    getComputeUnitsConsumed(client, swapSignature),
+    getComputeUnitsConsumed(client, withdrawSignature),
+    getComputeUnitsConsumed(client, closeSignature),
+  ]);
+
+  t.log(`swap: ${swapUnits} CU`);
+  t.log(`withdraw: ${withdrawUnits} CU`);
+  t.log(`close: ${closeUnits} CU`);
+
+  t.true(swapUnits > 0n);
+  t.true(withdrawUnits > 0n);
+  t.true(closeUnits > 0n);
+});
+
+// find_program_address charges 1500 CU for every bump it tries from 255.
+const BUMP_ATTEMPT_UNITS = 1500n;
+
+const createIndexedPool = async (
+  client: Client,
+  authority: KeyPairSigner,
+  collection: Address,
+  treasury: Address,
+  index: number
+) => {
+  const [pool, bump] = await findIndexedPoolPda({
+    creator: authority.address,
+    collection,
+    index,
+  });
+  const createIx = await getCreateInstructionAsync({
+    pool,
+    authority,
+    collection,
+    treasury,
+    feeModel: feeModel('Flat', { lamports: 10000000n }),
+    feeMint: null,
+    maxFee: 1_000_000_000n,
+    feeDelay: 0,
+    index,
+  });
+  await pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) => appendTransactionMessageInstruction(createIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+  return { pool, bump };
+};
+
+const measureWithdraw = async (
+  client: Client,
+  authority: KeyPairSigner,
+  collection: Address,
+  pool: Address
+) => {
+  const assetPk = await createAndDepositAsset(
+    client,
+    authority,
+    collection,
+    pool
+  );
+  const [inventory] = await findInventoryPda({ pool });
+  const signature = await pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getWithdrawInstruction({
+          pool,
+          inventory,
+          collection,
+          asset: assetPk,
+          authority,
+          coreProgram: MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+        }),
+        tx
+      ),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+  return getComputeUnitsConsumed(client, signature);
+};
+
+test('the stored bump saves the bump search on low-bump pools', async (t) => {
+  t.timeout(60000);
+  const client = createDefaultSolanaClient();
+  const authority = await generateKeyPairSignerWithSol(client);
+  const collection = await createCoreCollection(client, authority);
+  const treasury = (await generateKeyPairSigner()).address;
+
+  // Pick a pool index whose address has bump 255 and one whose address
+  // takes at least three attempts to find.
+  const bumps = await Promise.all(
+    Array.from({ length: 64 }, async (_, i) => {
+      const [, bump] = await findIndexedPoolPda({
+        creator: authority.address,
+        collection,
+        index: i + 1,
+      });
+      return bump;
+    })
+  );
+  const highIndex = bumps.findIndex((bump) => bump === 255) + 1;
+  const lowIndex = bumps.findIndex((bump) => bump <= 253) + 1;
+  t.true(highIndex > 0 && lowIndex > 0);
+
+  const high = await createIndexedPool(
+    client,
+    authority,
+    collection,
+    treasury,
+    highIndex
+  );
+  const low = await createIndexedPool(
+    client,
+    authority,
+    collection,
+    treasury,
+    lowIndex
+  );
+
+  const highUnits = await measureWithdraw(
+    client,
+    authority,
+    collection,
+    high.pool
+  );
+  const lowUnits = await measureWithdraw(
+    client,
+    authority,
+    collection,
+    low.pool
+  );
+
+  // Searching for the bump would have cost the low-bump pool this much more.
+  const searchUnits = BUMP_ATTEMPT_UNITS * BigInt(255 - low.bump);
+  t.log(`withdraw, bump 255: ${highUnits} CU`);
+  t.log(`withdraw, bump ${low.bump}: ${lowUnits} CU`);
+  t.log(`bump search saved on bump ${low.bump}: ${searchUnits} CU`);
+
+  // With the stored bump, both pools pay a single address check.
+  const difference =
+    lowUnits > highUnits ? lowUnits - highUnits : highUnits - lowUnits;
+  t.true(difference < BUMP_ATTEMPT_UNITS);
+});
@@ -1,9 +1,12 @@
 import {
   Account,
+  airdropFactory,
   appendTransactionMessageInstruction,
   generateKeyPairSigner,
   isProgramError,
   isSolanaError,
+  lamports,
+  none,
   pipe,
   SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE,
 } from '@solana/kit';
@@ -11,14 +14,22 @@ import test from 'ava';
 import {
   FLOOR_SWAP_ERROR__EXPECTED_MPL_CORE_COLLECTION,
   FLOOR_SWAP_ERROR__INVALID_PROGRAM_OWNER,
+  FLOOR_SWAP_ERROR__INVALID_REFERRAL_FEE,
+  FLOOR_SWAP_ERROR__POOL_ALREADY_EXISTS,
   FLOOR_SWAP_PROGRAM_ADDRESS,
   Pool,
+  PoolStatus,
+  feeModel,
+  fetchPool,
   fetchPoolFromSeeds,
+  findIndexedPoolPda,
+  findPoolPda,
   getCreateInstructionAsync,
 } from '../src';
 import {
   createDefaultSolanaClient,
   createDefaultTransaction,
+  createPoolForAuthority,
   generateKeyPairSignerWithSol,
   signAndSendTransaction,
 } from './_setup';
@@ -32,14 +43,17 @@ test('it creates a new pool account', async (t) => {
   const treasury = (await generateKeyPairSigner()).address;
 
   const collection = await createCoreCollection(client, authority);
-  const feeAmount = 10000000n; // 0.01 sol
+  const flatFee = feeModel('Flat', { lamports: 10000000n }); // 0.01 sol
 
   // When we create a new counter account.
   const createIx = await getCreateInstructionAsync({
     authority,
     collection,
     treasury,
-    feeAmount, // 0.01 sol
+    feeModel: flatFee,
+    feeMint: null,
+    maxFee: 100000000n, // 0.1 sol
+    feeDelay: 86400, // 1 day
   });
   await pipe(
     await createDefaultTransaction(client, authority),
@@ -49,16 +63,28 @@ test('it creates a new pool account', async (t) => {
 
   // Then we expect the counter account to exist and have a value of 0.
   const pool = await fetchPoolFromSeeds(client.rpc, {
-    authority: authority.address,
+    creator: authority.address,
+    collection,
+  });
+  const [, bump] = await findPoolPda({
+    creator: authority.address,
     collection,
   });
   t.like(pool, <Account<Pool>>{
     data: {
       authority: authority.address,
+      creator: authority.address,
+      pendingAuthority: none(),
       collection,
       treasury,
-      feeAmount,
-      enabled: false,
+      feeModel: flatFee,
+      feeMint: none(),
+      maxFee: 100000000n,
+      feeDelay: 86400,
+      pendingFee: none(),
+      referencePrice: 0n,
+      status: PoolStatus.Pending,
+      bump,
     },
   });
 });
@@ -71,13 +97,16 @@ test('it cannot create a new pool account with an invalid collection', async (t)
   const treasury = (await generateKeyPairSigner()).address;
 
   const collection = (await generateKeyPairSigner()).address;
-  const feeAmount = 10000000n; // 0.01 sol
+  const flatFee = feeModel('Flat', { lamports: 10000000n }); // 0.01 sol
 
   const createIx = await getCreateInstructionAsync({
     authority,
     collection,
     treasury,
-    feeAmount, // 0.01 sol
+    feeModel: flatFee,
+    feeMint: null,
+    maxFee: 100000000n, // 0.1 sol
+    feeDelay: 86400, // 1 day
   });
   const transactionMessage = pipe(
     await createDefaultTransaction(client, authority),
@@ -111,13 +140,16 @@ test('it cannot create a new pool account with an asset instead of a collection'
   const treasury = (await generateKeyPairSigner()).address;
 
   const asset = await createCoreAsset(client, authority);
-  const feeAmount = 10000000n; // 0.01 sol
+  const flatFee = feeModel('Flat', { lamports: 10000000n }); // 0.01 sol
 
   const createIx = await getCreateInstructionAsync({
     authority,
     collection: asset,
     treasury,
-    feeAmount, // 0.01 sol
+    feeModel: flatFee,
+    feeMint: null,
+    maxFee: 100000000n, // 0.1 sol
+    feeDelay: 86400, // 1 day
   });
   const transactionMessage = pipe(
     await createDefaultTransaction(client, authority),
@@ -142,3 +174,213 @@ test('it cannot create a new pool account with an asset instead of a collection'
     )
   );
 });
+
+test('it creates several pools for the same collection by index', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const authority = await generateKeyPairSignerWithSol(client);
+  const treasury = (await generateKeyPairSigner()).address;
+  const collection = await createCoreCollection(client, authority);
+
+  // Given a first pool, which has index 0.
+  const [firstPool] = await createPoolForAuthority(
+    client,
+    authority,
+    collection,
+    treasury
+  );
+
+  // When we create a premium pool with index 1 on the same collection.
+  const [premiumPool] = await findIndexedPoolPda({
+    creator: authority.address,
+    collection,
+    index: 1,
+  });
+  const createIx = await getCreateInstructionAsync({
+    pool: premiumPool,
+    authority,
+    collection,
+    treasury,
+    feeModel: feeModel('Flat', { lamports: 50000000n }), // 0.05 sol
+    feeMint: null,
+    maxFee: 100000000n, // 0.1 sol
+    feeDelay: 0,
+    index: 1,
+  });
+  await pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) => appendTransactionMessageInstruction(createIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  // Then both pools exist side by side.
+  t.like(await fetchPool(client.rpc, firstPool), <Account<Pool>>{
+    data: { index: 0, collection },
+  });
+  t.like(await fetchPool(client.rpc, premiumPool), <Account<Pool>>{
+    data: { index: 1, collection },
+  });
+});
+
+test('it cannot create a pool that already exists', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const authority = await generateKeyPairSignerWithSol(client);
+  const treasury = (await generateKeyPairSigner()).address;
+  const collection = await createCoreCollection(client, authority);
+  await createPoolForAuthority(client, authority, collection, treasury);
+
+  // When we create the pool with index 0 again.
+  const createIx = await getCreateInstructionAsync({
+    authority,
+    collection,
+    treasury,
+    feeModel: feeModel('Flat', { lamports: 20000000n }), // 0.02 sol
+    feeMint: null,
+    maxFee: 100000000n, // 0.1 sol
+    feeDelay: 0,
+  });
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) => appendTransactionMessageInstruction(createIx, tx)
+  );
+
+  // Then we expect a program error.
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__POOL_ALREADY_EXISTS
+    )
+  );
+});
+
+test('it creates a pool whose address already holds lamports', async (t) => {
+  t.timeout(30000);
+  // Given a pool address someone sent lamports to beforehand.
+  const client = createDefaultSolanaClient();
+  const authority = await generateKeyPairSignerWithSol(client);
+  const treasury = (await generateKeyPairSigner()).address;
+  const collection = await createCoreCollection(client, authority);
+  const [poolPda] = await findPoolPda({
+    creator: authority.address,
+    collection,
+  });
+  await airdropFactory(client)({
+    recipientAddress: poolPda,
+    lamports: lamports(1_000_000n),
+    commitment: 'confirmed',
+  });
+
+  // When we create the pool.
+  await createPoolForAuthority(client, authority, collection, treasury);
+
+  // Then we expect the pool to be created.
+  t.like(await fetchPool(client.rpc, poolPda), <Account<Pool>>{
+    data: { authority: authority.address, collection, treasury },
+  });
+});
+
+test('it cannot create a pool twice at an address funded beforehand', async (t) => {
+  t.timeout(30000);
+  // Given a pool created at an address someone sent lamports to beforehand.
+  const client = createDefaultSolanaClient();
+  const authority = await generateKeyPairSignerWithSol(client);
+  const treasury = (await generateKeyPairSigner()).address;
+  const collection = await createCoreCollection(client, authority);
+  const [poolPda] = await findPoolPda({
+    creator: authority.address,
+    collection,
+  });
+  await airdropFactory(client)({
+    recipientAddress: poolPda,
+    lamports: lamports(1_000_000n),
+    commitment: 'confirmed',
+  });
+  await createPoolForAuthority(client, authority, collection, treasury);
+
+  // When we create the pool with index 0 again.
+  const createIx = await getCreateInstructionAsync({
+    authority,
+    collection,
+    treasury,
+    feeModel: feeModel('Flat', { lamports: 20000000n }), // 0.02 sol
+    feeMint: null,
+    maxFee: 100000000n, // 0.1 sol
+    feeDelay: 0,
+  });
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) => appendTransactionMessageInstruction(createIx, tx)
+  );
+
+  // Then we expect a program error.
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__POOL_ALREADY_EXISTS
+    )
+  );
+});
+
+test('it cannot create a pool with a maximum referral fee above the limit', async (t) => {
+  t.timeout(30000);
+  // Given an authority key pair with some SOL.
+  const client = createDefaultSolanaClient();
+  const authority = await generateKeyPairSignerWithSol(client);
+  const treasury = (await generateKeyPairSigner()).address;
+  const collection = await createCoreCollection(client, authority);
+
+  // When we create a pool that could pay over half of its fee to referrers.
+  const createIx = await getCreateInstructionAsync({
+    authority,
+    collection,
+    treasury,
+    feeModel: feeModel('Flat', { lamports: 20000000n }), // 0.02 sol
+    feeMint: null,
+    maxFee: 100000000n, // 0.1 sol
+    feeDelay: 0,
+    maxReferralBps: 5001,
+  });
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) => appendTransactionMessageInstruction(createIx, tx)
+  );
+
+  // Then we expect a program error.
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__INVALID_REFERRAL_FEE
+    )
+  );
+});
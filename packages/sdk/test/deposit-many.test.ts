@@ -0,0 +1,192 @@
+import test from 'ava';
+import {
+  createDefaultSolanaClient,
+  createDefaultTransaction,
+  createPoolForAuthority,
+  generateKeyPairSignerWithSol,
+  signAndSendTransaction,
+  withRemainingAccounts,
+} from './_setup';
+import { createCoreAsset, createCoreCollection } from './_mpl-core';
+import {
+  Account,
+  appendTransactionMessageInstruction,
+  generateKeyPairSigner,
+  isProgramError,
+  isSolanaError,
+  pipe,
+  SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE,
+} from '@solana/kit';
+import {
+  fetchPool,
+  findInventoryPda,
+  FLOOR_SWAP_ERROR__INVALID_COLLECTION_FOR_ASSET,
+  FLOOR_SWAP_ERROR__INVALID_REMAINING_ACCOUNTS,
+  FLOOR_SWAP_PROGRAM_ADDRESS,
+  getDepositManyInstruction,
+  Pool,
+} from '../src';
+import {
+  AssetV1,
+  fetchAssetV1,
+  MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+} from '../sdks/mpl-core/generated';
+
+test('it can deposit several assets into the pool', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const authority = await generateKeyPairSignerWithSol(client);
+  const collection = await createCoreCollection(client, authority);
+  const treasury = (await generateKeyPairSigner()).address;
+  const [poolPda] = await createPoolForAuthority(
+    client,
+    authority,
+    collection,
+    treasury
+  );
+
+  const assetPks = await Promise.all([
+    createCoreAsset(client, authority, collection),
+    createCoreAsset(client, authority, collection),
+    createCoreAsset(client, authority, collection),
+  ]);
+
+  const [inventory] = await findInventoryPda({ pool: poolPda });
+  await pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        withRemainingAccounts(
+          getDepositManyInstruction({
+            pool: poolPda,
+            inventory,
+            collection,
+            payer: authority,
+            coreProgram: MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+          }),
+          assetPks
+        ),
+        tx
+      ),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  for (const assetPk of assetPks) {
+    const asset = await fetchAssetV1(client.rpc, assetPk);
+    t.like(asset, <Account<AssetV1>>{
+      data: {
+        owner: poolPda,
+      },
+    });
+  }
+
+  const pool = await fetchPool(client.rpc, poolPda);
+  t.like(pool, <Account<Pool>>{
+    data: {
+      numAssets: 3,
+    },
+  });
+});
+
+test('it cannot deposit many without any assets', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const authority = await generateKeyPairSignerWithSol(client);
+  const collection = await createCoreCollection(client, authority);
+  const treasury = (await generateKeyPairSigner()).address;
+  const [poolPda] = await createPoolForAuthority(
+    client,
+    authority,
+    collection,
+    treasury
+  );
+
+  const [inventory] = await findInventoryPda({ pool: poolPda });
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getDepositManyInstruction({
+          pool: poolPda,
+          inventory,
+          collection,
+          payer: authority,
+          coreProgram: MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+        }),
+        tx
+      )
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__INVALID_REMAINING_ACCOUNTS
+    )
+  );
+});
+
+test('it cannot deposit many if one asset is from the wrong collection', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const authority = await generateKeyPairSignerWithSol(client);
+  const collection = await createCoreCollection(client, authority);
+  const otherCollection = await createCoreCollection(client, authority);
+  const treasury = (await generateKeyPairSigner()).address;
+  const [poolPda] = await createPoolForAuthority(
+    client,
+    authority,
+    collection,
+    treasury
+  );
+
+  const assetPks = await Promise.all([
+    createCoreAsset(client, authority, collection),
+    createCoreAsset(client, authority, otherCollection),
+  ]);
+
+  const [inventory] = await findInventoryPda({ pool: poolPda });
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        withRemainingAccounts(
+          getDepositManyInstruction({
+            pool: poolPda,
+            inventory,
+            collection,
+            payer: authority,
+            coreProgram: MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+          }),
+          assetPks
+        ),
+        tx
+      )
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__INVALID_COLLECTION_FOR_ASSET
+    )
+  );
+});
@@ -18,7 +18,10 @@ import {
 } from '@solana/kit';
 import {
   fetchPool,
+  findInventoryPda,
+  FLOOR_SWAP_ERROR__INVALID_ASSET_OWNER,
   FLOOR_SWAP_ERROR__INVALID_COLLECTION_FOR_ASSET,
+  FLOOR_SWAP_ERROR__INVALID_PROGRAM_OWNER,
   FLOOR_SWAP_PROGRAM_ADDRESS,
   getDepositInstruction,
   Pool,
@@ -44,12 +47,14 @@ test('it can deposit an asset into the pool', async (t) => {
 
   const assetPk = await createCoreAsset(client, authority, collection);
 
+  const [inventory] = await findInventoryPda({ pool: poolPda });
   await pipe(
     await createDefaultTransaction(client, authority),
     (tx) =>
       appendTransactionMessageInstruction(
         getDepositInstruction({
           pool: poolPda,
+          inventory,
           collection,
           asset: assetPk,
           payer: authority,
@@ -94,12 +99,14 @@ test('it cannot deposit an asset from the wrong collection into the pool', async
 
   const assetPk = await createCoreAsset(client, authority, collection2);
 
+  const [inventory] = await findInventoryPda({ pool: poolPda });
   const transactionMessage = pipe(
     await createDefaultTransaction(client, authority),
     (tx) =>
       appendTransactionMessageInstruction(
         getDepositInstruction({
           pool: poolPda,
+          inventory,
           asset: assetPk,
           collection,
           payer: authority,
@@ -134,3 +141,111 @@ test('it cannot deposit an asset from the wrong collection into the pool', async
     },
   });
 });
+
+test('it cannot deposit into a pool not owned by the program', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const authority = await generateKeyPairSignerWithSol(client);
+  const collection = await createCoreCollection(client, authority);
+  const treasury = (await generateKeyPairSigner()).address;
+  const [poolPda] = await createPoolForAuthority(
+    client,
+    authority,
+    collection,
+    treasury
+  );
+
+  const assetPk = await createCoreAsset(client, authority, collection);
+
+  const spoofedPool = (await generateKeyPairSigner()).address;
+
+  const [spoofedInventory] = await findInventoryPda({ pool: spoofedPool });
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getDepositInstruction({
+          pool: spoofedPool,
+          inventory: spoofedInventory,
+          asset: assetPk,
+          collection,
+          payer: authority,
+          coreProgram: MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+        }),
+        tx
+      )
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__INVALID_PROGRAM_OWNER
+    )
+  );
+});
+
+test('it cannot deposit an asset owned by someone else', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const authority = await generateKeyPairSignerWithSol(client);
+  const collection = await createCoreCollection(client, authority);
+  const treasury = (await generateKeyPairSigner()).address;
+  const [poolPda] = await createPoolForAuthority(
+    client,
+    authority,
+    collection,
+    treasury
+  );
+
+  const owner = await generateKeyPairSignerWithSol(client);
+  const assetPk = await createCoreAsset(
+    client,
+    authority,
+    collection,
+    owner.address
+  );
+
+  const [inventory] = await findInventoryPda({ pool: poolPda });
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getDepositInstruction({
+          pool: poolPda,
+          inventory,
+          asset: assetPk,
+          collection,
+          payer: authority,
+          coreProgram: MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+        }),
+        tx
+      )
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__INVALID_ASSET_OWNER
+    )
+  );
+});
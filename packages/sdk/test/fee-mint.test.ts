@@ -0,0 +1,323 @@
+import test from 'ava';
+import {
+  Client,
+  createAndDepositAsset,
+  createDefaultSolanaClient,
+  createDefaultTransaction,
+  createPoolForAuthority,
+  generateKeyPairSignerWithSol,
+  getBalance,
+  setPoolStatus,
+  setPoolFee,
+  signAndSendTransaction,
+} from './_setup';
+import { createCoreAsset, createCoreCollection } from './_mpl-core';
+import {
+  createMint,
+  createTokenAccount,
+  getTokenBalance,
+  TOKEN_2022_PROGRAM_ADDRESS,
+  TOKEN_PROGRAM_ADDRESS,
+} from './_token';
+import {
+  Address,
+  appendTransactionMessageInstruction,
+  generateKeyPairSigner,
+  isProgramError,
+  isSolanaError,
+  pipe,
+  SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE,
+} from '@solana/kit';
+import {
+  feeModel,
+  findFeeVaultPda,
+  findInventoryPda,
+  FLOOR_SWAP_ERROR__INVALID_TOKEN_ACCOUNT,
+  FLOOR_SWAP_ERROR__MISSING_FEE_TOKEN_ACCOUNTS,
+  FLOOR_SWAP_PROGRAM_ADDRESS,
+  getSwapInstruction,
+  PoolStatus,
+} from '../src';
+import { MPL_CORE_PROGRAM_PROGRAM_ADDRESS } from '../sdks/mpl-core/generated';
+
+const setup = async (
+  client: Client,
+  {
+    tokenProgram = TOKEN_PROGRAM_ADDRESS,
+    transferFeeBps,
+  }: { tokenProgram?: Address; transferFeeBps?: number } = {}
+) => {
+  const authority = await generateKeyPairSignerWithSol(client);
+  const collection = await createCoreCollection(client, authority);
+  const treasury = (await generateKeyPairSigner()).address;
+  const [poolPda] = await createPoolForAuthority(
+    client,
+    authority,
+    collection,
+    treasury
+  );
+
+  const mint = await createMint(client, authority, {
+    tokenProgram,
+    transferFeeBps,
+  });
+  await setPoolFee(
+    client,
+    authority,
+    poolPda,
+    feeModel('Flat', { lamports: 1_000_000n }),
+    mint
+  );
+
+  const destAssetPk = await createAndDepositAsset(
+    client,
+    authority,
+    collection,
+    poolPda
+  );
+
+  const payer = await generateKeyPairSignerWithSol(client);
+
+  const sourceAssetPk = await createCoreAsset(
+    client,
+    authority,
+    collection,
+    payer.address
+  );
+
+  const withTransferFee = transferFeeBps !== undefined;
+  const payerTokenAccount = await createTokenAccount(
+    client,
+    authority,
+    mint,
+    payer.address,
+    { tokenProgram, amount: 5_000_000n, withTransferFee }
+  );
+  const treasuryTokenAccount = await createTokenAccount(
+    client,
+    authority,
+    mint,
+    treasury,
+    { tokenProgram, withTransferFee }
+  );
+
+  await setPoolStatus(client, authority, poolPda, PoolStatus.Active);
+
+  return {
+    authority,
+    collection,
+    treasury,
+    poolPda,
+    mint,
+    destAssetPk,
+    payer,
+    sourceAssetPk,
+    payerTokenAccount,
+    treasuryTokenAccount,
+  };
+};
+
+test('it can pay the swap fee in an SPL token', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const {
+    collection,
+    treasury,
+    poolPda,
+    mint,
+    destAssetPk,
+    payer,
+    sourceAssetPk,
+    payerTokenAccount,
+    treasuryTokenAccount,
+  } = await setup(client);
+
+  const [feeVault] = await findFeeVaultPda({ pool: poolPda });
+  const [inventory] = await findInventoryPda({ pool: poolPda });
+  await pipe(
+    await createDefaultTransaction(client, payer),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getSwapInstruction({
+          pool: poolPda,
+          inventory,
+          collection,
+          sourceAsset: sourceAssetPk,
+          destAsset: destAssetPk,
+          payer,
+          feeVault,
+          coreProgram: MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+          feeMint: mint,
+          payerTokenAccount,
+          treasuryTokenAccount,
+          tokenProgram: TOKEN_PROGRAM_ADDRESS,
+          maxFee: 1_000_000n,
+        }),
+        tx
+      ),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  t.deepEqual(await getTokenBalance(client, payerTokenAccount), 4_000_000n);
+  t.deepEqual(await getTokenBalance(client, treasuryTokenAccount), 1_000_000n);
+  t.deepEqual(await getBalance(client, treasury), 0n);
+});
+
+test('it can pay the swap fee in a Token-2022 mint with a transfer fee', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const {
+    collection,
+    treasury,
+    poolPda,
+    mint,
+    destAssetPk,
+    payer,
+    sourceAssetPk,
+    payerTokenAccount,
+    treasuryTokenAccount,
+  } = await setup(client, {
+    tokenProgram: TOKEN_2022_PROGRAM_ADDRESS,
+    transferFeeBps: 100,
+  });
+
+  const [feeVault] = await findFeeVaultPda({ pool: poolPda });
+  const [inventory] = await findInventoryPda({ pool: poolPda });
+  await pipe(
+    await createDefaultTransaction(client, payer),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getSwapInstruction({
+          pool: poolPda,
+          inventory,
+          collection,
+          sourceAsset: sourceAssetPk,
+          destAsset: destAssetPk,
+          payer,
+          feeVault,
+          coreProgram: MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+          feeMint: mint,
+          payerTokenAccount,
+          treasuryTokenAccount,
+          tokenProgram: TOKEN_2022_PROGRAM_ADDRESS,
+          maxFee: 1_000_000n,
+        }),
+        tx
+      ),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  // The mint withholds 1% of the transfer from the treasury.
+  t.deepEqual(await getTokenBalance(client, payerTokenAccount), 4_000_000n);
+  t.deepEqual(await getTokenBalance(client, treasuryTokenAccount), 990_000n);
+});
+
+test('it cannot swap without the fee token accounts', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const { collection, treasury, poolPda, destAssetPk, payer, sourceAssetPk } =
+    await setup(client);
+
+  const [feeVault] = await findFeeVaultPda({ pool: poolPda });
+  const [inventory] = await findInventoryPda({ pool: poolPda });
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, payer),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getSwapInstruction({
+          pool: poolPda,
+          inventory,
+          collection,
+          sourceAsset: sourceAssetPk,
+          destAsset: destAssetPk,
+          payer,
+          feeVault,
+          coreProgram: MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+          maxFee: 1_000_000n,
+        }),
+        tx
+      )
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__MISSING_FEE_TOKEN_ACCOUNTS
+    )
+  );
+});
+
+test('it cannot pay the swap fee to a token account not owned by the treasury', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const {
+    authority,
+    collection,
+    treasury,
+    poolPda,
+    mint,
+    destAssetPk,
+    payer,
+    sourceAssetPk,
+    payerTokenAccount,
+  } = await setup(client);
+
+  const otherTokenAccount = await createTokenAccount(
+    client,
+    authority,
+    mint,
+    authority.address
+  );
+
+  const [feeVault] = await findFeeVaultPda({ pool: poolPda });
+  const [inventory] = await findInventoryPda({ pool: poolPda });
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, payer),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getSwapInstruction({
+          pool: poolPda,
+          inventory,
+          collection,
+          sourceAsset: sourceAssetPk,
+          destAsset: destAssetPk,
+          payer,
+          feeVault,
+          coreProgram: MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+          feeMint: mint,
+          payerTokenAccount,
+          treasuryTokenAccount: otherTokenAccount,
+          tokenProgram: TOKEN_PROGRAM_ADDRESS,
+          maxFee: 1_000_000n,
+        }),
+        tx
+      )
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__INVALID_TOKEN_ACCOUNT
+    )
+  );
+});
@@ -0,0 +1,299 @@
+import test from 'ava';
+import {
+  createAndDepositAsset,
+  createDefaultSolanaClient,
+  createDefaultTransaction,
+  createPoolForAuthority,
+  generateKeyPairSignerWithSol,
+  setPoolStatus,
+  signAndSendTransaction,
+} from './_setup';
+import { createCoreAsset, createCoreCollection } from './_mpl-core';
+import {
+  Account,
+  appendTransactionMessageInstruction,
+  appendTransactionMessageInstructions,
+  generateKeyPairSigner,
+  isProgramError,
+  isSolanaError,
+  pipe,
+  SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE,
+} from '@solana/kit';
+import {
+  feeModel,
+  FeeVault,
+  fetchFeeVault,
+  fetchPool,
+  findFeeVaultPda,
+  findInventoryPda,
+  FLOOR_SWAP_ERROR__ACCOUNT_MISMATCH,
+  FLOOR_SWAP_ERROR__FEE_INCREASE_NOT_PROPOSED,
+  FLOOR_SWAP_ERROR__INVALID_BASIS_POINTS,
+  FLOOR_SWAP_PROGRAM_ADDRESS,
+  getApplyFeeInstruction,
+  getProposeFeeInstruction,
+  getSetFeeInstruction,
+  getSetReferencePriceInstruction,
+  getSwapInstruction,
+  Pool,
+  PoolStatus,
+} from '../src';
+import { MPL_CORE_PROGRAM_PROGRAM_ADDRESS } from '../sdks/mpl-core/generated';
+
+test('it can set the reference price of a pool', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const authority = await generateKeyPairSignerWithSol(client);
+  const collection = await createCoreCollection(client, authority);
+  const treasury = (await generateKeyPairSigner()).address;
+  const [poolPda] = await createPoolForAuthority(
+    client,
+    authority,
+    collection,
+    treasury
+  );
+
+  await pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getSetReferencePriceInstruction({
+          pool: poolPda,
+          authority,
+          referencePrice: 2_000_000_000n, // 2 sol
+        }),
+        tx
+      ),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  const pool = await fetchPool(client.rpc, poolPda);
+
+  t.like(pool, <Account<Pool>>{
+    data: {
+      referencePrice: 2_000_000_000n,
+    },
+  });
+});
+
+test('it charges basis points of the reference price on swap', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const authority = await generateKeyPairSignerWithSol(client);
+  const collection = await createCoreCollection(client, authority);
+  const treasury = (await generateKeyPairSigner()).address;
+  const [poolPda] = await createPoolForAuthority(
+    client,
+    authority,
+    collection,
+    treasury,
+    // 0.01 sol plus 2.5% of the reference price.
+    feeModel('FlatPlusBps', { lamports: 10000000n, bps: 250 })
+  );
+
+  const destAssetPk = await createAndDepositAsset(
+    client,
+    authority,
+    collection,
+    poolPda
+  );
+
+  const payer = await generateKeyPairSignerWithSol(client);
+
+  const sourceAssetPk = await createCoreAsset(
+    client,
+    authority,
+    collection,
+    payer.address
+  );
+
+  await setPoolStatus(client, authority, poolPda, PoolStatus.Active);
+
+  // Raising the reference price raises the fee, so it waits for the fee delay.
+  await pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstructions(
+        [
+          getProposeFeeInstruction({
+            pool: poolPda,
+            authority,
+            feeModel: feeModel('FlatPlusBps', {
+              lamports: 10000000n,
+              bps: 250,
+            }),
+            feeMint: null,
+            referencePrice: 2_000_000_000n, // 2 sol
+          }),
+          getApplyFeeInstruction({ pool: poolPda }),
+        ],
+        tx
+      ),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  const [feeVault] = await findFeeVaultPda({ pool: poolPda });
+  const [inventory] = await findInventoryPda({ pool: poolPda });
+  await pipe(
+    await createDefaultTransaction(client, payer),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getSwapInstruction({
+          pool: poolPda,
+          inventory,
+          collection,
+          sourceAsset: sourceAssetPk,
+          destAsset: destAssetPk,
+          payer,
+          feeVault,
+          coreProgram: MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+          maxFee: 60000000n,
+        }),
+        tx
+      ),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  // 0.01 sol flat plus 2.5% of 2 sol.
+  t.like(await fetchFeeVault(client.rpc, feeVault), <Account<FeeVault>>{
+    data: {
+      lifetimeFees: 60000000n,
+    },
+  });
+});
+
+test('it cannot set a fee above 10000 basis points', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const authority = await generateKeyPairSignerWithSol(client);
+  const collection = await createCoreCollection(client, authority);
+  const treasury = (await generateKeyPairSigner()).address;
+  const [poolPda] = await createPoolForAuthority(
+    client,
+    authority,
+    collection,
+    treasury
+  );
+
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getSetFeeInstruction({
+          pool: poolPda,
+          authority,
+          feeModel: feeModel('Bps', { bps: 10001 }),
+          feeMint: null,
+        }),
+        tx
+      )
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__INVALID_BASIS_POINTS
+    )
+  );
+});
+
+test('it cannot set the reference price if not the authority', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const authority = await generateKeyPairSignerWithSol(client);
+  const collection = await createCoreCollection(client, authority);
+  const treasury = (await generateKeyPairSigner()).address;
+  const [poolPda] = await createPoolForAuthority(
+    client,
+    authority,
+    collection,
+    treasury
+  );
+
+  const unauthorizedSigner = await generateKeyPairSignerWithSol(client);
+
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, unauthorizedSigner),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getSetReferencePriceInstruction({
+          pool: poolPda,
+          authority: unauthorizedSigner,
+          referencePrice: 1n,
+        }),
+        tx
+      )
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__ACCOUNT_MISMATCH
+    )
+  );
+});
+
+test('it cannot raise the fee through the reference price', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const authority = await generateKeyPairSignerWithSol(client);
+  const collection = await createCoreCollection(client, authority);
+  const treasury = (await generateKeyPairSigner()).address;
+  const [poolPda] = await createPoolForAuthority(
+    client,
+    authority,
+    collection,
+    treasury,
+    feeModel('Bps', { bps: 250 })
+  );
+
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getSetReferencePriceInstruction({
+          pool: poolPda,
+          authority,
+          referencePrice: 2_000_000_000n, // 2 sol
+        }),
+        tx
+      )
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__FEE_INCREASE_NOT_PROPOSED
+    )
+  );
+});
@@ -0,0 +1,305 @@
+import test from 'ava';
+import {
+  Client,
+  createAndDepositAsset,
+  createDefaultSolanaClient,
+  createDefaultTransaction,
+  createPoolForAuthority,
+  generateKeyPairSignerWithSol,
+  getBalance,
+  setPoolStatus,
+  signAndSendTransaction,
+  withRemainingAccounts,
+} from './_setup';
+import { createCoreAsset, createCoreCollection } from './_mpl-core';
+import {
+  Account,
+  Address,
+  appendTransactionMessageInstruction,
+  generateKeyPairSigner,
+  isProgramError,
+  isSolanaError,
+  KeyPairSigner,
+  pipe,
+  SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE,
+} from '@solana/kit';
+import {
+  feeModel,
+  FeeRecipientArgs,
+  fetchPool,
+  findFeeVaultPda,
+  findInventoryPda,
+  FLOOR_SWAP_ERROR__INVALID_FEE_SPLIT,
+  FLOOR_SWAP_ERROR__INVALID_REMAINING_ACCOUNTS,
+  FLOOR_SWAP_PROGRAM_ADDRESS,
+  getClaimFeesInstruction,
+  getSetFeeSplitInstruction,
+  getSwapInstruction,
+  Pool,
+  PoolStatus,
+} from '../src';
+import { MPL_CORE_PROGRAM_PROGRAM_ADDRESS } from '../sdks/mpl-core/generated';
+
+const setFeeSplit = async (
+  client: Client,
+  authority: KeyPairSigner,
+  pool: Address,
+  feeSplit: FeeRecipientArgs[]
+) => {
+  await pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getSetFeeSplitInstruction({ pool, authority, feeSplit }),
+        tx
+      ),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+};
+
+const setup = async (client: Client) => {
+  const authority = await generateKeyPairSignerWithSol(client);
+  const collection = await createCoreCollection(client, authority);
+  // The treasury receives the rounding dust, so it must already be rent exempt.
+  const treasury = (await generateKeyPairSignerWithSol(client)).address;
+  const [poolPda] = await createPoolForAuthority(
+    client,
+    authority,
+    collection,
+    treasury,
+    feeModel('Flat', { lamports: 100_000_001n })
+  );
+
+  const destAssetPk = await createAndDepositAsset(
+    client,
+    authority,
+    collection,
+    poolPda
+  );
+
+  const payer = await generateKeyPairSignerWithSol(client);
+
+  const sourceAssetPk = await createCoreAsset(
+    client,
+    authority,
+    collection,
+    payer.address
+  );
+
+  await setPoolStatus(client, authority, poolPda, PoolStatus.Active);
+
+  const [feeVault] = await findFeeVaultPda({ pool: poolPda });
+
+  return {
+    authority,
+    collection,
+    treasury,
+    poolPda,
+    feeVault,
+    destAssetPk,
+    payer,
+    sourceAssetPk,
+  };
+};
+
+test('it splits the claimed fees between recipients', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const {
+    authority,
+    collection,
+    treasury,
+    poolPda,
+    feeVault,
+    destAssetPk,
+    payer,
+    sourceAssetPk,
+  } = await setup(client);
+
+  const recipients = [
+    (await generateKeyPairSigner()).address,
+    (await generateKeyPairSigner()).address,
+    (await generateKeyPairSigner()).address,
+  ];
+  await setFeeSplit(client, authority, poolPda, [
+    { address: recipients[0], bps: 5000 },
+    { address: recipients[1], bps: 3000 },
+    { address: recipients[2], bps: 2000 },
+  ]);
+
+  t.like(await fetchPool(client.rpc, poolPda), <Account<Pool>>{
+    data: {
+      feeSplit: [
+        { address: recipients[0], bps: 5000 },
+        { address: recipients[1], bps: 3000 },
+        { address: recipients[2], bps: 2000 },
+      ],
+    },
+  });
+
+  const treasuryBalance = await getBalance(client, treasury);
+
+  const [inventory] = await findInventoryPda({ pool: poolPda });
+  await pipe(
+    await createDefaultTransaction(client, payer),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getSwapInstruction({
+          pool: poolPda,
+          inventory,
+          collection,
+          sourceAsset: sourceAssetPk,
+          destAsset: destAssetPk,
+          payer,
+          feeVault,
+          coreProgram: MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+          maxFee: 100_000_001n,
+        }),
+        tx
+      ),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  await pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        withRemainingAccounts(
+          getClaimFeesInstruction({
+            pool: poolPda,
+            feeVault,
+            claimer: authority,
+            treasury,
+          }),
+          recipients
+        ),
+        tx
+      ),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  t.deepEqual(await getBalance(client, recipients[0]), 50_000_000n);
+  t.deepEqual(await getBalance(client, recipients[1]), 30_000_000n);
+  t.deepEqual(await getBalance(client, recipients[2]), 20_000_000n);
+  // The rounding dust goes to the treasury.
+  t.deepEqual(await getBalance(client, treasury), treasuryBalance + 1n);
+});
+
+test('it cannot set a fee split that does not sum to 10000 basis points', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const authority = await generateKeyPairSignerWithSol(client);
+  const collection = await createCoreCollection(client, authority);
+  const treasury = (await generateKeyPairSigner()).address;
+  const [poolPda] = await createPoolForAuthority(
+    client,
+    authority,
+    collection,
+    treasury
+  );
+  const recipient = (await generateKeyPairSigner()).address;
+
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getSetFeeSplitInstruction({
+          pool: poolPda,
+          authority,
+          feeSplit: [
+            { address: recipient, bps: 5000 },
+            { address: treasury, bps: 4000 },
+          ],
+        }),
+        tx
+      )
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__INVALID_FEE_SPLIT
+    )
+  );
+});
+
+test('it cannot claim fees without the fee recipient accounts', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const {
+    authority,
+    collection,
+    treasury,
+    poolPda,
+    feeVault,
+    destAssetPk,
+    payer,
+    sourceAssetPk,
+  } = await setup(client);
+
+  await setFeeSplit(client, authority, poolPda, [
+    { address: (await generateKeyPairSigner()).address, bps: 10000 },
+  ]);
+
+  const [inventory] = await findInventoryPda({ pool: poolPda });
+  await pipe(
+    await createDefaultTransaction(client, payer),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getSwapInstruction({
+          pool: poolPda,
+          inventory,
+          collection,
+          sourceAsset: sourceAssetPk,
+          destAsset: destAssetPk,
+          payer,
+          feeVault,
+          coreProgram: MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+          maxFee: 100_000_001n,
+        }),
+        tx
+      ),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getClaimFeesInstruction({
+          pool: poolPda,
+          feeVault,
+          claimer: authority,
+          treasury,
+        }),
+        tx
+      )
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__INVALID_REMAINING_ACCOUNTS
+    )
+  );
+});
@@ -0,0 +1,253 @@
+import test from 'ava';
+import {
+  createDefaultSolanaClient,
+  createDefaultTransaction,
+  createPoolForAuthority,
+  generateKeyPairSignerWithSol,
+  setPoolFee,
+  signAndSendTransaction,
+} from './_setup';
+import { createCoreCollection } from './_mpl-core';
+import {
+  Account,
+  appendTransactionMessageInstruction,
+  generateKeyPairSigner,
+  isProgramError,
+  isSolanaError,
+  none,
+  pipe,
+  SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE,
+} from '@solana/kit';
+import {
+  feeModel,
+  fetchPool,
+  FLOOR_SWAP_ERROR__FEE_ABOVE_MAXIMUM,
+  FLOOR_SWAP_ERROR__NO_PENDING_FEE,
+  FLOOR_SWAP_ERROR__PENDING_FEE_NOT_EFFECTIVE,
+  FLOOR_SWAP_PROGRAM_ADDRESS,
+  getApplyFeeInstruction,
+  getCreateInstructionAsync,
+  getProposeFeeInstruction,
+  Pool,
+} from '../src';
+
+test('it applies a proposed fee once the fee delay has passed', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const authority = await generateKeyPairSignerWithSol(client);
+  const collection = await createCoreCollection(client, authority);
+  const treasury = (await generateKeyPairSigner()).address;
+  const [poolPda] = await createPoolForAuthority(
+    client,
+    authority,
+    collection,
+    treasury
+  );
+
+  // Without a fee delay the proposal can be applied right away.
+  await setPoolFee(
+    client,
+    authority,
+    poolPda,
+    feeModel('Flat', { lamports: 20_000_000n }) // 0.02 sol
+  );
+
+  t.like(await fetchPool(client.rpc, poolPda), <Account<Pool>>{
+    data: {
+      feeModel: { __kind: 'Flat', lamports: 20_000_000n },
+      pendingFee: none(),
+    },
+  });
+});
+
+test('it cannot apply a proposed fee before the fee delay', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const authority = await generateKeyPairSignerWithSol(client);
+  const collection = await createCoreCollection(client, authority);
+  const treasury = (await generateKeyPairSigner()).address;
+  const [poolPda] = await createPoolForAuthority(
+    client,
+    authority,
+    collection,
+    treasury,
+    undefined,
+    { feeDelay: 3600 } // 1 hour
+  );
+
+  await pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getProposeFeeInstruction({
+          pool: poolPda,
+          authority,
+          feeModel: feeModel('Flat', { lamports: 20_000_000n }),
+          feeMint: null,
+        }),
+        tx
+      ),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  const pool = await fetchPool(client.rpc, poolPda);
+  // The current fee is unchanged until the proposal is applied.
+  t.like(pool, <Account<Pool>>{
+    data: {
+      feeModel: { __kind: 'Flat', lamports: 10_000_000n },
+    },
+  });
+  t.is(pool.data.pendingFee.__option, 'Some');
+
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getApplyFeeInstruction({ pool: poolPda }),
+        tx
+      )
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__PENDING_FEE_NOT_EFFECTIVE
+    )
+  );
+});
+
+test('it cannot apply a fee that was not proposed', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const authority = await generateKeyPairSignerWithSol(client);
+  const collection = await createCoreCollection(client, authority);
+  const treasury = (await generateKeyPairSigner()).address;
+  const [poolPda] = await createPoolForAuthority(
+    client,
+    authority,
+    collection,
+    treasury
+  );
+
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getApplyFeeInstruction({ pool: poolPda }),
+        tx
+      )
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__NO_PENDING_FEE
+    )
+  );
+});
+
+test('it cannot propose a fee above the fee ceiling', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const authority = await generateKeyPairSignerWithSol(client);
+  const collection = await createCoreCollection(client, authority);
+  const treasury = (await generateKeyPairSigner()).address;
+  const [poolPda] = await createPoolForAuthority(
+    client,
+    authority,
+    collection,
+    treasury,
+    undefined,
+    { maxFee: 50_000_000n } // 0.05 sol
+  );
+
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getProposeFeeInstruction({
+          pool: poolPda,
+          authority,
+          feeModel: feeModel('Flat', { lamports: 60_000_000n }),
+          feeMint: null,
+        }),
+        tx
+      )
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__FEE_ABOVE_MAXIMUM
+    )
+  );
+});
+
+test('it cannot create a pool with a fee above the fee ceiling', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const authority = await generateKeyPairSignerWithSol(client);
+  const collection = await createCoreCollection(client, authority);
+  const treasury = (await generateKeyPairSigner()).address;
+
+  const createIx = await getCreateInstructionAsync({
+    authority,
+    collection,
+    treasury,
+    feeModel: feeModel('Flat', { lamports: 60_000_000n }),
+    feeMint: null,
+    maxFee: 50_000_000n,
+    feeDelay: 0,
+  });
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) => appendTransactionMessageInstruction(createIx, tx)
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__FEE_ABOVE_MAXIMUM
+    )
+  );
+});
@@ -0,0 +1,543 @@
+import test from 'ava';
+import {
+  createAndDepositAsset,
+  createDefaultSolanaClient,
+  createDefaultTransaction,
+  createPoolForAuthority,
+  findProgramDataAddress,
+  generateKeyPairSignerWithSol,
+  getBalance,
+  getLocalUpgradeAuthority,
+  setPoolMode,
+  setPoolStatus,
+  signAndSendTransaction,
+} from './_setup';
+import { createCoreAsset, createCoreCollection } from './_mpl-core';
+import {
+  Account,
+  appendTransactionMessageInstructions,
+  createKeyPairSignerFromPrivateKeyBytes,
+  generateKeyPairSigner,
+  isProgramError,
+  isSolanaError,
+  pipe,
+  SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE,
+} from '@solana/kit';
+import {
+  feeModel,
+  fetchGlobalConfigFromSeeds,
+  fetchMaybeGlobalConfigFromSeeds,
+  findFeeVaultPda,
+  findInventoryPda,
+  FLOOR_SWAP_ERROR__ACCOUNT_MISMATCH,
+  FLOOR_SWAP_ERROR__INVALID_UPGRADE_AUTHORITY,
+  FLOOR_SWAP_ERROR__PROGRAM_PAUSED,
+  FLOOR_SWAP_ERROR__PROTOCOL_FEE_ABOVE_MAXIMUM,
+  FLOOR_SWAP_PROGRAM_ADDRESS,
+  getCancelSwapInstructionAsync,
+  getCommitSwapInstructionAsync,
+  getInitGlobalConfigInstruction,
+  getPauseProgramInstruction,
+  getRevealSwapInstructionAsync,
+  getSwapInstruction,
+  getUpdateGlobalConfigInstruction,
+  GlobalConfig,
+  PoolMode,
+  PoolStatus,
+} from '../src';
+import { MPL_CORE_PROGRAM_PROGRAM_ADDRESS } from '../sdks/mpl-core/generated';
+
+// A fixed guardian, since the global config is shared by every test.
+const getGuardian = () =>
+  createKeyPairSignerFromPrivateKeyBytes(new Uint8Array(32).fill(2));
+
+test.before(async () => {
+  const client = createDefaultSolanaClient();
+  const config = await fetchMaybeGlobalConfigFromSeeds(client.rpc);
+  if (config.exists) {
+    return;
+  }
+
+  const [admin, guardian, payer, [programData]] = await Promise.all([
+    getLocalUpgradeAuthority(),
+    getGuardian(),
+    generateKeyPairSignerWithSol(client),
+    findProgramDataAddress(),
+  ]);
+  await pipe(
+    await createDefaultTransaction(client, payer),
+    (tx) =>
+      appendTransactionMessageInstructions(
+        [
+          getInitGlobalConfigInstruction({
+            admin,
+            payer,
+            programData,
+            guardians: [guardian.address],
+          }),
+        ],
+        tx
+      ),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+});
+
+test('the global config is managed by the upgrade authority', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const [admin, guardian] = await Promise.all([
+    getLocalUpgradeAuthority(),
+    getGuardian(),
+  ]);
+
+  t.like(
+    await fetchGlobalConfigFromSeeds(client.rpc),
+    <Account<GlobalConfig>>{
+      data: {
+        admin: admin.address,
+        paused: false,
+        guardians: [guardian.address],
+      },
+    }
+  );
+});
+
+test('a guardian can halt swaps', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const authority = await generateKeyPairSignerWithSol(client);
+  const collection = await createCoreCollection(client, authority);
+  const treasury = (await generateKeyPairSigner()).address;
+  const [poolPda] = await createPoolForAuthority(
+    client,
+    authority,
+    collection,
+    treasury
+  );
+  const destAssetPk = await createAndDepositAsset(
+    client,
+    authority,
+    collection,
+    poolPda
+  );
+  const payer = await generateKeyPairSignerWithSol(client);
+  const sourceAssetPk = await createCoreAsset(
+    client,
+    authority,
+    collection,
+    payer.address
+  );
+  await setPoolStatus(client, authority, poolPda, PoolStatus.Active);
+
+  // Pausing in the same transaction keeps the program running for other tests,
+  // since the failed swap rolls the pause back.
+  const guardian = await getGuardian();
+  const [feeVault] = await findFeeVaultPda({ pool: poolPda });
+  const [inventory] = await findInventoryPda({ pool: poolPda });
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, payer),
+    (tx) =>
+      appendTransactionMessageInstructions(
+        [
+          getPauseProgramInstruction({ guardian }),
+          getSwapInstruction({
+            pool: poolPda,
+            inventory,
+            collection,
+            sourceAsset: sourceAssetPk,
+            destAsset: destAssetPk,
+            payer,
+            feeVault,
+            coreProgram: MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+            maxFee: 10000000n,
+          }),
+        ],
+        tx
+      )
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__PROGRAM_PAUSED
+    )
+  );
+});
+
+test('a guardian can halt mystery swap reveals and cancellations', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const authority = await generateKeyPairSignerWithSol(client);
+  const collection = await createCoreCollection(client, authority);
+  const treasury = (await generateKeyPairSigner()).address;
+  const [poolPda] = await createPoolForAuthority(
+    client,
+    authority,
+    collection,
+    treasury
+  );
+  const destAssetPk = await createAndDepositAsset(
+    client,
+    authority,
+    collection,
+    poolPda
+  );
+  const payer = await generateKeyPairSignerWithSol(client);
+  const sourceAssetPk = await createCoreAsset(
+    client,
+    authority,
+    collection,
+    payer.address
+  );
+  await setPoolStatus(client, authority, poolPda, PoolStatus.Active);
+  await setPoolMode(client, authority, poolPda, PoolMode.Mystery);
+
+  const commitIx = await getCommitSwapInstructionAsync({
+    pool: poolPda,
+    sourceAsset: sourceAssetPk,
+    payer,
+    collection,
+    coreProgram: MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+    maxFee: 10000000n,
+  });
+  await pipe(
+    await createDefaultTransaction(client, payer),
+    (tx) => appendTransactionMessageInstructions([commitIx], tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  // The open swap can neither be revealed nor cancelled while paused.
+  const guardian = await getGuardian();
+  const instructions = [
+    await getRevealSwapInstructionAsync({
+      pool: poolPda,
+      sourceAsset: sourceAssetPk,
+      owner: payer.address,
+      payer,
+      collection,
+      coreProgram: MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+      destAsset: destAssetPk,
+    }),
+    await getCancelSwapInstructionAsync({
+      pool: poolPda,
+      sourceAsset: sourceAssetPk,
+      owner: payer.address,
+      payer,
+      collection,
+      coreProgram: MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+    }),
+  ];
+  for (const instruction of instructions) {
+    const transactionMessage = pipe(
+      await createDefaultTransaction(client, payer),
+      (tx) =>
+        appendTransactionMessageInstructions(
+          [getPauseProgramInstruction({ guardian }), instruction],
+          tx
+        )
+    );
+
+    const promise = signAndSendTransaction(client, transactionMessage);
+    const error = await t.throwsAsync(promise);
+    t.true(
+      isSolanaError(
+        error,
+        SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+      )
+    );
+    t.true(
+      isProgramError(
+        error.cause,
+        transactionMessage,
+        FLOOR_SWAP_PROGRAM_ADDRESS,
+        FLOOR_SWAP_ERROR__PROGRAM_PAUSED
+      )
+    );
+  }
+});
+
+test('only a guardian can pause the program', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const attacker = await generateKeyPairSignerWithSol(client);
+
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, attacker),
+    (tx) =>
+      appendTransactionMessageInstructions(
+        [getPauseProgramInstruction({ guardian: attacker })],
+        tx
+      )
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__ACCOUNT_MISMATCH
+    )
+  );
+});
+
+test('only the upgrade authority can resume the program', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const guardian = await getGuardian();
+  const payer = await generateKeyPairSignerWithSol(client);
+  const [programData] = await findProgramDataAddress();
+
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, payer),
+    (tx) =>
+      appendTransactionMessageInstructions(
+        [
+          getUpdateGlobalConfigInstruction({
+            admin: guardian,
+            programData,
+            paused: false,
+          }),
+        ],
+        tx
+      )
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__INVALID_UPGRADE_AUTHORITY
+    )
+  );
+});
+
+test('swaps pay the protocol fee to the protocol treasury', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const authority = await generateKeyPairSignerWithSol(client);
+  const collection = await createCoreCollection(client, authority);
+  const treasury = (await generateKeyPairSigner()).address;
+  const [poolPda] = await createPoolForAuthority(
+    client,
+    authority,
+    collection,
+    treasury
+  );
+  const destAssetPk = await createAndDepositAsset(
+    client,
+    authority,
+    collection,
+    poolPda
+  );
+  const payer = await generateKeyPairSignerWithSol(client);
+  const sourceAssetPk = await createCoreAsset(
+    client,
+    authority,
+    collection,
+    payer.address
+  );
+  await setPoolStatus(client, authority, poolPda, PoolStatus.Active);
+
+  const [admin, protocolTreasury, [programData], [feeVault]] =
+    await Promise.all([
+      getLocalUpgradeAuthority(),
+      generateKeyPairSignerWithSol(client),
+      findProgramDataAddress(),
+      findFeeVaultPda({ pool: poolPda }),
+    ]);
+  const balanceBefore = await getBalance(client, protocolTreasury.address);
+
+  // The protocol fee is reset within the same transaction, so swaps from
+  // other tests are not charged.
+  const [inventory] = await findInventoryPda({ pool: poolPda });
+  await pipe(
+    await createDefaultTransaction(client, payer),
+    (tx) =>
+      appendTransactionMessageInstructions(
+        [
+          getUpdateGlobalConfigInstruction({
+            admin,
+            programData,
+            protocolFee: feeModel('Flat', { lamports: 1000000n }),
+            protocolTreasury: protocolTreasury.address,
+          }),
+          getSwapInstruction({
+            pool: poolPda,
+            inventory,
+            collection,
+            sourceAsset: sourceAssetPk,
+            destAsset: destAssetPk,
+            payer,
+            feeVault,
+            coreProgram: MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+            protocolTreasury: protocolTreasury.address,
+            maxFee: 11000000n,
+          }),
+          getUpdateGlobalConfigInstruction({
+            admin,
+            programData,
+            protocolFee: feeModel('Flat', { lamports: 0n }),
+          }),
+        ],
+        tx
+      ),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  t.is(
+    await getBalance(client, protocolTreasury.address),
+    balanceBefore + 1000000n
+  );
+});
+
+test('the protocol basis points apply to the pool swap fee', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const authority = await generateKeyPairSignerWithSol(client);
+  const collection = await createCoreCollection(client, authority);
+  const treasury = (await generateKeyPairSigner()).address;
+  const [poolPda] = await createPoolForAuthority(
+    client,
+    authority,
+    collection,
+    treasury
+  );
+  const destAssetPk = await createAndDepositAsset(
+    client,
+    authority,
+    collection,
+    poolPda
+  );
+  const payer = await generateKeyPairSignerWithSol(client);
+  const sourceAssetPk = await createCoreAsset(
+    client,
+    authority,
+    collection,
+    payer.address
+  );
+  await setPoolStatus(client, authority, poolPda, PoolStatus.Active);
+
+  const [admin, protocolTreasury, [programData], [feeVault]] =
+    await Promise.all([
+      getLocalUpgradeAuthority(),
+      generateKeyPairSignerWithSol(client),
+      findProgramDataAddress(),
+      findFeeVaultPda({ pool: poolPda }),
+    ]);
+  const balanceBefore = await getBalance(client, protocolTreasury.address);
+
+  // The protocol fee is reset within the same transaction, so swaps from
+  // other tests are not charged.
+  const [inventory] = await findInventoryPda({ pool: poolPda });
+  await pipe(
+    await createDefaultTransaction(client, payer),
+    (tx) =>
+      appendTransactionMessageInstructions(
+        [
+          getUpdateGlobalConfigInstruction({
+            admin,
+            programData,
+            protocolFee: feeModel('FlatPlusBps', {
+              lamports: 1000000n,
+              bps: 500,
+            }),
+            protocolTreasury: protocolTreasury.address,
+          }),
+          getSwapInstruction({
+            pool: poolPda,
+            inventory,
+            collection,
+            sourceAsset: sourceAssetPk,
+            destAsset: destAssetPk,
+            payer,
+            feeVault,
+            coreProgram: MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+            protocolTreasury: protocolTreasury.address,
+            maxFee: 11500000n,
+          }),
+          getUpdateGlobalConfigInstruction({
+            admin,
+            programData,
+            protocolFee: feeModel('Flat', { lamports: 0n }),
+          }),
+        ],
+        tx
+      ),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  // 5% of the 0.01 SOL pool fee on top of the flat part.
+  t.is(
+    await getBalance(client, protocolTreasury.address),
+    balanceBefore + 1500000n
+  );
+});
+
+test('the protocol fee cannot exceed the hard-coded maximum', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const payer = await generateKeyPairSignerWithSol(client);
+  const [admin, [programData]] = await Promise.all([
+    getLocalUpgradeAuthority(),
+    findProgramDataAddress(),
+  ]);
+
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, payer),
+    (tx) =>
+      appendTransactionMessageInstructions(
+        [
+          getUpdateGlobalConfigInstruction({
+            admin,
+            programData,
+            protocolFee: feeModel('Bps', { bps: 501 }),
+          }),
+        ],
+        tx
+      )
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__PROTOCOL_FEE_ABOVE_MAXIMUM
+    )
+  );
+});
@@ -0,0 +1,202 @@
+import test from 'ava';
+import {
+  Client,
+  createAndDepositAsset,
+  createDefaultSolanaClient,
+  createDefaultTransaction,
+  createPoolForAuthority,
+  generateKeyPairSignerWithSol,
+  setPoolStatus,
+  signAndSendTransaction,
+} from './_setup';
+import { createCoreAsset, createCoreCollection } from './_mpl-core';
+import {
+  Account,
+  Address,
+  appendTransactionMessageInstruction,
+  generateKeyPairSigner,
+  isProgramError,
+  isSolanaError,
+  pipe,
+  SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE,
+} from '@solana/kit';
+import {
+  fetchInventoryFromSeeds,
+  fetchPool,
+  findFeeVaultPda,
+  findInventoryPda,
+  FLOOR_SWAP_ERROR__ASSET_NOT_IN_INVENTORY,
+  FLOOR_SWAP_PROGRAM_ADDRESS,
+  getSwapInstruction,
+  getWithdrawInstruction,
+  Pool,
+  PoolStatus,
+} from '../src';
+import {
+  getTransferV1Instruction,
+  MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+} from '../sdks/mpl-core/generated';
+
+const fetchInventoryAssets = async (client: Client, pool: Address) =>
+  (await fetchInventoryFromSeeds(client.rpc, { pool })).data.assets;
+
+test('the inventory tracks deposits, swaps and withdrawals', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const authority = await generateKeyPairSignerWithSol(client);
+  const collection = await createCoreCollection(client, authority);
+  const treasury = (await generateKeyPairSigner()).address;
+  const [poolPda] = await createPoolForAuthority(
+    client,
+    authority,
+    collection,
+    treasury
+  );
+
+  t.deepEqual(await fetchInventoryAssets(client, poolPda), []);
+
+  const destAssetPk = await createAndDepositAsset(
+    client,
+    authority,
+    collection,
+    poolPda
+  );
+
+  t.deepEqual(await fetchInventoryAssets(client, poolPda), [destAssetPk]);
+
+  const payer = await generateKeyPairSignerWithSol(client);
+  const sourceAssetPk = await createCoreAsset(
+    client,
+    authority,
+    collection,
+    payer.address
+  );
+  await setPoolStatus(client, authority, poolPda, PoolStatus.Active);
+
+  const [feeVault] = await findFeeVaultPda({ pool: poolPda });
+  const [inventory] = await findInventoryPda({ pool: poolPda });
+  await pipe(
+    await createDefaultTransaction(client, payer),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getSwapInstruction({
+          pool: poolPda,
+          inventory,
+          collection,
+          sourceAsset: sourceAssetPk,
+          destAsset: destAssetPk,
+          payer,
+          feeVault,
+          coreProgram: MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+          maxFee: 10000000n,
+        }),
+        tx
+      ),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  t.deepEqual(await fetchInventoryAssets(client, poolPda), [sourceAssetPk]);
+
+  await pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getWithdrawInstruction({
+          pool: poolPda,
+          inventory,
+          collection,
+          asset: sourceAssetPk,
+          authority,
+          coreProgram: MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+        }),
+        tx
+      ),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  t.deepEqual(await fetchInventoryAssets(client, poolPda), []);
+  t.like(await fetchPool(client.rpc, poolPda), <Account<Pool>>{
+    data: { numAssets: 0 },
+  });
+});
+
+test('it cannot swap an asset sent to the pool without a deposit', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const authority = await generateKeyPairSignerWithSol(client);
+  const collection = await createCoreCollection(client, authority);
+  const treasury = (await generateKeyPairSigner()).address;
+  const [poolPda] = await createPoolForAuthority(
+    client,
+    authority,
+    collection,
+    treasury
+  );
+  await createAndDepositAsset(client, authority, collection, poolPda);
+
+  // Transferred straight to the pool, so it is missing from the inventory.
+  const destAssetPk = await createCoreAsset(client, authority, collection);
+  await pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getTransferV1Instruction({
+          asset: destAssetPk,
+          newOwner: poolPda,
+          authority,
+          payer: authority,
+          collection,
+          compressionProof: null,
+        }),
+        tx
+      ),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  const payer = await generateKeyPairSignerWithSol(client);
+  const sourceAssetPk = await createCoreAsset(
+    client,
+    authority,
+    collection,
+    payer.address
+  );
+  await setPoolStatus(client, authority, poolPda, PoolStatus.Active);
+
+  const [feeVault] = await findFeeVaultPda({ pool: poolPda });
+  const [inventory] = await findInventoryPda({ pool: poolPda });
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, payer),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getSwapInstruction({
+          pool: poolPda,
+          inventory,
+          collection,
+          sourceAsset: sourceAssetPk,
+          destAsset: destAssetPk,
+          payer,
+          feeVault,
+          coreProgram: MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+          maxFee: 10000000n,
+        }),
+        tx
+      )
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__ASSET_NOT_IN_INVENTORY
+    )
+  );
+});
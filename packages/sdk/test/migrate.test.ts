@@ -0,0 +1,66 @@
+import test from 'ava';
+import {
+  createDefaultSolanaClient,
+  createDefaultTransaction,
+  createPoolForAuthority,
+  generateKeyPairSignerWithSol,
+  signAndSendTransaction,
+} from './_setup';
+import { createCoreCollection } from './_mpl-core';
+import {
+  appendTransactionMessageInstruction,
+  generateKeyPairSigner,
+  isProgramError,
+  isSolanaError,
+  pipe,
+  SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE,
+} from '@solana/kit';
+import {
+  FLOOR_SWAP_ERROR__INVALID_ACCOUNT_LENGTH,
+  FLOOR_SWAP_PROGRAM_ADDRESS,
+  getMigrateInstructionAsync,
+} from '../src';
+
+test('it cannot migrate a pool that already has the current layout', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const authority = await generateKeyPairSignerWithSol(client);
+  const collection = await createCoreCollection(client, authority);
+  const treasury = (await generateKeyPairSigner()).address;
+  const [poolPda] = await createPoolForAuthority(
+    client,
+    authority,
+    collection,
+    treasury
+  );
+
+  const migrateIx = await getMigrateInstructionAsync({
+    pool: poolPda,
+    authority,
+    collection,
+    maxFee: 10000000n,
+    feeDelay: 0,
+    maxReferralBps: 0,
+  });
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) => appendTransactionMessageInstruction(migrateIx, tx)
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__INVALID_ACCOUNT_LENGTH
+    )
+  );
+});
@@ -0,0 +1,521 @@
+import test from 'ava';
+import {
+  Client,
+  createAndDepositAsset,
+  createDefaultSolanaClient,
+  createDefaultTransaction,
+  createPoolForAuthority,
+  generateKeyPairSignerWithSol,
+  signAndSendTransaction,
+  withSigners,
+} from './_setup';
+import { createCoreCollection } from './_mpl-core';
+import {
+  Account,
+  Address,
+  appendTransactionMessageInstruction,
+  generateKeyPairSigner,
+  isProgramError,
+  isSolanaError,
+  KeyPairSigner,
+  none,
+  pipe,
+  SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE,
+  some,
+} from '@solana/kit';
+import {
+  fetchPool,
+  findInventoryPda,
+  FLOOR_SWAP_ERROR__INVALID_MULTISIG,
+  FLOOR_SWAP_ERROR__MULTISIG_THRESHOLD_NOT_MET,
+  FLOOR_SWAP_PROGRAM_ADDRESS,
+  getSetFeeSplitInstruction,
+  getSetModeInstruction,
+  getSetMultisigInstruction,
+  getSetReferralFeeInstruction,
+  getSetRolesInstruction,
+  getSetRoyaltyBaseInstruction,
+  getUpdatePoolInstruction,
+  getWithdrawInstruction,
+  Pool,
+  PoolMode,
+  RoyaltyBase,
+} from '../src';
+import { MPL_CORE_PROGRAM_PROGRAM_ADDRESS } from '../sdks/mpl-core/generated';
+
+const setMultisig = async (
+  client: Client,
+  authority: KeyPairSigner,
+  pool: Address,
+  threshold: number,
+  signers: Address[],
+  approvals: KeyPairSigner[] = []
+) => {
+  await pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        withSigners(
+          getSetMultisigInstruction({ pool, authority, threshold, signers }),
+          approvals
+        ),
+        tx
+      ),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+};
+
+const setup = async (client: Client) => {
+  const authority = await generateKeyPairSignerWithSol(client);
+  const collection = await createCoreCollection(client, authority);
+  const treasury = (await generateKeyPairSigner()).address;
+  const [poolPda] = await createPoolForAuthority(
+    client,
+    authority,
+    collection,
+    treasury
+  );
+
+  const signers = await Promise.all([
+    generateKeyPairSigner(),
+    generateKeyPairSigner(),
+    generateKeyPairSigner(),
+  ]);
+  await setMultisig(
+    client,
+    authority,
+    poolPda,
+    2,
+    signers.map((signer) => signer.address)
+  );
+
+  return { authority, collection, poolPda, signers };
+};
+
+test('it can withdraw with enough multisig signers', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const { authority, collection, poolPda, signers } = await setup(client);
+
+  t.like(await fetchPool(client.rpc, poolPda), <Account<Pool>>{
+    data: {
+      multisig: some({
+        threshold: 2,
+        signers: signers.map((signer) => signer.address),
+      }),
+    },
+  });
+
+  const assetPk = await createAndDepositAsset(
+    client,
+    authority,
+    collection,
+    poolPda
+  );
+
+  const [inventory] = await findInventoryPda({ pool: poolPda });
+  await pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        withSigners(
+          getWithdrawInstruction({
+            pool: poolPda,
+            inventory,
+            collection,
+            asset: assetPk,
+            authority,
+            coreProgram: MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+          }),
+          [signers[0], signers[2]]
+        ),
+        tx
+      ),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  t.like(await fetchPool(client.rpc, poolPda), <Account<Pool>>{
+    data: { numAssets: 0 },
+  });
+});
+
+test('it cannot withdraw without enough multisig signers', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const { authority, collection, poolPda, signers } = await setup(client);
+
+  const assetPk = await createAndDepositAsset(
+    client,
+    authority,
+    collection,
+    poolPda
+  );
+
+  const [inventory] = await findInventoryPda({ pool: poolPda });
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        withSigners(
+          getWithdrawInstruction({
+            pool: poolPda,
+            inventory,
+            collection,
+            asset: assetPk,
+            authority,
+            coreProgram: MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+          }),
+          [signers[1]]
+        ),
+        tx
+      )
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__MULTISIG_THRESHOLD_NOT_MET
+    )
+  );
+});
+
+test('it cannot change the treasury without enough multisig signers', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const { authority, poolPda, signers } = await setup(client);
+
+  const newTreasury = (await generateKeyPairSigner()).address;
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        withSigners(
+          getUpdatePoolInstruction({
+            pool: poolPda,
+            authority,
+            treasury: newTreasury,
+          }),
+          [signers[1]]
+        ),
+        tx
+      )
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__MULTISIG_THRESHOLD_NOT_MET
+    )
+  );
+});
+
+test('it cannot change the fee split without enough multisig signers', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const { authority, poolPda, signers } = await setup(client);
+
+  const address = (await generateKeyPairSigner()).address;
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        withSigners(
+          getSetFeeSplitInstruction({
+            pool: poolPda,
+            authority,
+            feeSplit: [{ address, bps: 10000 }],
+          }),
+          [signers[1]]
+        ),
+        tx
+      )
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__MULTISIG_THRESHOLD_NOT_MET
+    )
+  );
+});
+
+test('it cannot change the royalty base without enough multisig signers', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const { authority, poolPda, signers } = await setup(client);
+
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        withSigners(
+          getSetRoyaltyBaseInstruction({
+            pool: poolPda,
+            authority,
+            royaltyBase: RoyaltyBase.Fee,
+          }),
+          [signers[1]]
+        ),
+        tx
+      )
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__MULTISIG_THRESHOLD_NOT_MET
+    )
+  );
+});
+
+test('it cannot change the referral fee without enough multisig signers', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const { authority, poolPda, signers } = await setup(client);
+
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        withSigners(
+          getSetReferralFeeInstruction({
+            pool: poolPda,
+            authority,
+            referralBps: 0,
+          }),
+          [signers[1]]
+        ),
+        tx
+      )
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__MULTISIG_THRESHOLD_NOT_MET
+    )
+  );
+});
+
+test('it cannot change the mode without enough multisig signers', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const { authority, poolPda, signers } = await setup(client);
+
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        withSigners(
+          getSetModeInstruction({
+            pool: poolPda,
+            authority,
+            mode: PoolMode.Mystery,
+          }),
+          [signers[1]]
+        ),
+        tx
+      )
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__MULTISIG_THRESHOLD_NOT_MET
+    )
+  );
+});
+
+test('it cannot change the roles without enough multisig signers', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const { authority, poolPda, signers } = await setup(client);
+
+  const operator = (await generateKeyPairSigner()).address;
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        withSigners(
+          getSetRolesInstruction({
+            pool: poolPda,
+            authority,
+            operator,
+          }),
+          [signers[1]]
+        ),
+        tx
+      )
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__MULTISIG_THRESHOLD_NOT_MET
+    )
+  );
+});
+
+test('it cannot set a threshold above the number of signers', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const authority = await generateKeyPairSignerWithSol(client);
+  const collection = await createCoreCollection(client, authority);
+  const treasury = (await generateKeyPairSigner()).address;
+  const [poolPda] = await createPoolForAuthority(
+    client,
+    authority,
+    collection,
+    treasury
+  );
+
+  const signers = await Promise.all([
+    generateKeyPairSigner(),
+    generateKeyPairSigner(),
+  ]);
+
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getSetMultisigInstruction({
+          pool: poolPda,
+          authority,
+          threshold: 3,
+          signers: signers.map((signer) => signer.address),
+        }),
+        tx
+      )
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__INVALID_MULTISIG
+    )
+  );
+});
+
+test('the multisig must approve its own removal', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const { authority, poolPda, signers } = await setup(client);
+
+  // The authority alone cannot remove the multisig.
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getSetMultisigInstruction({
+          pool: poolPda,
+          authority,
+          threshold: 0,
+          signers: [],
+        }),
+        tx
+      )
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__MULTISIG_THRESHOLD_NOT_MET
+    )
+  );
+
+  await setMultisig(client, authority, poolPda, 0, [], signers.slice(0, 2));
+
+  t.like(await fetchPool(client.rpc, poolPda), <Account<Pool>>{
+    data: { multisig: none() },
+  });
+});
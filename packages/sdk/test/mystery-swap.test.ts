@@ -0,0 +1,682 @@
+import test from 'ava';
+import {
+  createAndDepositAsset,
+  createDefaultSolanaClient,
+  createDefaultTransaction,
+  createPoolForAuthority,
+  generateKeyPairSignerWithSol,
+  setPoolStatus,
+  setPoolMode,
+  signAndSendTransaction,
+  waitForSlot,
+  withRemainingAccounts,
+} from './_setup';
+import { createCoreAsset, createCoreCollection } from './_mpl-core';
+import {
+  Account,
+  Address,
+  appendTransactionMessageInstruction,
+  generateKeyPairSigner,
+  isProgramError,
+  isSolanaError,
+  pipe,
+  SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE,
+} from '@solana/kit';
+import {
+  FeeVault,
+  fetchFeeVault,
+  fetchMaybeSwapCommit,
+  fetchPool,
+  fetchSwapCommit,
+  fetchSwapCommitFromSeeds,
+  findFeeVaultPda,
+  findInventoryPda,
+  findMysteryDestination,
+  findSwapCommitPda,
+  FLOOR_SWAP_ERROR__INVALID_ACCOUNT_KEY,
+  FLOOR_SWAP_ERROR__INVALID_POOL_MODE,
+  FLOOR_SWAP_ERROR__OPEN_SWAP_COMMITS,
+  FLOOR_SWAP_ERROR__POOL_HAS_NO_ASSETS,
+  FLOOR_SWAP_ERROR__SWAP_COMMIT_NOT_EXPIRED,
+  FLOOR_SWAP_ERROR__SWAP_COMMIT_NOT_NEXT,
+  FLOOR_SWAP_PROGRAM_ADDRESS,
+  getCancelSwapInstructionAsync,
+  getCloseAndDrainInstructionAsync,
+  getCommitSwapInstructionAsync,
+  getRevealSwapInstructionAsync,
+  getSwapInstruction,
+  getWithdrawInstruction,
+  Pool,
+  PoolMode,
+  PoolStatus,
+  SwapCommit,
+} from '../src';
+import {
+  AssetV1,
+  fetchAssetV1,
+  MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+} from '../sdks/mpl-core/generated';
+
+test('it can commit and reveal a mystery swap', async (t) => {
+  t.timeout(60000);
+  const client = createDefaultSolanaClient();
+  const authority = await generateKeyPairSignerWithSol(client);
+  const collection = await createCoreCollection(client, authority);
+  const treasury = (await generateKeyPairSigner()).address;
+  const [poolPda] = await createPoolForAuthority(
+    client,
+    authority,
+    collection,
+    treasury
+  );
+
+  const poolAssetPks = [
+    await createAndDepositAsset(client, authority, collection, poolPda),
+    await createAndDepositAsset(client, authority, collection, poolPda),
+    await createAndDepositAsset(client, authority, collection, poolPda),
+  ];
+
+  const payer = await generateKeyPairSignerWithSol(client);
+
+  const sourceAssetPk = await createCoreAsset(
+    client,
+    authority,
+    collection,
+    payer.address
+  );
+
+  await setPoolStatus(client, authority, poolPda, PoolStatus.Active);
+  await setPoolMode(client, authority, poolPda, PoolMode.Mystery);
+
+  const commitIx = await getCommitSwapInstructionAsync({
+    pool: poolPda,
+    sourceAsset: sourceAssetPk,
+    payer,
+    collection,
+    coreProgram: MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+    maxFee: 10000000n,
+  });
+  await pipe(
+    await createDefaultTransaction(client, payer),
+    (tx) => appendTransactionMessageInstruction(commitIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  const swapCommit = await fetchSwapCommitFromSeeds(client.rpc, {
+    pool: poolPda,
+    sourceAsset: sourceAssetPk,
+  });
+
+  t.like(swapCommit, <Account<SwapCommit>>{
+    data: {
+      pool: poolPda,
+      owner: payer.address,
+      sourceAsset: sourceAssetPk,
+    },
+  });
+
+  // The destination is picked with the hash of a slot after the commit.
+  await waitForSlot(client, swapCommit.data.slot + 2n);
+
+  const destAssetPk = await findMysteryDestination(
+    client.rpc,
+    swapCommit.address
+  );
+  if (!destAssetPk) throw new Error('The swap cannot be revealed');
+  t.true(poolAssetPks.includes(destAssetPk));
+
+  const revealIx = await getRevealSwapInstructionAsync({
+    pool: poolPda,
+    sourceAsset: sourceAssetPk,
+    owner: payer.address,
+    payer,
+    collection,
+    coreProgram: MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+    destAsset: destAssetPk,
+  });
+  await pipe(
+    await createDefaultTransaction(client, payer),
+    (tx) => appendTransactionMessageInstruction(revealIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  const sourceAsset = await fetchAssetV1(client.rpc, sourceAssetPk);
+  t.like(sourceAsset, <Account<AssetV1>>{
+    data: {
+      owner: poolPda,
+    },
+  });
+
+  const poolAssets = await Promise.all(
+    poolAssetPks.map((assetPk) => fetchAssetV1(client.rpc, assetPk))
+  );
+  t.deepEqual(
+    poolAssets
+      .filter((asset) => asset.data.owner === payer.address)
+      .map((asset) => asset.address),
+    [destAssetPk]
+  );
+  t.like(await fetchPool(client.rpc, poolPda), <Account<Pool>>{
+    data: { openCommits: 0 },
+  });
+
+  t.false((await fetchMaybeSwapCommit(client.rpc, swapCommit.address)).exists);
+  const [feeVault] = await findFeeVaultPda({ pool: poolPda });
+  t.like(await fetchFeeVault(client.rpc, feeVault), <Account<FeeVault>>{
+    data: {
+      lifetimeFees: 10000000n,
+    },
+  });
+});
+
+test('it cannot pick the destination asset in a mystery pool', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const authority = await generateKeyPairSignerWithSol(client);
+  const collection = await createCoreCollection(client, authority);
+  const treasury = (await generateKeyPairSigner()).address;
+  const [poolPda] = await createPoolForAuthority(
+    client,
+    authority,
+    collection,
+    treasury
+  );
+
+  const destAssetPk = await createAndDepositAsset(
+    client,
+    authority,
+    collection,
+    poolPda
+  );
+
+  const payer = await generateKeyPairSignerWithSol(client);
+
+  const sourceAssetPk = await createCoreAsset(
+    client,
+    authority,
+    collection,
+    payer.address
+  );
+
+  await setPoolStatus(client, authority, poolPda, PoolStatus.Active);
+  await setPoolMode(client, authority, poolPda, PoolMode.Mystery);
+
+  const [feeVault] = await findFeeVaultPda({ pool: poolPda });
+  const [inventory] = await findInventoryPda({ pool: poolPda });
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, payer),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getSwapInstruction({
+          pool: poolPda,
+          inventory,
+          collection,
+          sourceAsset: sourceAssetPk,
+          destAsset: destAssetPk,
+          payer,
+          feeVault,
+          coreProgram: MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+          maxFee: 10000000n,
+        }),
+        tx
+      )
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__INVALID_POOL_MODE
+    )
+  );
+});
+
+test('it cannot cancel a mystery swap that can still be revealed', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const authority = await generateKeyPairSignerWithSol(client);
+  const collection = await createCoreCollection(client, authority);
+  const treasury = (await generateKeyPairSigner()).address;
+  const [poolPda] = await createPoolForAuthority(
+    client,
+    authority,
+    collection,
+    treasury
+  );
+
+  await createAndDepositAsset(client, authority, collection, poolPda);
+
+  const payer = await generateKeyPairSignerWithSol(client);
+
+  const sourceAssetPk = await createCoreAsset(
+    client,
+    authority,
+    collection,
+    payer.address
+  );
+
+  await setPoolStatus(client, authority, poolPda, PoolStatus.Active);
+  await setPoolMode(client, authority, poolPda, PoolMode.Mystery);
+
+  const commitIx = await getCommitSwapInstructionAsync({
+    pool: poolPda,
+    sourceAsset: sourceAssetPk,
+    payer,
+    collection,
+    coreProgram: MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+    maxFee: 10000000n,
+  });
+  await pipe(
+    await createDefaultTransaction(client, payer),
+    (tx) => appendTransactionMessageInstruction(commitIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  const cancelIx = await getCancelSwapInstructionAsync({
+    pool: poolPda,
+    sourceAsset: sourceAssetPk,
+    owner: payer.address,
+    payer,
+    collection,
+    coreProgram: MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+  });
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, payer),
+    (tx) => appendTransactionMessageInstruction(cancelIx, tx)
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__SWAP_COMMIT_NOT_EXPIRED
+    )
+  );
+});
+
+test('it cannot reveal a mystery swap with another asset', async (t) => {
+  t.timeout(60000);
+  const client = createDefaultSolanaClient();
+  const authority = await generateKeyPairSignerWithSol(client);
+  const collection = await createCoreCollection(client, authority);
+  const treasury = (await generateKeyPairSigner()).address;
+  const [poolPda] = await createPoolForAuthority(
+    client,
+    authority,
+    collection,
+    treasury
+  );
+
+  const poolAssetPks = [
+    await createAndDepositAsset(client, authority, collection, poolPda),
+    await createAndDepositAsset(client, authority, collection, poolPda),
+  ];
+
+  const payer = await generateKeyPairSignerWithSol(client);
+
+  const sourceAssetPk = await createCoreAsset(
+    client,
+    authority,
+    collection,
+    payer.address
+  );
+
+  await setPoolStatus(client, authority, poolPda, PoolStatus.Active);
+  await setPoolMode(client, authority, poolPda, PoolMode.Mystery);
+
+  const commitIx = await getCommitSwapInstructionAsync({
+    pool: poolPda,
+    sourceAsset: sourceAssetPk,
+    payer,
+    collection,
+    coreProgram: MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+    maxFee: 10000000n,
+  });
+  await pipe(
+    await createDefaultTransaction(client, payer),
+    (tx) => appendTransactionMessageInstruction(commitIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  const [swapCommit] = await findSwapCommitPda({
+    pool: poolPda,
+    sourceAsset: sourceAssetPk,
+  });
+  const { data } = await fetchSwapCommit(client.rpc, swapCommit);
+  await waitForSlot(client, data.slot + 2n);
+
+  const pickedAssetPk = await findMysteryDestination(client.rpc, swapCommit);
+  const otherAssetPk = poolAssetPks.find((pk) => pk !== pickedAssetPk);
+  if (!otherAssetPk) throw new Error('Expected another pool asset');
+
+  const revealIx = await getRevealSwapInstructionAsync({
+    pool: poolPda,
+    sourceAsset: sourceAssetPk,
+    owner: payer.address,
+    payer,
+    collection,
+    coreProgram: MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+    destAsset: otherAssetPk,
+  });
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, payer),
+    (tx) => appendTransactionMessageInstruction(revealIx, tx)
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__INVALID_ACCOUNT_KEY
+    )
+  );
+});
+
+test('mystery swaps are revealed in the order they were committed', async (t) => {
+  t.timeout(60000);
+  const client = createDefaultSolanaClient();
+  const authority = await generateKeyPairSignerWithSol(client);
+  const collection = await createCoreCollection(client, authority);
+  const treasury = (await generateKeyPairSigner()).address;
+  const [poolPda] = await createPoolForAuthority(
+    client,
+    authority,
+    collection,
+    treasury
+  );
+
+  const poolAssetPks = [
+    await createAndDepositAsset(client, authority, collection, poolPda),
+    await createAndDepositAsset(client, authority, collection, poolPda),
+  ];
+
+  const payers = [
+    await generateKeyPairSignerWithSol(client),
+    await generateKeyPairSignerWithSol(client),
+  ];
+  const sourceAssetPks = [
+    await createCoreAsset(client, authority, collection, payers[0].address),
+    await createCoreAsset(client, authority, collection, payers[1].address),
+  ];
+
+  await setPoolStatus(client, authority, poolPda, PoolStatus.Active);
+  await setPoolMode(client, authority, poolPda, PoolMode.Mystery);
+
+  for (const [index, payer] of payers.entries()) {
+    const commitIx = await getCommitSwapInstructionAsync({
+      pool: poolPda,
+      sourceAsset: sourceAssetPks[index],
+      payer,
+      collection,
+      coreProgram: MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+      maxFee: 10000000n,
+    });
+    await pipe(
+      await createDefaultTransaction(client, payer),
+      (tx) => appendTransactionMessageInstruction(commitIx, tx),
+      (tx) => signAndSendTransaction(client, tx)
+    );
+  }
+
+  const swapCommits = await Promise.all(
+    sourceAssetPks.map(async (sourceAsset) => {
+      const [swapCommit] = await findSwapCommitPda({
+        pool: poolPda,
+        sourceAsset,
+      });
+      return swapCommit;
+    })
+  );
+  const { data } = await fetchSwapCommit(client.rpc, swapCommits[1]);
+  t.is(data.ticket, 1n);
+  await waitForSlot(client, data.slot + 2n);
+
+  const getRevealIx = (index: number, destAsset: Address) =>
+    getRevealSwapInstructionAsync({
+      pool: poolPda,
+      sourceAsset: sourceAssetPks[index],
+      owner: payers[index].address,
+      payer: payers[index],
+      collection,
+      coreProgram: MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+      destAsset,
+    });
+
+  // The second swap cannot be revealed before the first one.
+  t.is(await findMysteryDestination(client.rpc, swapCommits[1]), null);
+  const outOfOrderIx = await getRevealIx(1, poolAssetPks[0]);
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, payers[1]),
+    (tx) => appendTransactionMessageInstruction(outOfOrderIx, tx)
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__SWAP_COMMIT_NOT_NEXT
+    )
+  );
+
+  // Revealed in commit order, each swap gets the asset its slot hash picks.
+  for (const [index, swapCommit] of swapCommits.entries()) {
+    const destAssetPk = await findMysteryDestination(client.rpc, swapCommit);
+    if (!destAssetPk) throw new Error('The swap cannot be revealed');
+    const revealIx = await getRevealIx(index, destAssetPk);
+    await pipe(
+      await createDefaultTransaction(client, payers[index]),
+      (tx) => appendTransactionMessageInstruction(revealIx, tx),
+      (tx) => signAndSendTransaction(client, tx)
+    );
+
+    t.like(await fetchAssetV1(client.rpc, destAssetPk), <Account<AssetV1>>{
+      data: { owner: payers[index].address },
+    });
+  }
+
+  t.like(await fetchPool(client.rpc, poolPda), <Account<Pool>>{
+    data: { numAssets: 2, openCommits: 0, commitCount: 2n },
+  });
+});
+
+test('it cannot withdraw or close a pool with an open mystery swap', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const authority = await generateKeyPairSignerWithSol(client);
+  const collection = await createCoreCollection(client, authority);
+  const treasury = (await generateKeyPairSigner()).address;
+  const [poolPda] = await createPoolForAuthority(
+    client,
+    authority,
+    collection,
+    treasury
+  );
+
+  const poolAssetPk = await createAndDepositAsset(
+    client,
+    authority,
+    collection,
+    poolPda
+  );
+
+  const payer = await generateKeyPairSignerWithSol(client);
+
+  const sourceAssetPk = await createCoreAsset(
+    client,
+    authority,
+    collection,
+    payer.address
+  );
+
+  await setPoolStatus(client, authority, poolPda, PoolStatus.Active);
+  await setPoolMode(client, authority, poolPda, PoolMode.Mystery);
+
+  const commitIx = await getCommitSwapInstructionAsync({
+    pool: poolPda,
+    sourceAsset: sourceAssetPk,
+    payer,
+    collection,
+    coreProgram: MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+    maxFee: 10000000n,
+  });
+  await pipe(
+    await createDefaultTransaction(client, payer),
+    (tx) => appendTransactionMessageInstruction(commitIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  t.like(await fetchPool(client.rpc, poolPda), <Account<Pool>>{
+    data: { openCommits: 1 },
+  });
+
+  // The escrowed asset would be stranded if the pool could close.
+  await setPoolStatus(client, authority, poolPda, PoolStatus.Closing);
+
+  const [inventory] = await findInventoryPda({ pool: poolPda });
+  const withdrawMessage = pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getWithdrawInstruction({
+          pool: poolPda,
+          inventory,
+          collection,
+          asset: poolAssetPk,
+          authority,
+          coreProgram: MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+        }),
+        tx
+      )
+  );
+
+  const withdrawError = await t.throwsAsync(
+    signAndSendTransaction(client, withdrawMessage)
+  );
+  t.true(
+    isProgramError(
+      withdrawError.cause,
+      withdrawMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__OPEN_SWAP_COMMITS
+    )
+  );
+
+  const closeIx = await getCloseAndDrainInstructionAsync({
+    pool: poolPda,
+    authority,
+    collection,
+    coreProgram: MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+  });
+  const closeMessage = pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        withRemainingAccounts(closeIx, [poolAssetPk]),
+        tx
+      )
+  );
+
+  const closeError = await t.throwsAsync(
+    signAndSendTransaction(client, closeMessage)
+  );
+  t.true(
+    isProgramError(
+      closeError.cause,
+      closeMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__OPEN_SWAP_COMMITS
+    )
+  );
+});
+
+test('it cannot commit a mystery swap on an empty pool', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const authority = await generateKeyPairSignerWithSol(client);
+  const collection = await createCoreCollection(client, authority);
+  const treasury = (await generateKeyPairSigner()).address;
+  const [poolPda] = await createPoolForAuthority(
+    client,
+    authority,
+    collection,
+    treasury
+  );
+
+  const payer = await generateKeyPairSignerWithSol(client);
+
+  const sourceAssetPk = await createCoreAsset(
+    client,
+    authority,
+    collection,
+    payer.address
+  );
+
+  await setPoolStatus(client, authority, poolPda, PoolStatus.Active);
+  await setPoolMode(client, authority, poolPda, PoolMode.Mystery);
+
+  const commitIx = await getCommitSwapInstructionAsync({
+    pool: poolPda,
+    sourceAsset: sourceAssetPk,
+    payer,
+    collection,
+    coreProgram: MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+    maxFee: 10000000n,
+  });
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, payer),
+    (tx) => appendTransactionMessageInstruction(commitIx, tx)
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__POOL_HAS_NO_ASSETS
+    )
+  );
+});
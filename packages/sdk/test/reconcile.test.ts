@@ -0,0 +1,272 @@
+import test from 'ava';
+import {
+  Client,
+  createAndDepositAsset,
+  createDefaultSolanaClient,
+  createDefaultTransaction,
+  createPoolForAuthority,
+  generateKeyPairSignerWithSol,
+  signAndSendTransaction,
+  withRemainingAccounts,
+} from './_setup';
+import { createCoreAsset, createCoreCollection } from './_mpl-core';
+import {
+  Account,
+  AccountRole,
+  appendTransactionMessageInstruction,
+  generateKeyPairSigner,
+  isProgramError,
+  isSolanaError,
+  pipe,
+  SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE,
+} from '@solana/kit';
+import {
+  fetchInventoryFromSeeds,
+  fetchPool,
+  findInventoryPda,
+  FLOOR_SWAP_ERROR__INVALID_ASSET_OWNER,
+  FLOOR_SWAP_PROGRAM_ADDRESS,
+  getDepositInstruction,
+  getReconcileInstruction,
+  Pool,
+} from '../src';
+import { plugin } from '../../mpl-core/generated';
+import {
+  getTransferV1Instruction,
+  MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+} from '../sdks/mpl-core/generated';
+
+// The permanent transfer delegate lets the collection authority move assets
+// out of the pool, leaving stale entries in its inventory.
+const setupStaleAsset = async (client: Client) => {
+  const authority = await generateKeyPairSignerWithSol(client);
+  const collection = await createCoreCollection(
+    client,
+    authority,
+    undefined,
+    undefined,
+    [{ plugin: plugin('PermanentTransferDelegate', [{}]), authority: null }]
+  );
+  const treasury = (await generateKeyPairSigner()).address;
+  const [poolPda] = await createPoolForAuthority(
+    client,
+    authority,
+    collection,
+    treasury
+  );
+  const assetPk = await createAndDepositAsset(
+    client,
+    authority,
+    collection,
+    poolPda
+  );
+
+  await pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getTransferV1Instruction({
+          asset: assetPk,
+          newOwner: authority.address,
+          authority,
+          payer: authority,
+          collection,
+          compressionProof: null,
+        }),
+        tx
+      ),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  return { authority, collection, poolPda, assetPk };
+};
+
+test('anyone can reconcile assets sent to the pool without a deposit', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const authority = await generateKeyPairSignerWithSol(client);
+  const collection = await createCoreCollection(client, authority);
+  const treasury = (await generateKeyPairSigner()).address;
+  const [poolPda] = await createPoolForAuthority(
+    client,
+    authority,
+    collection,
+    treasury
+  );
+  const depositedAssetPk = await createAndDepositAsset(
+    client,
+    authority,
+    collection,
+    poolPda
+  );
+
+  const sentAssetPk = await createCoreAsset(client, authority, collection);
+  await pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getTransferV1Instruction({
+          asset: sentAssetPk,
+          newOwner: poolPda,
+          authority,
+          payer: authority,
+          collection,
+          compressionProof: null,
+        }),
+        tx
+      ),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  t.like(await fetchPool(client.rpc, poolPda), <Account<Pool>>{
+    data: { numAssets: 1 },
+  });
+
+  // Assets already in the inventory are skipped.
+  const payer = await generateKeyPairSignerWithSol(client);
+  const [inventory] = await findInventoryPda({ pool: poolPda });
+  await pipe(
+    await createDefaultTransaction(client, payer),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        withRemainingAccounts(
+          getReconcileInstruction({
+            pool: poolPda,
+            inventory,
+            collection,
+            payer,
+          }),
+          [depositedAssetPk, sentAssetPk],
+          AccountRole.READONLY
+        ),
+        tx
+      ),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  t.like(await fetchPool(client.rpc, poolPda), <Account<Pool>>{
+    data: { numAssets: 2 },
+  });
+  const { data } = await fetchInventoryFromSeeds(client.rpc, { pool: poolPda });
+  t.deepEqual(data.assets, [depositedAssetPk, sentAssetPk]);
+});
+
+test('it cannot reconcile an asset not owned by the pool', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const authority = await generateKeyPairSignerWithSol(client);
+  const collection = await createCoreCollection(client, authority);
+  const treasury = (await generateKeyPairSigner()).address;
+  const [poolPda] = await createPoolForAuthority(
+    client,
+    authority,
+    collection,
+    treasury
+  );
+
+  const assetPk = await createCoreAsset(client, authority, collection);
+
+  const [inventory] = await findInventoryPda({ pool: poolPda });
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        withRemainingAccounts(
+          getReconcileInstruction({
+            pool: poolPda,
+            inventory,
+            collection,
+            payer: authority,
+          }),
+          [assetPk],
+          AccountRole.READONLY
+        ),
+        tx
+      )
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__INVALID_ASSET_OWNER
+    )
+  );
+});
+
+test('it drops assets the pool no longer holds when reconciling', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const { authority, collection, poolPda, assetPk } =
+    await setupStaleAsset(client);
+
+  t.like(await fetchPool(client.rpc, poolPda), <Account<Pool>>{
+    data: { numAssets: 1 },
+  });
+
+  const [inventory] = await findInventoryPda({ pool: poolPda });
+  await pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        withRemainingAccounts(
+          getReconcileInstruction({
+            pool: poolPda,
+            inventory,
+            collection,
+            payer: authority,
+          }),
+          [assetPk],
+          AccountRole.READONLY
+        ),
+        tx
+      ),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  t.like(await fetchPool(client.rpc, poolPda), <Account<Pool>>{
+    data: { numAssets: 0 },
+  });
+  const { data } = await fetchInventoryFromSeeds(client.rpc, { pool: poolPda });
+  t.deepEqual(data.assets, []);
+});
+
+test('depositing an asset still in the inventory does not track it twice', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const { authority, collection, poolPda, assetPk } =
+    await setupStaleAsset(client);
+
+  const [inventory] = await findInventoryPda({ pool: poolPda });
+  await pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getDepositInstruction({
+          pool: poolPda,
+          inventory,
+          collection,
+          asset: assetPk,
+          payer: authority,
+          coreProgram: MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+        }),
+        tx
+      ),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  t.like(await fetchPool(client.rpc, poolPda), <Account<Pool>>{
+    data: { numAssets: 1 },
+  });
+  const { data } = await fetchInventoryFromSeeds(client.rpc, { pool: poolPda });
+  t.deepEqual(data.assets, [assetPk]);
+});
@@ -0,0 +1,370 @@
+import test from 'ava';
+import {
+  Client,
+  createAndDepositAsset,
+  createDefaultSolanaClient,
+  createDefaultTransaction,
+  createPoolForAuthority,
+  generateKeyPairSignerWithSol,
+  getBalance,
+  setPoolStatus,
+  signAndSendTransaction,
+} from './_setup';
+import { createCoreAsset, createCoreCollection } from './_mpl-core';
+import {
+  Account,
+  Address,
+  appendTransactionMessageInstruction,
+  generateKeyPairSigner,
+  isProgramError,
+  isSolanaError,
+  KeyPairSigner,
+  pipe,
+  SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE,
+} from '@solana/kit';
+import {
+  feeModel,
+  FeeVault,
+  fetchFeeVault,
+  fetchPool,
+  findFeeVaultPda,
+  findInventoryPda,
+  FLOOR_SWAP_ERROR__INVALID_REFERRAL_FEE,
+  FLOOR_SWAP_ERROR__INVALID_REFERRER,
+  FLOOR_SWAP_PROGRAM_ADDRESS,
+  getSetReferralFeeInstruction,
+  getSwapInstruction,
+  Pool,
+  PoolStatus,
+} from '../src';
+import { MPL_CORE_PROGRAM_PROGRAM_ADDRESS } from '../sdks/mpl-core/generated';
+
+const setReferralFee = async (
+  client: Client,
+  authority: KeyPairSigner,
+  pool: Address,
+  referralBps: number
+) => {
+  await pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getSetReferralFeeInstruction({ pool, authority, referralBps }),
+        tx
+      ),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+};
+
+const setup = async (client: Client) => {
+  const authority = await generateKeyPairSignerWithSol(client);
+  const collection = await createCoreCollection(client, authority);
+  const treasury = (await generateKeyPairSigner()).address;
+  const [poolPda] = await createPoolForAuthority(
+    client,
+    authority,
+    collection,
+    treasury,
+    feeModel('Flat', { lamports: 100_000_000n }), // 0.1 sol
+    { maxReferralBps: 2000 }
+  );
+
+  const destAssetPk = await createAndDepositAsset(
+    client,
+    authority,
+    collection,
+    poolPda
+  );
+
+  const payer = await generateKeyPairSignerWithSol(client);
+
+  const sourceAssetPk = await createCoreAsset(
+    client,
+    authority,
+    collection,
+    payer.address
+  );
+
+  await setPoolStatus(client, authority, poolPda, PoolStatus.Active);
+
+  const [feeVault] = await findFeeVaultPda({ pool: poolPda });
+
+  return {
+    authority,
+    collection,
+    poolPda,
+    feeVault,
+    destAssetPk,
+    payer,
+    sourceAssetPk,
+  };
+};
+
+const swapWithReferrer = async (
+  client: Client,
+  {
+    collection,
+    poolPda,
+    feeVault,
+    destAssetPk,
+    payer,
+    sourceAssetPk,
+  }: Awaited<ReturnType<typeof setup>>,
+  referrer: Address
+) => {
+  const [inventory] = await findInventoryPda({ pool: poolPda });
+  return pipe(
+    await createDefaultTransaction(client, payer),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getSwapInstruction({
+          pool: poolPda,
+          inventory,
+          collection,
+          sourceAsset: sourceAssetPk,
+          destAsset: destAssetPk,
+          payer,
+          feeVault,
+          coreProgram: MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+          referrer,
+          maxFee: 100_000_000n,
+        }),
+        tx
+      )
+  );
+};
+
+test('it pays the referral share of the fee to the referrer', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const {
+    authority,
+    collection,
+    poolPda,
+    feeVault,
+    destAssetPk,
+    payer,
+    sourceAssetPk,
+  } = await setup(client);
+
+  await setReferralFee(client, authority, poolPda, 2000);
+
+  t.like(await fetchPool(client.rpc, poolPda), <Account<Pool>>{
+    data: { referralBps: 2000 },
+  });
+
+  const referrer = (await generateKeyPairSigner()).address;
+
+  const [inventory] = await findInventoryPda({ pool: poolPda });
+  await pipe(
+    await createDefaultTransaction(client, payer),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getSwapInstruction({
+          pool: poolPda,
+          inventory,
+          collection,
+          sourceAsset: sourceAssetPk,
+          destAsset: destAssetPk,
+          payer,
+          feeVault,
+          coreProgram: MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+          referrer,
+          maxFee: 100_000_000n,
+        }),
+        tx
+      ),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  // 20% of the 0.1 sol fee goes to the referrer, the rest to the fee vault.
+  t.deepEqual(await getBalance(client, referrer), 20_000_000n);
+  t.like(await fetchFeeVault(client.rpc, feeVault), <Account<FeeVault>>{
+    data: {
+      lifetimeFees: 80_000_000n,
+    },
+  });
+});
+
+test('it keeps the whole fee when the swap has no referrer', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const {
+    authority,
+    collection,
+    poolPda,
+    feeVault,
+    destAssetPk,
+    payer,
+    sourceAssetPk,
+  } = await setup(client);
+
+  await setReferralFee(client, authority, poolPda, 2000);
+
+  const [inventory] = await findInventoryPda({ pool: poolPda });
+  await pipe(
+    await createDefaultTransaction(client, payer),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getSwapInstruction({
+          pool: poolPda,
+          inventory,
+          collection,
+          sourceAsset: sourceAssetPk,
+          destAsset: destAssetPk,
+          payer,
+          feeVault,
+          coreProgram: MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+          maxFee: 100_000_000n,
+        }),
+        tx
+      ),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  t.like(await fetchFeeVault(client.rpc, feeVault), <Account<FeeVault>>{
+    data: {
+      lifetimeFees: 100_000_000n,
+    },
+  });
+});
+
+test('it cannot set a referral fee above the maximum of the pool', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const authority = await generateKeyPairSignerWithSol(client);
+  const collection = await createCoreCollection(client, authority);
+  const treasury = (await generateKeyPairSigner()).address;
+  const [poolPda] = await createPoolForAuthority(
+    client,
+    authority,
+    collection,
+    treasury,
+    undefined,
+    { maxReferralBps: 2000 }
+  );
+
+  t.like(await fetchPool(client.rpc, poolPda), <Account<Pool>>{
+    data: { maxReferralBps: 2000 },
+  });
+
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getSetReferralFeeInstruction({
+          pool: poolPda,
+          authority,
+          referralBps: 2001,
+        }),
+        tx
+      )
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__INVALID_REFERRAL_FEE
+    )
+  );
+});
+
+test('it cannot refer its own swap', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const accounts = await setup(client);
+  await setReferralFee(client, accounts.authority, accounts.poolPda, 2000);
+
+  const transactionMessage = await swapWithReferrer(
+    client,
+    accounts,
+    accounts.payer.address
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__INVALID_REFERRER
+    )
+  );
+});
+
+test('it cannot use the fee vault as the referrer', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const accounts = await setup(client);
+  await setReferralFee(client, accounts.authority, accounts.poolPda, 2000);
+
+  const transactionMessage = await swapWithReferrer(
+    client,
+    accounts,
+    accounts.feeVault
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__INVALID_REFERRER
+    )
+  );
+});
+
+test('it cannot use the pool as the referrer', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const accounts = await setup(client);
+  await setReferralFee(client, accounts.authority, accounts.poolPda, 2000);
+
+  const transactionMessage = await swapWithReferrer(
+    client,
+    accounts,
+    accounts.poolPda
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__INVALID_REFERRER
+    )
+  );
+});
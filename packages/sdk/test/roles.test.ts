@@ -0,0 +1,282 @@
+import test from 'ava';
+import {
+  Client,
+  createAndDepositAsset,
+  createDefaultSolanaClient,
+  createDefaultTransaction,
+  createPoolForAuthority,
+  generateKeyPairSignerWithSol,
+  setPoolStatus,
+  signAndSendTransaction,
+} from './_setup';
+import { createCoreCollection } from './_mpl-core';
+import {
+  Account,
+  Address,
+  appendTransactionMessageInstruction,
+  generateKeyPairSigner,
+  isProgramError,
+  isSolanaError,
+  KeyPairSigner,
+  none,
+  pipe,
+  SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE,
+  some,
+} from '@solana/kit';
+import {
+  feeModel,
+  fetchPool,
+  findInventoryPda,
+  FLOOR_SWAP_ERROR__ACCOUNT_MISMATCH,
+  FLOOR_SWAP_ERROR__OPERATOR_STATUS_NOT_ALLOWED,
+  FLOOR_SWAP_PROGRAM_ADDRESS,
+  getSetFeeInstruction,
+  getSetRolesInstruction,
+  getSetStatusInstruction,
+  getWithdrawInstruction,
+  Pool,
+  PoolStatus,
+} from '../src';
+import { MPL_CORE_PROGRAM_PROGRAM_ADDRESS } from '../sdks/mpl-core/generated';
+
+const setRoles = async (
+  client: Client,
+  authority: KeyPairSigner,
+  pool: Address,
+  { operator, feeManager }: { operator?: Address; feeManager?: Address }
+) => {
+  await pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getSetRolesInstruction({ pool, authority, operator, feeManager }),
+        tx
+      ),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+};
+
+const setup = async (client: Client) => {
+  const authority = await generateKeyPairSignerWithSol(client);
+  const collection = await createCoreCollection(client, authority);
+  const treasury = (await generateKeyPairSigner()).address;
+  const [poolPda] = await createPoolForAuthority(
+    client,
+    authority,
+    collection,
+    treasury
+  );
+  await setPoolStatus(client, authority, poolPda, PoolStatus.Active);
+
+  const operator = await generateKeyPairSignerWithSol(client);
+  const feeManager = await generateKeyPairSignerWithSol(client);
+  await setRoles(client, authority, poolPda, {
+    operator: operator.address,
+    feeManager: feeManager.address,
+  });
+
+  return { authority, collection, poolPda, operator, feeManager };
+};
+
+test('the operator can pause and resume a pool', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const { poolPda, operator, feeManager } = await setup(client);
+
+  t.like(await fetchPool(client.rpc, poolPda), <Account<Pool>>{
+    data: {
+      operator: some(operator.address),
+      feeManager: some(feeManager.address),
+    },
+  });
+
+  await setPoolStatus(client, operator, poolPda, PoolStatus.Paused);
+  t.like(await fetchPool(client.rpc, poolPda), <Account<Pool>>{
+    data: { status: PoolStatus.Paused },
+  });
+
+  await setPoolStatus(client, operator, poolPda, PoolStatus.Active);
+  t.like(await fetchPool(client.rpc, poolPda), <Account<Pool>>{
+    data: { status: PoolStatus.Active },
+  });
+});
+
+test('the operator cannot wind down a pool', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const { poolPda, operator } = await setup(client);
+
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, operator),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getSetStatusInstruction({
+          pool: poolPda,
+          authority: operator,
+          status: PoolStatus.WindDown,
+        }),
+        tx
+      )
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__OPERATOR_STATUS_NOT_ALLOWED
+    )
+  );
+});
+
+test('the operator cannot withdraw assets', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const { authority, collection, poolPda, operator } = await setup(client);
+
+  const assetPk = await createAndDepositAsset(
+    client,
+    authority,
+    collection,
+    poolPda
+  );
+
+  const [inventory] = await findInventoryPda({ pool: poolPda });
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, operator),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getWithdrawInstruction({
+          pool: poolPda,
+          inventory,
+          collection,
+          asset: assetPk,
+          authority: operator,
+          coreProgram: MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+        }),
+        tx
+      )
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__ACCOUNT_MISMATCH
+    )
+  );
+});
+
+test('the fee manager can lower the fee but not pause the pool', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const { poolPda, feeManager } = await setup(client);
+
+  await pipe(
+    await createDefaultTransaction(client, feeManager),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getSetFeeInstruction({
+          pool: poolPda,
+          authority: feeManager,
+          feeModel: feeModel('Flat', { lamports: 5_000_000n }),
+          feeMint: null,
+        }),
+        tx
+      ),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  t.like(await fetchPool(client.rpc, poolPda), <Account<Pool>>{
+    data: { feeModel: { __kind: 'Flat', lamports: 5_000_000n } },
+  });
+
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, feeManager),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getSetStatusInstruction({
+          pool: poolPda,
+          authority: feeManager,
+          status: PoolStatus.Paused,
+        }),
+        tx
+      )
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__ACCOUNT_MISMATCH
+    )
+  );
+});
+
+test('the authority can revoke roles', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const { authority, poolPda, operator } = await setup(client);
+
+  await setRoles(client, authority, poolPda, {});
+
+  t.like(await fetchPool(client.rpc, poolPda), <Account<Pool>>{
+    data: { operator: none(), feeManager: none() },
+  });
+
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, operator),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getSetStatusInstruction({
+          pool: poolPda,
+          authority: operator,
+          status: PoolStatus.Paused,
+        }),
+        tx
+      )
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__ACCOUNT_MISMATCH
+    )
+  );
+});
@@ -0,0 +1,448 @@
+import test from 'ava';
+import {
+  Client,
+  createAndDepositAsset,
+  createDefaultSolanaClient,
+  createDefaultTransaction,
+  createPoolForAuthority,
+  generateKeyPairSignerWithSol,
+  getBalance,
+  setPoolStatus,
+  signAndSendTransaction,
+  withRemainingAccounts,
+} from './_setup';
+import { createCoreAsset, createCoreCollection } from './_mpl-core';
+import {
+  Account,
+  Address,
+  appendTransactionMessageInstruction,
+  generateKeyPairSigner,
+  isProgramError,
+  isSolanaError,
+  KeyPairSigner,
+  pipe,
+  SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE,
+} from '@solana/kit';
+import {
+  feeModel,
+  FeeVault,
+  fetchFeeVault,
+  fetchPool,
+  findFeeVaultPda,
+  findInventoryPda,
+  FLOOR_SWAP_ERROR__ACCOUNT_MISMATCH,
+  FLOOR_SWAP_ERROR__FEE_INCREASE_NOT_PROPOSED,
+  FLOOR_SWAP_ERROR__INVALID_REMAINING_ACCOUNTS,
+  FLOOR_SWAP_ERROR__SLIPPAGE_EXCEEDED,
+  FLOOR_SWAP_PROGRAM_ADDRESS,
+  getApplyFeeInstruction,
+  getProposeFeeInstruction,
+  getSetReferencePriceInstruction,
+  getSetRoyaltyBaseInstruction,
+  getSwapInstruction,
+  Pool,
+  PoolStatus,
+  RoyaltyBase,
+} from '../src';
+import { plugin, ruleSet } from '../../mpl-core/generated';
+import { MPL_CORE_PROGRAM_PROGRAM_ADDRESS } from '../sdks/mpl-core/generated';
+
+// 5% royalties split 70/30 between two creators.
+const setup = async (client: Client) => {
+  const authority = await generateKeyPairSignerWithSol(client);
+  const creators = [
+    (await generateKeyPairSigner()).address,
+    (await generateKeyPairSigner()).address,
+  ];
+  const collection = await createCoreCollection(
+    client,
+    authority,
+    undefined,
+    undefined,
+    [
+      {
+        plugin: plugin('Royalties', [
+          {
+            basisPoints: 500,
+            creators: [
+              { address: creators[0], percentage: 70 },
+              { address: creators[1], percentage: 30 },
+            ],
+            ruleSet: ruleSet('None'),
+          },
+        ]),
+        authority: null,
+      },
+    ]
+  );
+  const treasury = (await generateKeyPairSigner()).address;
+  const [poolPda] = await createPoolForAuthority(
+    client,
+    authority,
+    collection,
+    treasury,
+    feeModel('Flat', { lamports: 100_000_000n }) // 0.1 sol
+  );
+
+  const destAssetPk = await createAndDepositAsset(
+    client,
+    authority,
+    collection,
+    poolPda
+  );
+
+  const payer = await generateKeyPairSignerWithSol(client);
+
+  const sourceAssetPk = await createCoreAsset(
+    client,
+    authority,
+    collection,
+    payer.address
+  );
+
+  await setPoolStatus(client, authority, poolPda, PoolStatus.Active);
+
+  const [feeVault] = await findFeeVaultPda({ pool: poolPda });
+  const [inventory] = await findInventoryPda({ pool: poolPda });
+
+  return {
+    authority,
+    creators,
+    collection,
+    poolPda,
+    inventory,
+    feeVault,
+    destAssetPk,
+    payer,
+    sourceAssetPk,
+  };
+};
+
+const getSwapWithCreatorsInstruction = (
+  {
+    collection,
+    poolPda,
+    inventory,
+    feeVault,
+    destAssetPk,
+    payer,
+    sourceAssetPk,
+  }: {
+    collection: Address;
+    poolPda: Address;
+    inventory: Address;
+    feeVault: Address;
+    destAssetPk: Address;
+    payer: KeyPairSigner;
+    sourceAssetPk: Address;
+  },
+  creators: Address[],
+  // The 0.1 sol fee plus royalties of up to 0.1 sol.
+  maxFee = 200_000_000n
+) =>
+  withRemainingAccounts(
+    getSwapInstruction({
+      pool: poolPda,
+      inventory,
+      collection,
+      sourceAsset: sourceAssetPk,
+      destAsset: destAssetPk,
+      payer,
+      feeVault,
+      coreProgram: MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+      maxFee,
+    }),
+    creators
+  );
+
+test('it pays creator royalties on the swap fee', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const accounts = await setup(client);
+
+  await pipe(
+    await createDefaultTransaction(client, accounts.payer),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getSwapWithCreatorsInstruction(accounts, accounts.creators),
+        tx
+      ),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  // 5% of the 0.1 sol fee, split 70/30.
+  t.like(
+    await fetchFeeVault(client.rpc, accounts.feeVault),
+    <Account<FeeVault>>{ data: { lifetimeFees: 100_000_000n } }
+  );
+  t.deepEqual(await getBalance(client, accounts.creators[0]), 3_500_000n);
+  t.deepEqual(await getBalance(client, accounts.creators[1]), 1_500_000n);
+});
+
+test('it can charge royalties on the reference price', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const accounts = await setup(client);
+  const { authority, poolPda } = accounts;
+
+  await pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getSetReferencePriceInstruction({
+          pool: poolPda,
+          authority,
+          referencePrice: 2_000_000_000n, // 2 sol
+        }),
+        tx
+      ),
+    // Charging royalties on the higher reference price must be proposed.
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getProposeFeeInstruction({
+          pool: poolPda,
+          authority,
+          feeModel: feeModel('Flat', { lamports: 100_000_000n }),
+          feeMint: null,
+          royaltyBase: RoyaltyBase.ReferencePrice,
+        }),
+        tx
+      ),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getApplyFeeInstruction({ pool: poolPda }),
+        tx
+      ),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  t.like(await fetchPool(client.rpc, poolPda), <Account<Pool>>{
+    data: { royaltyBase: RoyaltyBase.ReferencePrice },
+  });
+
+  await pipe(
+    await createDefaultTransaction(client, accounts.payer),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getSwapWithCreatorsInstruction(accounts, accounts.creators),
+        tx
+      ),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  // 5% of the 2 sol reference price, split 70/30.
+  t.deepEqual(await getBalance(client, accounts.creators[0]), 70_000_000n);
+  t.deepEqual(await getBalance(client, accounts.creators[1]), 30_000_000n);
+});
+
+test('it cannot switch the royalty base to raise the royalties without a proposal', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const { authority, poolPda } = await setup(client);
+  await pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getSetReferencePriceInstruction({
+          pool: poolPda,
+          authority,
+          referencePrice: 2_000_000_000n, // 2 sol
+        }),
+        tx
+      ),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  // When the authority charges royalties on the 2 sol reference price instead.
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getSetRoyaltyBaseInstruction({
+          pool: poolPda,
+          authority,
+          royaltyBase: RoyaltyBase.ReferencePrice,
+        }),
+        tx
+      )
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__FEE_INCREASE_NOT_PROPOSED
+    )
+  );
+});
+
+test('it cannot raise the reference price royalties without a proposal', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const { authority, poolPda } = await setup(client);
+  await pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getProposeFeeInstruction({
+          pool: poolPda,
+          authority,
+          feeModel: feeModel('Flat', { lamports: 100_000_000n }),
+          feeMint: null,
+          referencePrice: 1_000_000_000n, // 1 sol
+          royaltyBase: RoyaltyBase.ReferencePrice,
+        }),
+        tx
+      ),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getApplyFeeInstruction({ pool: poolPda }),
+        tx
+      ),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  // When the authority raises the reference price royalties are charged on.
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getSetReferencePriceInstruction({
+          pool: poolPda,
+          authority,
+          referencePrice: 2_000_000_000n, // 2 sol
+        }),
+        tx
+      )
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__FEE_INCREASE_NOT_PROPOSED
+    )
+  );
+});
+
+test('it cannot swap without the creator accounts', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const accounts = await setup(client);
+
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, accounts.payer),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getSwapWithCreatorsInstruction(accounts, []),
+        tx
+      )
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__INVALID_REMAINING_ACCOUNTS
+    )
+  );
+});
+
+test('it cannot pay royalties to the wrong creator', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const accounts = await setup(client);
+
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, accounts.payer),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getSwapWithCreatorsInstruction(accounts, [
+          accounts.creators[0],
+          accounts.payer.address,
+        ]),
+        tx
+      )
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__ACCOUNT_MISMATCH
+    )
+  );
+});
+
+test('it cannot swap when the royalties exceed the max fee', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const accounts = await setup(client);
+
+  // The max fee only covers the 0.1 sol fee, not the 5% royalties on top.
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, accounts.payer),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getSwapWithCreatorsInstruction(
+          accounts,
+          accounts.creators,
+          100_000_000n
+        ),
+        tx
+      )
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__SLIPPAGE_EXCEEDED
+    )
+  );
+});
@@ -17,14 +17,16 @@ import {
   SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE,
 } from '@solana/kit';
 import {
+  feeModel,
   fetchPoolFromSeeds,
   FLOOR_SWAP_ERROR__ACCOUNT_MISMATCH,
+  FLOOR_SWAP_ERROR__FEE_INCREASE_NOT_PROPOSED,
   FLOOR_SWAP_PROGRAM_ADDRESS,
   getSetFeeInstruction,
   Pool,
 } from '../src';
 
-test('it can change the fee for a pool', async (t) => {
+test('it can lower the fee for a pool', async (t) => {
   t.timeout(30000);
   const client = createDefaultSolanaClient();
   const authority = await generateKeyPairSignerWithSol(client);
@@ -44,7 +46,8 @@ test('it can change the fee for a pool', async (t) => {
         getSetFeeInstruction({
           pool: poolPda,
           authority,
-          feeAmount: 1_000_000_000n, // 1 sol
+          feeModel: feeModel('Flat', { lamports: 5_000_000n }), // 0.005 sol
+          feeMint: null,
         }),
         tx
       ),
@@ -52,13 +55,13 @@ test('it can change the fee for a pool', async (t) => {
   );
 
   const pool = await fetchPoolFromSeeds(client.rpc, {
-    authority: authority.address,
+    creator: authority.address,
     collection,
   });
 
   t.like(pool, <Account<Pool>>{
     data: {
-      feeAmount: 1_000_000_000n,
+      feeModel: { __kind: 'Flat', lamports: 5_000_000n },
     },
   });
 });
@@ -85,7 +88,8 @@ test('it cannot update the fee if not the authority', async (t) => {
         getSetFeeInstruction({
           pool: poolPda,
           authority: unauthorizedSigner,
-          feeAmount: 1_000_000_000n,
+          feeModel: feeModel('Flat', { lamports: 1_000_000_000n }),
+          feeMint: null,
         }),
         tx
       )
@@ -108,3 +112,48 @@ test('it cannot update the fee if not the authority', async (t) => {
     )
   );
 });
+
+test('it cannot raise the fee without proposing it', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const authority = await generateKeyPairSignerWithSol(client);
+  const collection = await createCoreCollection(client, authority);
+  const treasury = (await generateKeyPairSigner()).address;
+  const [poolPda] = await createPoolForAuthority(
+    client,
+    authority,
+    collection,
+    treasury
+  );
+
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getSetFeeInstruction({
+          pool: poolPda,
+          authority,
+          feeModel: feeModel('Flat', { lamports: 20_000_000n }), // 0.02 sol
+          feeMint: null,
+        }),
+        tx
+      )
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__FEE_INCREASE_NOT_PROPOSED
+    )
+  );
+});
@@ -0,0 +1,312 @@
+import test from 'ava';
+import {
+  Client,
+  createAndDepositAsset,
+  createDefaultSolanaClient,
+  createDefaultTransaction,
+  createPoolForAuthority,
+  generateKeyPairSignerWithSol,
+  setPoolMode,
+  setPoolStatus,
+  setPoolFee,
+  signAndSendTransaction,
+  withRemainingAccounts,
+} from './_setup';
+import { createCoreAsset, createCoreCollection } from './_mpl-core';
+import {
+  Account,
+  Address,
+  appendTransactionMessageInstruction,
+  generateKeyPairSigner,
+  isProgramError,
+  isSolanaError,
+  KeyPairSigner,
+  pipe,
+  SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE,
+  some,
+} from '@solana/kit';
+import {
+  feeModel,
+  fetchPool,
+  FLOOR_SWAP_ERROR__SLIPPAGE_EXCEEDED,
+  FLOOR_SWAP_PROGRAM_ADDRESS,
+  getCommitSwapInstructionAsync,
+  getSetFeeInstruction,
+  getSwapBatchInstructionAsync,
+  getSwapInstructionAsync,
+  Pool,
+  PoolMode,
+  PoolStatus,
+} from '../src';
+import {
+  fetchAssetV1,
+  MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+} from '../sdks/mpl-core/generated';
+
+const setFee = async (
+  client: Client,
+  authority: KeyPairSigner,
+  pool: Address,
+  lamports: bigint
+) => {
+  await pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getSetFeeInstruction({
+          pool,
+          authority,
+          feeModel: feeModel('Flat', { lamports }),
+          feeMint: null,
+        }),
+        tx
+      ),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+};
+
+const setup = async (client: Client) => {
+  const authority = await generateKeyPairSignerWithSol(client);
+  const collection = await createCoreCollection(client, authority);
+  const treasury = (await generateKeyPairSigner()).address;
+  const [poolPda] = await createPoolForAuthority(
+    client,
+    authority,
+    collection,
+    treasury
+  );
+
+  const destAssetPk = await createAndDepositAsset(
+    client,
+    authority,
+    collection,
+    poolPda
+  );
+
+  const payer = await generateKeyPairSignerWithSol(client);
+
+  const sourceAssetPk = await createCoreAsset(
+    client,
+    authority,
+    collection,
+    payer.address
+  );
+
+  await setPoolStatus(client, authority, poolPda, PoolStatus.Active);
+
+  return {
+    authority,
+    collection,
+    poolPda,
+    destAssetPk,
+    payer,
+    sourceAssetPk,
+  };
+};
+
+test('it can swap at the expected pool sequence', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const { collection, poolPda, destAssetPk, payer, sourceAssetPk } =
+    await setup(client);
+
+  const pool = await fetchPool(client.rpc, poolPda);
+
+  const swapIx = await getSwapInstructionAsync({
+    pool: poolPda,
+    collection,
+    sourceAsset: sourceAssetPk,
+    destAsset: destAssetPk,
+    payer,
+    coreProgram: MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+    maxFee: 10000000n,
+    expectedSequence: some(pool.data.sequence),
+  });
+  await pipe(
+    await createDefaultTransaction(client, payer),
+    (tx) => appendTransactionMessageInstruction(swapIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  const destAsset = await fetchAssetV1(client.rpc, destAssetPk);
+  t.is(destAsset.data.owner, payer.address);
+});
+
+test('it cannot swap if the fee exceeds the max fee', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const { authority, collection, poolPda, destAssetPk, payer, sourceAssetPk } =
+    await setup(client);
+
+  // The fee is raised after the user saw 0.01 sol.
+  await setPoolFee(
+    client,
+    authority,
+    poolPda,
+    feeModel('Flat', { lamports: 20000000n })
+  );
+
+  const swapIx = await getSwapInstructionAsync({
+    pool: poolPda,
+    collection,
+    sourceAsset: sourceAssetPk,
+    destAsset: destAssetPk,
+    payer,
+    coreProgram: MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+    maxFee: 10000000n,
+  });
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, payer),
+    (tx) => appendTransactionMessageInstruction(swapIx, tx)
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__SLIPPAGE_EXCEEDED
+    )
+  );
+});
+
+test('it cannot swap if the pool changed since the expected sequence', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const { authority, collection, poolPda, destAssetPk, payer, sourceAssetPk } =
+    await setup(client);
+
+  const pool = await fetchPool(client.rpc, poolPda);
+
+  // Setting the same fee still changes the terms of the pool.
+  await setFee(client, authority, poolPda, 10000000n);
+
+  t.like(await fetchPool(client.rpc, poolPda), <Account<Pool>>{
+    data: { sequence: pool.data.sequence + 1n },
+  });
+
+  const swapIx = await getSwapInstructionAsync({
+    pool: poolPda,
+    collection,
+    sourceAsset: sourceAssetPk,
+    destAsset: destAssetPk,
+    payer,
+    coreProgram: MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+    maxFee: 10000000n,
+    expectedSequence: some(pool.data.sequence),
+  });
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, payer),
+    (tx) => appendTransactionMessageInstruction(swapIx, tx)
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__SLIPPAGE_EXCEEDED
+    )
+  );
+});
+
+test('it cannot swap a batch if the fees exceed the max fee', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const { authority, collection, poolPda, destAssetPk, payer, sourceAssetPk } =
+    await setup(client);
+
+  await setFee(client, authority, poolPda, 20000000n);
+
+  const swapBatchIx = await getSwapBatchInstructionAsync({
+    pool: poolPda,
+    payer,
+    collection,
+    coreProgram: MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+    maxFee: 10000000n,
+  });
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, payer),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        withRemainingAccounts(swapBatchIx, [sourceAssetPk, destAssetPk]),
+        tx
+      )
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__SLIPPAGE_EXCEEDED
+    )
+  );
+});
+
+test('it cannot commit a mystery swap if the pool changed since the expected sequence', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const { authority, collection, poolPda, payer, sourceAssetPk } =
+    await setup(client);
+
+  await setPoolMode(client, authority, poolPda, PoolMode.Mystery);
+  const pool = await fetchPool(client.rpc, poolPda);
+
+  await setFee(client, authority, poolPda, 10000000n);
+
+  const commitIx = await getCommitSwapInstructionAsync({
+    pool: poolPda,
+    sourceAsset: sourceAssetPk,
+    payer,
+    collection,
+    coreProgram: MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+    maxFee: 10000000n,
+    expectedSequence: some(pool.data.sequence),
+  });
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, payer),
+    (tx) => appendTransactionMessageInstruction(commitIx, tx)
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__SLIPPAGE_EXCEEDED
+    )
+  );
+});
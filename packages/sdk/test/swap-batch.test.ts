@@ -0,0 +1,269 @@
+import test from 'ava';
+import {
+  createAndDepositAsset,
+  createDefaultSolanaClient,
+  createDefaultTransaction,
+  createPoolForAuthority,
+  generateKeyPairSignerWithSol,
+  setPoolStatus,
+  signAndSendTransaction,
+  withRemainingAccounts,
+} from './_setup';
+import { createCoreAsset, createCoreCollection } from './_mpl-core';
+import {
+  Account,
+  Address,
+  appendTransactionMessageInstruction,
+  generateKeyPairSigner,
+  isProgramError,
+  isSolanaError,
+  pipe,
+  SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE,
+} from '@solana/kit';
+import {
+  FeeVault,
+  fetchFeeVault,
+  findFeeVaultPda,
+  findInventoryPda,
+  FLOOR_SWAP_ERROR__DUPLICATE_ACCOUNT,
+  FLOOR_SWAP_ERROR__INVALID_REMAINING_ACCOUNTS,
+  FLOOR_SWAP_PROGRAM_ADDRESS,
+  getSwapBatchInstruction,
+  PoolStatus,
+} from '../src';
+import {
+  AssetV1,
+  fetchAssetV1,
+  MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+} from '../sdks/mpl-core/generated';
+
+test('it can swap several assets from the pool at once', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const authority = await generateKeyPairSignerWithSol(client);
+  const collection = await createCoreCollection(client, authority);
+  const treasury = (await generateKeyPairSigner()).address;
+  const [poolPda] = await createPoolForAuthority(
+    client,
+    authority,
+    collection,
+    treasury
+  );
+
+  const payer = await generateKeyPairSignerWithSol(client);
+
+  const pairs: [Address, Address][] = [];
+  for (let i = 0; i < 3; i++) {
+    const sourceAssetPk = await createCoreAsset(
+      client,
+      authority,
+      collection,
+      payer.address
+    );
+    const destAssetPk = await createAndDepositAsset(
+      client,
+      authority,
+      collection,
+      poolPda
+    );
+    pairs.push([sourceAssetPk, destAssetPk]);
+  }
+
+  await setPoolStatus(client, authority, poolPda, PoolStatus.Active);
+
+  const [feeVault] = await findFeeVaultPda({ pool: poolPda });
+  const [inventory] = await findInventoryPda({ pool: poolPda });
+  await pipe(
+    await createDefaultTransaction(client, payer),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        withRemainingAccounts(
+          getSwapBatchInstruction({
+            pool: poolPda,
+            inventory,
+            payer,
+            feeVault,
+            collection,
+            coreProgram: MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+            maxFee: 30000000n,
+          }),
+          pairs.flat()
+        ),
+        tx
+      ),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  for (const [sourceAssetPk, destAssetPk] of pairs) {
+    const [sourceAsset, destAsset] = await Promise.all([
+      fetchAssetV1(client.rpc, sourceAssetPk),
+      fetchAssetV1(client.rpc, destAssetPk),
+    ]);
+
+    t.like(sourceAsset, <Account<AssetV1>>{
+      data: {
+        owner: poolPda,
+      },
+    });
+
+    t.like(destAsset, <Account<AssetV1>>{
+      data: {
+        owner: payer.address,
+      },
+    });
+  }
+
+  t.like(await fetchFeeVault(client.rpc, feeVault), <Account<FeeVault>>{
+    data: {
+      lifetimeFees: 30000000n,
+    },
+  });
+});
+
+test('it cannot swap a batch with an incomplete asset pair', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const authority = await generateKeyPairSignerWithSol(client);
+  const collection = await createCoreCollection(client, authority);
+  const treasury = (await generateKeyPairSigner()).address;
+  const [poolPda] = await createPoolForAuthority(
+    client,
+    authority,
+    collection,
+    treasury
+  );
+
+  await createAndDepositAsset(client, authority, collection, poolPda);
+
+  const payer = await generateKeyPairSignerWithSol(client);
+
+  const sourceAssetPk = await createCoreAsset(
+    client,
+    authority,
+    collection,
+    payer.address
+  );
+
+  await setPoolStatus(client, authority, poolPda, PoolStatus.Active);
+
+  const [feeVault] = await findFeeVaultPda({ pool: poolPda });
+  const [inventory] = await findInventoryPda({ pool: poolPda });
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, payer),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        withRemainingAccounts(
+          getSwapBatchInstruction({
+            pool: poolPda,
+            inventory,
+            payer,
+            feeVault,
+            collection,
+            coreProgram: MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+            maxFee: 30000000n,
+          }),
+          [sourceAssetPk]
+        ),
+        tx
+      )
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__INVALID_REMAINING_ACCOUNTS
+    )
+  );
+});
+
+test('it cannot swap the same asset twice in a batch', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const authority = await generateKeyPairSignerWithSol(client);
+  const collection = await createCoreCollection(client, authority);
+  const treasury = (await generateKeyPairSigner()).address;
+  const [poolPda] = await createPoolForAuthority(
+    client,
+    authority,
+    collection,
+    treasury
+  );
+
+  const firstDestAssetPk = await createAndDepositAsset(
+    client,
+    authority,
+    collection,
+    poolPda
+  );
+  const secondDestAssetPk = await createAndDepositAsset(
+    client,
+    authority,
+    collection,
+    poolPda
+  );
+
+  const payer = await generateKeyPairSignerWithSol(client);
+
+  const sourceAssetPk = await createCoreAsset(
+    client,
+    authority,
+    collection,
+    payer.address
+  );
+
+  await setPoolStatus(client, authority, poolPda, PoolStatus.Active);
+
+  const [feeVault] = await findFeeVaultPda({ pool: poolPda });
+  const [inventory] = await findInventoryPda({ pool: poolPda });
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, payer),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        withRemainingAccounts(
+          getSwapBatchInstruction({
+            pool: poolPda,
+            inventory,
+            payer,
+            feeVault,
+            collection,
+            coreProgram: MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+            maxFee: 30000000n,
+          }),
+          [
+            sourceAssetPk,
+            firstDestAssetPk,
+            sourceAssetPk,
+            secondDestAssetPk,
+          ]
+        ),
+        tx
+      )
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__DUPLICATE_ACCOUNT
+    )
+  );
+});
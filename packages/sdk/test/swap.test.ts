@@ -5,8 +5,7 @@ import {
   createDefaultTransaction,
   createPoolForAuthority,
   generateKeyPairSignerWithSol,
-  getBalance,
-  setPoolActive,
+  setPoolStatus,
   signAndSendTransaction,
 } from './_setup';
 import { createCoreAsset, createCoreCollection } from './_mpl-core';
@@ -20,10 +19,18 @@ import {
   SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE,
 } from '@solana/kit';
 import {
+  FeeVault,
+  fetchFeeVault,
+  findFeeVaultPda,
+  findInventoryPda,
+  FLOOR_SWAP_ERROR__ACCOUNT_MISMATCH,
+  FLOOR_SWAP_ERROR__DUPLICATE_ACCOUNT,
   FLOOR_SWAP_ERROR__INVALID_COLLECTION_FOR_ASSET,
+  FLOOR_SWAP_ERROR__INVALID_PROGRAM_OWNER,
   FLOOR_SWAP_ERROR__POOL_INACTIVE,
   FLOOR_SWAP_PROGRAM_ADDRESS,
   getSwapInstruction,
+  PoolStatus,
 } from '../src';
 import {
   AssetV1,
@@ -60,30 +67,34 @@ test('it can swap an asset from the pool', async (t) => {
     payer.address
   );
 
-  await setPoolActive(client, authority, poolPda, true);
+  await setPoolStatus(client, authority, poolPda, PoolStatus.Active);
 
+  const [feeVault] = await findFeeVaultPda({ pool: poolPda });
+  const [inventory] = await findInventoryPda({ pool: poolPda });
   await pipe(
     await createDefaultTransaction(client, authority),
     (tx) =>
       appendTransactionMessageInstruction(
         getSwapInstruction({
           pool: poolPda,
+          inventory,
           collection,
           sourceAsset: sourceAssetPk,
           destAsset: destAssetPk,
           payer,
-          treasury,
+          feeVault,
           coreProgram: MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+          maxFee: 10000000n,
         }),
         tx
       ),
     (tx) => signAndSendTransaction(client, tx)
   );
 
-  const [sourceAsset, destAsset, treasuryBalance] = await Promise.all([
+  const [sourceAsset, destAsset, feeVaultAccount] = await Promise.all([
     fetchAssetV1(client.rpc, sourceAssetPk),
     fetchAssetV1(client.rpc, destAssetPk),
-    getBalance(client, treasury),
+    fetchFeeVault(client.rpc, feeVault),
   ]);
 
   t.like(sourceAsset, <Account<AssetV1>>{
@@ -98,7 +109,11 @@ test('it can swap an asset from the pool', async (t) => {
     },
   });
 
-  t.deepEqual(treasuryBalance, 10000000n);
+  t.like(feeVaultAccount, <Account<FeeVault>>{
+    data: {
+      lifetimeFees: 10000000n,
+    },
+  });
 });
 
 test('it cannot swap if the pool is inactive', async (t) => {
@@ -130,18 +145,22 @@ test('it cannot swap if the pool is inactive', async (t) => {
     payer.address
   );
 
+  const [feeVault] = await findFeeVaultPda({ pool: poolPda });
+  const [inventory] = await findInventoryPda({ pool: poolPda });
   const transactionMessage = pipe(
     await createDefaultTransaction(client, authority),
     (tx) =>
       appendTransactionMessageInstruction(
         getSwapInstruction({
           pool: poolPda,
+          inventory,
           collection,
           sourceAsset: sourceAssetPk,
           destAsset: destAssetPk,
           payer,
-          treasury,
+          feeVault,
           coreProgram: MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+          maxFee: 10000000n,
         }),
         tx
       )
@@ -195,20 +214,24 @@ test('it cannot swap if the source asset is from the wrong collection', async (t
     payer.address
   );
 
-  await setPoolActive(client, authority, poolPda, true);
+  await setPoolStatus(client, authority, poolPda, PoolStatus.Active);
 
+  const [feeVault] = await findFeeVaultPda({ pool: poolPda });
+  const [inventory] = await findInventoryPda({ pool: poolPda });
   const transactionMessage = pipe(
     await createDefaultTransaction(client, authority),
     (tx) =>
       appendTransactionMessageInstruction(
         getSwapInstruction({
           pool: poolPda,
+          inventory,
           collection,
           sourceAsset: sourceAssetPk,
           destAsset: destAssetPk,
           payer,
-          treasury,
+          feeVault,
           coreProgram: MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+          maxFee: 10000000n,
         }),
         tx
       )
@@ -231,3 +254,293 @@ test('it cannot swap if the source asset is from the wrong collection', async (t
     )
   );
 });
+
+test('it cannot swap with the fee vault of another pool', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const authority = await generateKeyPairSignerWithSol(client);
+  const collection = await createCoreCollection(client, authority);
+  const treasury = (await generateKeyPairSigner()).address;
+  const [poolPda] = await createPoolForAuthority(
+    client,
+    authority,
+    collection,
+    treasury
+  );
+
+  const destAssetPk = await createAndDepositAsset(
+    client,
+    authority,
+    collection,
+    poolPda
+  );
+
+  const payer = await generateKeyPairSignerWithSol(client);
+
+  const sourceAssetPk = await createCoreAsset(
+    client,
+    authority,
+    collection,
+    payer.address
+  );
+
+  await setPoolStatus(client, authority, poolPda, PoolStatus.Active);
+
+  const otherCollection = await createCoreCollection(client, authority);
+  const [otherPoolPda] = await createPoolForAuthority(
+    client,
+    authority,
+    otherCollection,
+    treasury
+  );
+  const [spoofedFeeVault] = await findFeeVaultPda({ pool: otherPoolPda });
+
+  const [inventory] = await findInventoryPda({ pool: poolPda });
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getSwapInstruction({
+          pool: poolPda,
+          inventory,
+          collection,
+          sourceAsset: sourceAssetPk,
+          destAsset: destAssetPk,
+          payer,
+          feeVault: spoofedFeeVault,
+          coreProgram: MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+          maxFee: 10000000n,
+        }),
+        tx
+      )
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__ACCOUNT_MISMATCH
+    )
+  );
+});
+
+test('it cannot swap using a pool not owned by the program', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const authority = await generateKeyPairSignerWithSol(client);
+  const collection = await createCoreCollection(client, authority);
+  const treasury = (await generateKeyPairSigner()).address;
+  const [poolPda] = await createPoolForAuthority(
+    client,
+    authority,
+    collection,
+    treasury
+  );
+
+  const destAssetPk = await createAndDepositAsset(
+    client,
+    authority,
+    collection,
+    poolPda
+  );
+
+  const payer = await generateKeyPairSignerWithSol(client);
+
+  const sourceAssetPk = await createCoreAsset(
+    client,
+    authority,
+    collection,
+    payer.address
+  );
+
+  await setPoolStatus(client, authority, poolPda, PoolStatus.Active);
+
+  const spoofedPool = (await generateKeyPairSigner()).address;
+
+  const [feeVault] = await findFeeVaultPda({ pool: spoofedPool });
+  const [spoofedInventory] = await findInventoryPda({ pool: spoofedPool });
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getSwapInstruction({
+          pool: spoofedPool,
+          inventory: spoofedInventory,
+          collection,
+          sourceAsset: sourceAssetPk,
+          destAsset: destAssetPk,
+          payer,
+          feeVault,
+          coreProgram: MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+          maxFee: 10000000n,
+        }),
+        tx
+      )
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__INVALID_PROGRAM_OWNER
+    )
+  );
+});
+
+test('it cannot swap an asset for itself', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const authority = await generateKeyPairSignerWithSol(client);
+  const collection = await createCoreCollection(client, authority);
+  const treasury = (await generateKeyPairSigner()).address;
+  const [poolPda] = await createPoolForAuthority(
+    client,
+    authority,
+    collection,
+    treasury
+  );
+
+  const destAssetPk = await createAndDepositAsset(
+    client,
+    authority,
+    collection,
+    poolPda
+  );
+
+  const payer = await generateKeyPairSignerWithSol(client);
+
+  const sourceAssetPk = await createCoreAsset(
+    client,
+    authority,
+    collection,
+    payer.address
+  );
+
+  await setPoolStatus(client, authority, poolPda, PoolStatus.Active);
+
+  const [feeVault] = await findFeeVaultPda({ pool: poolPda });
+  const [inventory] = await findInventoryPda({ pool: poolPda });
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getSwapInstruction({
+          pool: poolPda,
+          inventory,
+          collection,
+          sourceAsset: sourceAssetPk,
+          destAsset: sourceAssetPk,
+          payer,
+          feeVault,
+          coreProgram: MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+          maxFee: 10000000n,
+        }),
+        tx
+      )
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__DUPLICATE_ACCOUNT
+    )
+  );
+});
+
+test('it cannot swap using the wrong core program', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const authority = await generateKeyPairSignerWithSol(client);
+  const collection = await createCoreCollection(client, authority);
+  const treasury = (await generateKeyPairSigner()).address;
+  const [poolPda] = await createPoolForAuthority(
+    client,
+    authority,
+    collection,
+    treasury
+  );
+
+  const destAssetPk = await createAndDepositAsset(
+    client,
+    authority,
+    collection,
+    poolPda
+  );
+
+  const payer = await generateKeyPairSignerWithSol(client);
+
+  const sourceAssetPk = await createCoreAsset(
+    client,
+    authority,
+    collection,
+    payer.address
+  );
+
+  await setPoolStatus(client, authority, poolPda, PoolStatus.Active);
+
+  const [feeVault] = await findFeeVaultPda({ pool: poolPda });
+  const [inventory] = await findInventoryPda({ pool: poolPda });
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getSwapInstruction({
+          pool: poolPda,
+          inventory,
+          collection,
+          sourceAsset: sourceAssetPk,
+          destAsset: destAssetPk,
+          payer,
+          feeVault,
+          coreProgram: FLOOR_SWAP_PROGRAM_ADDRESS,
+          maxFee: 10000000n,
+        }),
+        tx
+      )
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__ACCOUNT_MISMATCH
+    )
+  );
+});
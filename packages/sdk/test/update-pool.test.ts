@@ -0,0 +1,247 @@
+import test from 'ava';
+import {
+  createDefaultSolanaClient,
+  createDefaultTransaction,
+  createPoolForAuthority,
+  generateKeyPairSignerWithSol,
+  signAndSendTransaction,
+} from './_setup';
+import { createCoreCollection } from './_mpl-core';
+import {
+  Account,
+  appendTransactionMessageInstruction,
+  generateKeyPairSigner,
+  isProgramError,
+  isSolanaError,
+  pipe,
+  SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE,
+} from '@solana/kit';
+import {
+  feeModel,
+  fetchPool,
+  FLOOR_SWAP_ERROR__ACCOUNT_MISMATCH,
+  FLOOR_SWAP_ERROR__FEE_INCREASE_NOT_PROPOSED,
+  FLOOR_SWAP_PROGRAM_ADDRESS,
+  getUpdatePoolInstruction,
+  Pool,
+  PoolStatus,
+} from '../src';
+
+test('it can rotate the treasury of a pool', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const authority = await generateKeyPairSignerWithSol(client);
+  const collection = await createCoreCollection(client, authority);
+  const treasury = (await generateKeyPairSigner()).address;
+  const [poolPda] = await createPoolForAuthority(
+    client,
+    authority,
+    collection,
+    treasury
+  );
+
+  const newTreasury = (await generateKeyPairSigner()).address;
+
+  await pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getUpdatePoolInstruction({
+          pool: poolPda,
+          authority,
+          treasury: newTreasury,
+        }),
+        tx
+      ),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  // The swap terms are unchanged, so the sequence is too.
+  t.like(await fetchPool(client.rpc, poolPda), <Account<Pool>>{
+    data: {
+      treasury: newTreasury,
+      sequence: 0n,
+    },
+  });
+});
+
+test('it can update several settings at once', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const authority = await generateKeyPairSignerWithSol(client);
+  const collection = await createCoreCollection(client, authority);
+  const treasury = (await generateKeyPairSigner()).address;
+  const [poolPda] = await createPoolForAuthority(
+    client,
+    authority,
+    collection,
+    treasury,
+    undefined,
+    { maxReferralBps: 1000 }
+  );
+
+  await pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getUpdatePoolInstruction({
+          pool: poolPda,
+          authority,
+          feeModel: feeModel('Flat', { lamports: 5_000_000n }),
+          status: PoolStatus.Active,
+          referencePrice: 2_000_000_000n,
+          referralBps: 1000,
+        }),
+        tx
+      ),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  t.like(await fetchPool(client.rpc, poolPda), <Account<Pool>>{
+    data: {
+      treasury,
+      feeModel: { __kind: 'Flat', lamports: 5_000_000n },
+      status: PoolStatus.Active,
+      referencePrice: 2_000_000_000n,
+      referralBps: 1000,
+      sequence: 1n,
+    },
+  });
+});
+
+test('it cannot raise the fee with an update', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const authority = await generateKeyPairSignerWithSol(client);
+  const collection = await createCoreCollection(client, authority);
+  const treasury = (await generateKeyPairSigner()).address;
+  const [poolPda] = await createPoolForAuthority(
+    client,
+    authority,
+    collection,
+    treasury
+  );
+
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getUpdatePoolInstruction({
+          pool: poolPda,
+          authority,
+          feeModel: feeModel('Flat', { lamports: 20_000_000n }),
+        }),
+        tx
+      )
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__FEE_INCREASE_NOT_PROPOSED
+    )
+  );
+});
+
+test('it cannot raise the fee through the reference price with an update', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const authority = await generateKeyPairSignerWithSol(client);
+  const collection = await createCoreCollection(client, authority);
+  const treasury = (await generateKeyPairSigner()).address;
+  const [poolPda] = await createPoolForAuthority(
+    client,
+    authority,
+    collection,
+    treasury,
+    feeModel('FlatPlusBps', { lamports: 10_000_000n, bps: 250 })
+  );
+
+  // The flat part is lowered, but 2.5% of the new reference price outweighs it.
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getUpdatePoolInstruction({
+          pool: poolPda,
+          authority,
+          feeModel: feeModel('FlatPlusBps', { lamports: 5_000_000n, bps: 250 }),
+          referencePrice: 2_000_000_000n,
+        }),
+        tx
+      )
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__FEE_INCREASE_NOT_PROPOSED
+    )
+  );
+});
+
+test('it cannot update a pool if not the authority', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const authority = await generateKeyPairSignerWithSol(client);
+  const collection = await createCoreCollection(client, authority);
+  const treasury = (await generateKeyPairSigner()).address;
+  const [poolPda] = await createPoolForAuthority(
+    client,
+    authority,
+    collection,
+    treasury
+  );
+
+  const unauthorizedSigner = await generateKeyPairSignerWithSol(client);
+
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, unauthorizedSigner),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getUpdatePoolInstruction({
+          pool: poolPda,
+          authority: unauthorizedSigner,
+          treasury: unauthorizedSigner.address,
+        }),
+        tx
+      )
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__ACCOUNT_MISMATCH
+    )
+  );
+});
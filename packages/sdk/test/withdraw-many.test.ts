@@ -1,10 +1,12 @@
 import test from 'ava';
 import {
+  createAndDepositAsset,
   createDefaultSolanaClient,
   createDefaultTransaction,
   createPoolForAuthority,
   generateKeyPairSignerWithSol,
   signAndSendTransaction,
+  withRemainingAccounts,
 } from './_setup';
 import { createCoreCollection } from './_mpl-core';
 import {
@@ -17,14 +19,20 @@ import {
   SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE,
 } from '@solana/kit';
 import {
-  fetchPoolFromSeeds,
+  fetchPool,
+  findInventoryPda,
   FLOOR_SWAP_ERROR__ACCOUNT_MISMATCH,
   FLOOR_SWAP_PROGRAM_ADDRESS,
-  getSetActiveInstruction,
+  getWithdrawManyInstruction,
   Pool,
 } from '../src';
+import {
+  AssetV1,
+  fetchAssetV1,
+  MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+} from '../sdks/mpl-core/generated';
 
-test('it can activate a pool', async (t) => {
+test('it can withdraw several assets from the pool', async (t) => {
   t.timeout(30000);
   const client = createDefaultSolanaClient();
   const authority = await generateKeyPairSignerWithSol(client);
@@ -37,33 +45,53 @@ test('it can activate a pool', async (t) => {
     treasury
   );
 
+  const assetPks = [
+    await createAndDepositAsset(client, authority, collection, poolPda),
+    await createAndDepositAsset(client, authority, collection, poolPda),
+    await createAndDepositAsset(client, authority, collection, poolPda),
+  ];
+
+  const destination = (await generateKeyPairSigner()).address;
+
+  const [inventory] = await findInventoryPda({ pool: poolPda });
   await pipe(
     await createDefaultTransaction(client, authority),
     (tx) =>
       appendTransactionMessageInstruction(
-        getSetActiveInstruction({
-          pool: poolPda,
-          authority,
-          active: true,
-        }),
+        withRemainingAccounts(
+          getWithdrawManyInstruction({
+            pool: poolPda,
+            inventory,
+            authority,
+            collection,
+            destination,
+            coreProgram: MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+          }),
+          assetPks
+        ),
         tx
       ),
     (tx) => signAndSendTransaction(client, tx)
   );
 
-  const pool = await fetchPoolFromSeeds(client.rpc, {
-    authority: authority.address,
-    collection,
-  });
+  for (const assetPk of assetPks) {
+    const asset = await fetchAssetV1(client.rpc, assetPk);
+    t.like(asset, <Account<AssetV1>>{
+      data: {
+        owner: destination,
+      },
+    });
+  }
 
+  const pool = await fetchPool(client.rpc, poolPda);
   t.like(pool, <Account<Pool>>{
     data: {
-      enabled: true,
+      numAssets: 0,
     },
   });
 });
 
-test('it cannot activate a pool if not the authority', async (t) => {
+test('it cannot withdraw many assets from the pool if not authority', async (t) => {
   t.timeout(30000);
   const client = createDefaultSolanaClient();
   const authority = await generateKeyPairSignerWithSol(client);
@@ -76,17 +104,28 @@ test('it cannot activate a pool if not the authority', async (t) => {
     treasury
   );
 
+  const assetPks = [
+    await createAndDepositAsset(client, authority, collection, poolPda),
+    await createAndDepositAsset(client, authority, collection, poolPda),
+  ];
+
   const unauthorizedSigner = await generateKeyPairSignerWithSol(client);
 
+  const [inventory] = await findInventoryPda({ pool: poolPda });
   const transactionMessage = pipe(
     await createDefaultTransaction(client, unauthorizedSigner),
     (tx) =>
       appendTransactionMessageInstruction(
-        getSetActiveInstruction({
-          pool: poolPda,
-          authority: unauthorizedSigner,
-          active: true,
-        }),
+        withRemainingAccounts(
+          getWithdrawManyInstruction({
+            pool: poolPda,
+            inventory,
+            authority: unauthorizedSigner,
+            collection,
+            coreProgram: MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+          }),
+          assetPks
+        ),
         tx
       )
   );
@@ -108,67 +147,3 @@ test('it cannot activate a pool if not the authority', async (t) => {
     )
   );
 });
-
-test('it can deactivate a pool', async (t) => {
-  t.timeout(30000);
-  const client = createDefaultSolanaClient();
-  const authority = await generateKeyPairSignerWithSol(client);
-  const collection = await createCoreCollection(client, authority);
-  const treasury = (await generateKeyPairSigner()).address;
-  const [poolPda] = await createPoolForAuthority(
-    client,
-    authority,
-    collection,
-    treasury
-  );
-
-  await pipe(
-    await createDefaultTransaction(client, authority),
-    (tx) =>
-      appendTransactionMessageInstruction(
-        getSetActiveInstruction({
-          pool: poolPda,
-          authority,
-          active: true,
-        }),
-        tx
-      ),
-    (tx) => signAndSendTransaction(client, tx)
-  );
-
-  let pool = await fetchPoolFromSeeds(client.rpc, {
-    authority: authority.address,
-    collection,
-  });
-
-  t.like(pool, <Account<Pool>>{
-    data: {
-      enabled: true,
-    },
-  });
-
-  await pipe(
-    await createDefaultTransaction(client, authority),
-    (tx) =>
-      appendTransactionMessageInstruction(
-        getSetActiveInstruction({
-          pool: poolPda,
-          authority,
-          active: false,
-        }),
-        tx
-      ),
-    (tx) => signAndSendTransaction(client, tx)
-  );
-
-  pool = await fetchPoolFromSeeds(client.rpc, {
-    authority: authority.address,
-    collection,
-  });
-
-  t.like(pool, <Account<Pool>>{
-    data: {
-      enabled: false,
-    },
-  });
-});
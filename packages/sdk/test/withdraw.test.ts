@@ -6,10 +6,12 @@ import {
   createPoolForAuthority,
   generateKeyPairSignerWithSol,
   signAndSendTransaction,
+  withRemainingAccounts,
 } from './_setup';
 import { createCoreAsset, createCoreCollection } from './_mpl-core';
 import {
   Account,
+  AccountRole,
   appendTransactionMessageInstruction,
   appendTransactionMessageInstructions,
   generateKeyPairSigner,
@@ -20,9 +22,13 @@ import {
 } from '@solana/kit';
 import {
   fetchPool,
+  findInventoryPda,
   FLOOR_SWAP_ERROR__ACCOUNT_MISMATCH,
+  FLOOR_SWAP_ERROR__ASSET_NOT_IN_INVENTORY,
   FLOOR_SWAP_ERROR__INVALID_ASSET_OWNER,
+  FLOOR_SWAP_ERROR__INVALID_PROGRAM_OWNER,
   FLOOR_SWAP_PROGRAM_ADDRESS,
+  getReconcileInstruction,
   getWithdrawInstruction,
   Pool,
 } from '../src';
@@ -61,12 +67,14 @@ test('it can withdraw an asset from the pool', async (t) => {
     },
   });
 
+  const [inventory] = await findInventoryPda({ pool: poolPda });
   await pipe(
     await createDefaultTransaction(client, authority),
     (tx) =>
       appendTransactionMessageInstruction(
         getWithdrawInstruction({
           pool: poolPda,
+          inventory,
           collection,
           asset: assetPk,
           authority,
@@ -94,7 +102,7 @@ test('it can withdraw an asset from the pool', async (t) => {
   });
 });
 
-test('it can withdraw an asset from the pool that was sent manually', async (t) => {
+test('it can withdraw an asset sent manually once reconciled', async (t) => {
   t.timeout(30000);
   const client = createDefaultSolanaClient();
   const authority = await generateKeyPairSignerWithSol(client);
@@ -109,6 +117,7 @@ test('it can withdraw an asset from the pool that was sent manually', async (t)
 
   const assetPk = await createCoreAsset(client, authority, collection);
 
+  const [inventory] = await findInventoryPda({ pool: poolPda });
   await pipe(
     await createDefaultTransaction(client, authority),
     (tx) =>
@@ -122,8 +131,19 @@ test('it can withdraw an asset from the pool that was sent manually', async (t)
             collection,
             compressionProof: null,
           }),
+          withRemainingAccounts(
+            getReconcileInstruction({
+              pool: poolPda,
+              inventory,
+              collection,
+              payer: authority,
+            }),
+            [assetPk],
+            AccountRole.READONLY
+          ),
           getWithdrawInstruction({
             pool: poolPda,
+            inventory,
             collection,
             asset: assetPk,
             authority,
@@ -152,6 +172,66 @@ test('it can withdraw an asset from the pool that was sent manually', async (t)
   });
 });
 
+test('it cannot withdraw an asset sent manually without reconciling', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const authority = await generateKeyPairSignerWithSol(client);
+  const collection = await createCoreCollection(client, authority);
+  const treasury = (await generateKeyPairSigner()).address;
+  const [poolPda] = await createPoolForAuthority(
+    client,
+    authority,
+    collection,
+    treasury
+  );
+
+  const assetPk = await createCoreAsset(client, authority, collection);
+
+  const [inventory] = await findInventoryPda({ pool: poolPda });
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstructions(
+        [
+          getTransferV1Instruction({
+            asset: assetPk,
+            newOwner: poolPda,
+            authority,
+            payer: authority,
+            collection,
+            compressionProof: null,
+          }),
+          getWithdrawInstruction({
+            pool: poolPda,
+            inventory,
+            collection,
+            asset: assetPk,
+            authority,
+            coreProgram: MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+          }),
+        ],
+        tx
+      )
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__ASSET_NOT_IN_INVENTORY
+    )
+  );
+});
+
 test('it can withdraw an asset from the pool to another wallet', async (t) => {
   t.timeout(30000);
   const client = createDefaultSolanaClient();
@@ -182,12 +262,14 @@ test('it can withdraw an asset from the pool to another wallet', async (t) => {
 
   const destination = (await generateKeyPairSigner()).address;
 
+  const [inventory] = await findInventoryPda({ pool: poolPda });
   await pipe(
     await createDefaultTransaction(client, authority),
     (tx) =>
       appendTransactionMessageInstruction(
         getWithdrawInstruction({
           pool: poolPda,
+          inventory,
           collection,
           asset: assetPk,
           authority,
@@ -238,12 +320,14 @@ test('it cannot withdraw an asset from the pool if not authority', async (t) =>
 
   const unauthorizedSigner = await generateKeyPairSignerWithSol(client);
 
+  const [inventory] = await findInventoryPda({ pool: poolPda });
   const transactionMessage = pipe(
     await createDefaultTransaction(client, unauthorizedSigner),
     (tx) =>
       appendTransactionMessageInstruction(
         getWithdrawInstruction({
           pool: poolPda,
+          inventory,
           collection,
           asset: assetPk,
           authority: unauthorizedSigner,
@@ -286,12 +370,14 @@ test('it cannot withdraw an asset from the pool if not in pool', async (t) => {
 
   const assetPk = await createCoreAsset(client, authority, collection);
 
+  const [inventory] = await findInventoryPda({ pool: poolPda });
   const transactionMessage = pipe(
     await createDefaultTransaction(client, authority),
     (tx) =>
       appendTransactionMessageInstruction(
         getWithdrawInstruction({
           pool: poolPda,
+          inventory,
           collection,
           asset: assetPk,
           authority,
@@ -318,3 +404,60 @@ test('it cannot withdraw an asset from the pool if not in pool', async (t) => {
     )
   );
 });
+
+test('it cannot withdraw from a pool not owned by the program', async (t) => {
+  t.timeout(30000);
+  const client = createDefaultSolanaClient();
+  const authority = await generateKeyPairSignerWithSol(client);
+  const collection = await createCoreCollection(client, authority);
+  const treasury = (await generateKeyPairSigner()).address;
+  const [poolPda] = await createPoolForAuthority(
+    client,
+    authority,
+    collection,
+    treasury
+  );
+
+  const assetPk = await createAndDepositAsset(
+    client,
+    authority,
+    collection,
+    poolPda
+  );
+
+  const spoofedPool = (await generateKeyPairSigner()).address;
+
+  const [spoofedInventory] = await findInventoryPda({ pool: spoofedPool });
+  const transactionMessage = pipe(
+    await createDefaultTransaction(client, authority),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        getWithdrawInstruction({
+          pool: spoofedPool,
+          inventory: spoofedInventory,
+          collection,
+          asset: assetPk,
+          authority,
+          coreProgram: MPL_CORE_PROGRAM_PROGRAM_ADDRESS,
+        }),
+        tx
+      )
+  );
+
+  const promise = signAndSendTransaction(client, transactionMessage);
+  const error = await t.throwsAsync(promise);
+  t.true(
+    isSolanaError(
+      error,
+      SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+    )
+  );
+  t.true(
+    isProgramError(
+      error.cause,
+      transactionMessage,
+      FLOOR_SWAP_PROGRAM_ADDRESS,
+      FLOOR_SWAP_ERROR__INVALID_PROGRAM_OWNER
+    )
+  );
+});
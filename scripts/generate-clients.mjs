@@ -23,12 +23,16 @@ codama.update(
 codama.update(
   c.updateAccountsVisitor({
     pool: {
+      // Sized for the largest fee model and fee mint, a pending fee, a pending
+      // authority, both roles, a full multisig and a full fee split.
+      size: 725,
       seeds: [
         c.constantPdaSeedNodeFromString('utf8', 'floor_swap'),
+        // The creator stays in the seeds when the authority is transferred.
         c.variablePdaSeedNode(
-          'authority',
+          'creator',
           c.publicKeyTypeNode(),
-          'The authority of the pool'
+          'The authority that created the pool'
         ),
         c.variablePdaSeedNode(
           'collection',
@@ -37,18 +41,223 @@ codama.update(
         ),
       ],
     },
+    swapCommit: {
+      seeds: [
+        c.constantPdaSeedNodeFromString('utf8', 'swap_commit'),
+        c.variablePdaSeedNode(
+          'pool',
+          c.publicKeyTypeNode(),
+          'The pool the swap was committed to'
+        ),
+        c.variablePdaSeedNode(
+          'sourceAsset',
+          c.publicKeyTypeNode(),
+          'The asset escrowed by the swap'
+        ),
+      ],
+    },
+    feeVault: {
+      seeds: [
+        c.constantPdaSeedNodeFromString('utf8', 'fee_vault'),
+        c.variablePdaSeedNode(
+          'pool',
+          c.publicKeyTypeNode(),
+          'The pool the fees are paid to'
+        ),
+      ],
+    },
+    globalConfig: {
+      seeds: [c.constantPdaSeedNodeFromString('utf8', 'global_config')],
+    },
+    inventory: {
+      seeds: [
+        c.constantPdaSeedNodeFromString('utf8', 'inventory'),
+        c.variablePdaSeedNode(
+          'pool',
+          c.publicKeyTypeNode(),
+          'The pool holding the assets'
+        ),
+      ],
+    },
+  })
+);
+
+// Pools after the first of a creator and collection also have their index in the seeds.
+codama.update(
+  c.addPdasVisitor({
+    floorSwap: [
+      {
+        name: 'indexedPool',
+        seeds: [
+          c.constantPdaSeedNodeFromString('utf8', 'floor_swap'),
+          c.variablePdaSeedNode(
+            'creator',
+            c.publicKeyTypeNode(),
+            'The authority that created the pool'
+          ),
+          c.variablePdaSeedNode(
+            'collection',
+            c.publicKeyTypeNode(),
+            'The collection of the pool'
+          ),
+          c.variablePdaSeedNode(
+            'index',
+            c.numberTypeNode('u8'),
+            'The index of the pool, above 0'
+          ),
+        ],
+      },
+    ],
   })
 );
 
+// The global config is a singleton, so its address is known ahead of time.
+const GLOBAL_CONFIG_ADDRESS = 'Eh9HhTbDPkxqTSsJqMhygBny7GjsrVyVmEAZvWYzBsy1';
+codama.update(
+  c.setInstructionAccountDefaultValuesVisitor([
+    {
+      account: 'globalConfig',
+      defaultValue: c.publicKeyValueNode(GLOBAL_CONFIG_ADDRESS, 'globalConfig'),
+    },
+  ])
+);
+
 // Update instructions.
 codama.update(
   c.updateInstructionsVisitor({
     create: {
       byteDeltas: [c.instructionByteDeltaNode(c.accountLinkNode('pool'))],
       accounts: {
-        pool: { defaultValue: c.pdaValueNode('pool') },
+        pool: {
+          defaultValue: c.pdaValueNode('pool', [
+            c.pdaSeedValueNode('creator', c.accountValueNode('authority')),
+          ]),
+        },
+        feeVault: { defaultValue: c.pdaValueNode('feeVault') },
+        inventory: { defaultValue: c.pdaValueNode('inventory') },
         payer: { defaultValue: c.accountValueNode('authority') },
       },
+      arguments: {
+        maxReferralBps: { defaultValue: c.numberValueNode(0) },
+        index: { defaultValue: c.numberValueNode(0) },
+      },
+    },
+    swap: {
+      accounts: {
+        feeVault: { defaultValue: c.pdaValueNode('feeVault') },
+        inventory: { defaultValue: c.pdaValueNode('inventory') },
+      },
+      arguments: {
+        expectedSequence: { defaultValue: c.noneValueNode() },
+      },
+    },
+    swapBatch: {
+      accounts: {
+        feeVault: { defaultValue: c.pdaValueNode('feeVault') },
+        inventory: { defaultValue: c.pdaValueNode('inventory') },
+      },
+      arguments: {
+        expectedSequence: { defaultValue: c.noneValueNode() },
+      },
+    },
+    commitSwap: {
+      accounts: {
+        swapCommit: { defaultValue: c.pdaValueNode('swapCommit') },
+        feeVault: { defaultValue: c.pdaValueNode('feeVault') },
+      },
+      arguments: {
+        expectedSequence: { defaultValue: c.noneValueNode() },
+      },
+    },
+    revealSwap: {
+      accounts: {
+        swapCommit: { defaultValue: c.pdaValueNode('swapCommit') },
+        inventory: { defaultValue: c.pdaValueNode('inventory') },
+      },
+    },
+    cancelSwap: {
+      accounts: {
+        swapCommit: { defaultValue: c.pdaValueNode('swapCommit') },
+        inventory: { defaultValue: c.pdaValueNode('inventory') },
+      },
+    },
+    close: {
+      accounts: {
+        feeVault: { defaultValue: c.pdaValueNode('feeVault') },
+        inventory: { defaultValue: c.pdaValueNode('inventory') },
+      },
+    },
+    deposit: {
+      accounts: {
+        inventory: { defaultValue: c.pdaValueNode('inventory') },
+      },
+    },
+    depositMany: {
+      accounts: {
+        inventory: { defaultValue: c.pdaValueNode('inventory') },
+      },
+    },
+    withdraw: {
+      accounts: {
+        inventory: { defaultValue: c.pdaValueNode('inventory') },
+      },
+    },
+    withdrawMany: {
+      accounts: {
+        inventory: { defaultValue: c.pdaValueNode('inventory') },
+      },
+    },
+    reconcile: {
+      accounts: {
+        inventory: { defaultValue: c.pdaValueNode('inventory') },
+      },
+    },
+    closeAndDrain: {
+      accounts: {
+        feeVault: { defaultValue: c.pdaValueNode('feeVault') },
+        inventory: { defaultValue: c.pdaValueNode('inventory') },
+      },
+    },
+    migrate: {
+      accounts: {
+        feeVault: { defaultValue: c.pdaValueNode('feeVault') },
+        inventory: { defaultValue: c.pdaValueNode('inventory') },
+        payer: { defaultValue: c.accountValueNode('authority') },
+      },
+    },
+    claimFees: {
+      accounts: {
+        feeVault: { defaultValue: c.pdaValueNode('feeVault') },
+      },
+    },
+    initGlobalConfig: {
+      accounts: {
+        payer: { defaultValue: c.accountValueNode('admin') },
+      },
+    },
+    updateGlobalConfig: {
+      arguments: {
+        paused: { defaultValue: c.noneValueNode() },
+        guardians: { defaultValue: c.noneValueNode() },
+        protocolFee: { defaultValue: c.noneValueNode() },
+        protocolTreasury: { defaultValue: c.noneValueNode() },
+      },
+    },
+    proposeFee: {
+      arguments: {
+        referencePrice: { defaultValue: c.noneValueNode() },
+        royaltyBase: { defaultValue: c.noneValueNode() },
+      },
+    },
+    updatePool: {
+      arguments: {
+        feeModel: { defaultValue: c.noneValueNode() },
+        status: { defaultValue: c.noneValueNode() },
+        mode: { defaultValue: c.noneValueNode() },
+        referencePrice: { defaultValue: c.noneValueNode() },
+        royaltyBase: { defaultValue: c.noneValueNode() },
+        referralBps: { defaultValue: c.noneValueNode() },
+      },
     },
     increment: {
       accounts: {
@@ -66,6 +275,10 @@ const key = (name) => ({ field: 'key', value: c.enumValueNode('Key', name) });
 codama.update(
   c.setAccountDiscriminatorFromFieldVisitor({
     pool: key('pool'),
+    swapCommit: key('swapCommit'),
+    feeVault: key('feeVault'),
+    globalConfig: key('globalConfig'),
+    inventory: key('inventory'),
   })
 );
 
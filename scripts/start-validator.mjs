@@ -26,6 +26,10 @@ if (!restart && isValidatorRunning) {
 // Initial message.
 const verb = isValidatorRunning ? 'Restarting' : 'Starting';
 
+// The upgrade authority of local programs, derived from a throwaway key the
+// tests sign with to manage the global config.
+const LOCAL_UPGRADE_AUTHORITY = 'AKnL4NNf3DGWZJS6cPknBuEGnVsV4A4m5tgebLHaRSZ9';
+
 // Get programs and accounts.
 const programs = [...getPrograms(), ...getExternalPrograms()];
 const programPluralized = programs.length === 1 ? 'program' : 'programs';
@@ -49,8 +53,17 @@ if (isValidatorRunning) {
 const args = [/* Reset ledger */ '-r'];
 
 // Load programs.
-programs.forEach(({ programId, deployPath }) => {
-  args.push(/* Load BPF program */ '--bpf-program', programId, deployPath);
+programs.forEach(({ programId, deployPath, upgradeable }) => {
+  if (upgradeable) {
+    args.push(
+      /* Load upgradeable BPF program */ '--upgradeable-program',
+      programId,
+      deployPath,
+      LOCAL_UPGRADE_AUTHORITY
+    );
+  } else {
+    args.push(/* Load BPF program */ '--bpf-program', programId, deployPath);
+  }
 });
 
 // Load accounts.
@@ -110,6 +123,7 @@ function getPrograms() {
     return {
       programId: cargo.package.metadata.solana['program-id'],
       deployPath: path.join(binaryDir, `${name}.so`),
+      upgradeable: true,
     };
   });
 }